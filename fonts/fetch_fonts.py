@@ -0,0 +1,159 @@
+#!/usr/bin/env -S uv run --script
+# /// script
+# requires-python = ">=3.11"
+# dependencies = []
+# ///
+
+"""
+Collect the fonts embedded into the game so every supported language renders
+the same on every platform, including the web build which has no system fonts.
+
+Usage: fetch_fonts.py [output folder] [--web-lazy]
+       fetch_fonts.py --pin
+
+The fonts and the commits they are fetched from are listed in fonts.toml.
+Every file is checked against its recorded sha256; fonts already in the
+output folder are kept when they match. The output folder defaults to
+fonts/downloaded/, where build.rs picks the fonts up; the build itself never
+downloads them. Set FULL_CRISIS_FONTS_DIR to copy from a local folder holding
+the same file names instead.
+
+--web-lazy only collects the fonts the web build downloads on demand, for
+update-github-pages.py. --pin resolves the current upstream commit of every
+font, downloads it and rewrites commit and sha256 in fonts.toml.
+"""
+
+import hashlib
+import json
+import os
+import re
+import shutil
+import sys
+import tomllib
+import urllib.request
+from pathlib import Path
+
+MANIFEST = Path(__file__).resolve().parent / 'fonts.toml'
+DEFAULT_OUTPUT_DIR = Path(__file__).resolve().parent / 'downloaded'
+
+
+def load_fonts():
+    with open(MANIFEST, 'rb') as fd:
+        return tomllib.load(fd)['fonts']
+
+
+def font_url(font, commit=None):
+    return f'https://raw.githubusercontent.com/{font["repository"]}/{commit or font["commit"]}/{font["path"]}'
+
+
+def sha256_of(path):
+    digest = hashlib.sha256()
+    with open(path, 'rb') as fd:
+        for block in iter(lambda: fd.read(1 << 20), b''):
+            digest.update(block)
+    return digest.hexdigest()
+
+
+def download(url, target):
+    print(f'Downloading {url}')
+    partial = target.with_suffix(target.suffix + '.part')
+    with urllib.request.urlopen(url, timeout=60) as response, open(partial, 'wb') as fd:
+        shutil.copyfileobj(response, fd)
+    partial.replace(target)
+
+
+def fetch(output_dir, web_lazy_only):
+    output_dir.mkdir(parents=True, exist_ok=True)
+    local_dir = os.environ.get('FULL_CRISIS_FONTS_DIR')
+
+    failed = []
+    for font in load_fonts():
+        if web_lazy_only and not font.get('web_lazy_languages'):
+            continue
+        file_name = font['file']
+        if not font.get('commit') or not font.get('sha256'):
+            print(f'{file_name} is not pinned in {MANIFEST}, run "uv run fetch_fonts.py --pin"')
+            failed.append(file_name)
+            continue
+
+        target = output_dir / file_name
+        if target.exists() and sha256_of(target) == font['sha256']:
+            continue
+
+        try:
+            if local_dir:
+                shutil.copyfile(Path(local_dir) / file_name, target)
+            else:
+                download(font_url(font), target)
+        except Exception as e:
+            print(f'Could not fetch {file_name}: {e}')
+            failed.append(file_name)
+            continue
+
+        actual = sha256_of(target)
+        if actual != font['sha256']:
+            print(f'{file_name} has sha256 {actual}, fonts.toml expects {font["sha256"]}')
+            target.unlink()
+            failed.append(file_name)
+
+    if failed:
+        sys.exit(1)
+    print(f'Fonts ready in {output_dir}')
+
+
+def head_commit(repository):
+    request = urllib.request.Request(
+        f'https://api.github.com/repos/{repository}/commits/HEAD',
+        headers={'Accept': 'application/vnd.github+json'},
+    )
+    with urllib.request.urlopen(request, timeout=60) as response:
+        return json.load(response)['sha']
+
+
+def pin():
+    text = MANIFEST.read_text(encoding='utf-8')
+    commits = {}
+    scratch = MANIFEST.parent / '.pin'
+    scratch.mkdir(exist_ok=True)
+    try:
+        for font in load_fonts():
+            repository = font['repository']
+            if repository not in commits:
+                commits[repository] = head_commit(repository)
+            commit = commits[repository]
+            target = scratch / font['file']
+            download(font_url(font, commit), target)
+            digest = sha256_of(target)
+
+            # Rewrite the two fields inside this font's [[fonts]] table only
+            block = re.search(
+                r'(\[\[fonts\]\]\nfile = "' + re.escape(font['file']) + r'"\n.*?)(?=\n\[\[fonts\]\]|\Z)',
+                text, re.S,
+            )
+            updated = re.sub(r'commit = ".*?"', f'commit = "{commit}"', block.group(1))
+            updated = re.sub(r'sha256 = ".*?"', f'sha256 = "{digest}"', updated)
+            text = text[:block.start(1)] + updated + text[block.end(1):]
+            print(f'{font["file"]}: {commit} {digest}')
+    finally:
+        shutil.rmtree(scratch, ignore_errors=True)
+
+    MANIFEST.write_text(text, encoding='utf-8')
+    print(f'Updated {MANIFEST}')
+
+
+def main():
+    args = sys.argv[1:]
+    if args == ['--pin']:
+        pin()
+        return
+    web_lazy_only = '--web-lazy' in args
+    args = [arg for arg in args if arg != '--web-lazy']
+    if len(args) > 1:
+        print(f'Usage: {sys.argv[0]} [output folder] [--web-lazy]')
+        print(f'       {sys.argv[0]} --pin')
+        sys.exit(1)
+    fetch(Path(args[0]) if args else DEFAULT_OUTPUT_DIR, web_lazy_only)
+
+
+if __name__ == '__main__':
+    main()
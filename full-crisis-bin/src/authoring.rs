@@ -0,0 +1,535 @@
+use full_crisis::crisis::{CrisisCatalog, CrisisPack, PlayableCrises, pack::PACK_EXTENSION, signature};
+use rand::RngCore;
+use std::path::{Path, PathBuf};
+
+/// `full-crisis pack <crisis folder> [output.fcpack]`
+pub fn pack(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let crisis_folder = match args.first() {
+        Some(folder) => PathBuf::from(folder),
+        None => return Err("Usage: full-crisis pack <crisis folder> [output.fcpack]".into()),
+    };
+    if !crisis_folder.join("crisis.toml").exists() {
+        return Err(format!("{:?} does not contain a crisis.toml", crisis_folder).into());
+    }
+
+    let output = match args.get(1) {
+        Some(output) => PathBuf::from(output),
+        None => {
+            let crisis_folder = crisis_folder.canonicalize()?;
+            let name = crisis_folder.file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .ok_or_else(|| format!("Cannot name a pack after {:?}", crisis_folder))?;
+            PathBuf::from(format!("{}.{}", name, PACK_EXTENSION))
+        }
+    };
+
+    let manifest = CrisisPack::write_from_folder(&crisis_folder.canonicalize()?, &output)?;
+    println!("Packed '{}' v{} by {} ({}) into {:?}", manifest.name, manifest.version, manifest.author, manifest.license, output);
+    Ok(())
+}
+
+/// `full-crisis unpack <pack> [destination]`, where destination defaults to the crises folder from settings
+pub fn unpack(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let pack_path = match args.first() {
+        Some(pack_path) => Path::new(pack_path),
+        None => return Err("Usage: full-crisis unpack <pack> [destination]".into()),
+    };
+    let destination = match args.get(1) {
+        Some(destination) => PathBuf::from(destination),
+        None => PathBuf::from(full_crisis::gui::GameWindow::load_settings().game_crises_folder),
+    };
+
+    let bytes = std::fs::read(pack_path)
+        .map_err(|e| format!("Cannot read {:?}: {}", pack_path, e))?;
+    let pack = CrisisPack::from_bytes(&bytes)?;
+    let crisis_folder = pack.unpack_to(&destination)?;
+    println!("Unpacked '{}' v{} by {} ({}) into {:?}",
+        pack.manifest.name, pack.manifest.version, pack.manifest.author, pack.manifest.license, crisis_folder);
+    Ok(())
+}
+
+/// `full-crisis fork <crisis>` copies a built-in or packed crisis into the crises folder for editing
+pub fn fork(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let template_name = match args.first() {
+        Some(template_name) => template_name,
+        None => {
+            let catalog = CrisisCatalog::get();
+            let available: Vec<String> = catalog.entries().iter()
+                .map(|entry| format!("{} ({})", entry.template_name, entry.source))
+                .collect();
+            return Err(format!("Usage: full-crisis fork <crisis>\nAvailable crises: {}", available.join(", ")).into());
+        }
+    };
+
+    let crisis_folder = PlayableCrises::fork_to_crises_folder(template_name)?;
+    println!("Copied '{}' to {:?}; this copy now takes priority over the original", template_name, crisis_folder);
+    Ok(())
+}
+
+/// `full-crisis keygen [output.key]` writes a new secret signing key and prints its public key
+pub fn keygen(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let output = PathBuf::from(args.first().map(|output| output.as_str()).unwrap_or("crisis-signing.key"));
+    if output.exists() {
+        return Err(format!("{:?} already exists", output).into());
+    }
+
+    let mut seed = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut seed);
+    let (secret_key, public_key) = signature::keypair_from_seed(seed);
+    std::fs::write(&output, format!("{}\n", secret_key))?;
+
+    println!("Wrote the secret key to {:?}; keep it private.", output);
+    println!("Public key (add this to trusted keys in Settings): {}", public_key);
+    Ok(())
+}
+
+/// `full-crisis sign <crisis folder> <key file>` writes signature.toml into the crisis folder.
+/// Sign before running `pack`, the signature travels inside the pack.
+pub fn sign(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let (crisis_folder, key_file) = match (args.first(), args.get(1)) {
+        (Some(crisis_folder), Some(key_file)) => (Path::new(crisis_folder), Path::new(key_file)),
+        _ => return Err("Usage: full-crisis sign <crisis folder> <key file>".into()),
+    };
+    if !crisis_folder.join("crisis.toml").exists() {
+        return Err(format!("{:?} does not contain a crisis.toml", crisis_folder).into());
+    }
+
+    let secret_key = std::fs::read_to_string(key_file)
+        .map_err(|e| format!("Cannot read {:?}: {}", key_file, e))?;
+    let signing_key = signature::parse_signing_key(&secret_key)?;
+
+    let mut files = vec![];
+    collect_files(crisis_folder, crisis_folder, &mut files)?;
+    let crisis_signature = signature::sign_files(&files, &signing_key);
+
+    let signature_path = crisis_folder.join(signature::SIGNATURE_FILE_NAME);
+    std::fs::write(&signature_path, crisis_signature.to_toml()?)?;
+    println!("Signed {} files in {:?} with public key {}", files.len(), crisis_folder, crisis_signature.public_key);
+    Ok(())
+}
+
+fn collect_files(base_path: &Path, current_path: &Path, files: &mut Vec<(String, Vec<u8>)>) -> Result<(), std::io::Error> {
+    for entry in std::fs::read_dir(current_path)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(base_path, &path, files)?;
+        } else if let Ok(relative_path) = path.strip_prefix(base_path) {
+            files.push((relative_path.to_string_lossy().replace('\\', "/"), std::fs::read(&path)?));
+        }
+    }
+    Ok(())
+}
+
+/// `full-crisis new-crisis <Name> [parent folder] --languages eng,spa` creates a playable crisis
+/// skeleton, by default inside the crises folder from settings
+pub fn new_crisis(args: &[String], languages: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let display_name = match args.first() {
+        Some(display_name) if !display_name.trim().is_empty() => display_name.trim(),
+        _ => return Err("Usage: full-crisis new-crisis <Name> [parent folder] --languages eng,spa".into()),
+    };
+    let languages = check_languages(languages)?;
+
+    let template_name: String = display_name.chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' { c } else { '_' })
+        .collect();
+    let crisis_id = template_name.to_lowercase();
+
+    let catalog = CrisisCatalog::get();
+    if let Some(entry) = catalog.entries().iter().find(|entry| {
+        entry.template_name.eq_ignore_ascii_case(&template_name)
+            || entry.metadata.as_ref().map(|metadata| metadata.id == crisis_id).unwrap_or(false)
+    }) {
+        return Err(format!("A crisis named '{}' already exists ({}); pick another name", entry.template_name, entry.source).into());
+    }
+
+    let parent_folder = match args.get(1) {
+        Some(parent_folder) => PathBuf::from(parent_folder),
+        None => PathBuf::from(full_crisis::gui::GameWindow::load_settings().game_crises_folder),
+    };
+    let crisis_folder = parent_folder.join(&template_name);
+    if crisis_folder.exists() {
+        return Err(format!("{:?} already exists", crisis_folder).into());
+    }
+    std::fs::create_dir_all(crisis_folder.join("scenes"))?;
+
+    for (file_name, data) in PLACEHOLDER_ASSETS {
+        std::fs::write(crisis_folder.join(file_name), data)?;
+    }
+    std::fs::write(crisis_folder.join("crisis.toml"), crisis_toml(display_name, &crisis_id, &languages))?;
+
+    let scene_text = |language: &str| format!("[{}] {{character_name}}, this is where {} begins. Describe the situation here.", language, display_name);
+    let ending_text = |language: &str| format!("[{}] The crisis is over. Describe how it ended here.", language);
+    std::fs::write(
+        crisis_folder.join("scenes").join(format!("{}.toml", STARTING_SCENE)),
+        scene_toml(&template_name, &languages, &scene_text, &[ENDING_SCENE]),
+    )?;
+    std::fs::write(
+        crisis_folder.join("scenes").join(format!("{}.toml", ENDING_SCENE)),
+        scene_toml(&template_name, &languages, &ending_text, &[]),
+    )?;
+    CrisisCatalog::invalidate();
+
+    println!("Created '{}' in {:?}", display_name, crisis_folder);
+    println!("Run `full-crisis test` to check it, and `full-crisis new-scene {} <scene id>` to add scenes.", template_name);
+    Ok(())
+}
+
+/// `full-crisis new-scene <crisis> <scene id>` adds a scene stub to a crisis folder. The crisis is
+/// either a path to a crisis folder or the name of a crisis in the crises folder.
+pub fn new_scene(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let (crisis, scene_id) = match (args.first(), args.get(1)) {
+        (Some(crisis), Some(scene_id)) => (crisis, scene_id),
+        _ => return Err("Usage: full-crisis new-scene <crisis> <scene id>".into()),
+    };
+    if scene_id.is_empty() || !scene_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err(format!("Scene id '{}' may only use letters, digits, '_' and '-'", scene_id).into());
+    }
+
+    let crisis_folder = find_crisis_folder(crisis)?;
+    let template_name = crisis_folder.canonicalize()?
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or_else(|| format!("Cannot name a crisis after {:?}", crisis_folder))?;
+
+    let scene_path = crisis_folder.join("scenes").join(format!("{}.toml", scene_id));
+    if scene_path.exists() {
+        return Err(format!("{:?} already exists", scene_path).into());
+    }
+
+    // Write the stub in every language the crisis is named in
+    let crisis_toml = std::fs::read_to_string(crisis_folder.join("crisis.toml"))?;
+    let crisis = full_crisis::crisis::parse_crisis_toml(&crisis_toml)?;
+    let mut languages: Vec<String> = crisis.name.keys().cloned().collect();
+    languages.sort_by_key(|language| (*language != crisis.story.default_language, language.clone()));
+    if languages.is_empty() {
+        languages.push(crisis.story.default_language.clone());
+    }
+
+    let scene_text = |language: &str| format!("[{}] Describe the '{}' scene here.", language, scene_id);
+    std::fs::create_dir_all(crisis_folder.join("scenes"))?;
+    std::fs::write(&scene_path, scene_toml(&template_name, &languages, &scene_text, &[]))?;
+    CrisisCatalog::invalidate();
+
+    println!("Created {:?}", scene_path);
+    println!("Add a choice with leads_to = \"{}\" to an existing scene so players can reach it.", scene_id);
+    Ok(())
+}
+
+/// `full-crisis migrate <crisis>` moves inline `[scenes.*]` tables into `scenes/<id>.toml` files
+pub fn migrate(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let crisis = match args.first() {
+        Some(crisis) => crisis,
+        None => return Err("Usage: full-crisis migrate <crisis>".into()),
+    };
+    let crisis_folder = find_crisis_folder(crisis)?;
+
+    let scene_ids = full_crisis::crisis::migrate::migrate_folder(&crisis_folder)?;
+    CrisisCatalog::invalidate();
+    if scene_ids.is_empty() {
+        println!("{:?} has no inline scenes to migrate", crisis_folder);
+    } else {
+        println!("Moved {} scenes into {:?}: {}", scene_ids.len(), crisis_folder.join("scenes"), scene_ids.join(", "));
+    }
+    Ok(())
+}
+
+/// `full-crisis flatten <crisis> [output.toml]` folds scene files back into one crisis.toml,
+/// either in place or into a separate file for review
+pub fn flatten(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let crisis = match args.first() {
+        Some(crisis) => crisis,
+        None => return Err("Usage: full-crisis flatten <crisis> [output.toml]".into()),
+    };
+    let crisis_folder = find_crisis_folder(crisis)?;
+    let output = args.get(1).map(PathBuf::from);
+
+    let flattened = full_crisis::crisis::migrate::flatten_folder(&crisis_folder, output.as_deref())?;
+    if output.is_none() {
+        CrisisCatalog::invalidate();
+    }
+    println!("Wrote the flattened crisis to {:?}", flattened);
+    Ok(())
+}
+
+/// `full-crisis i18n-report [crisis or ui ...]` lists missing translations and mismatched `{placeholders}`
+/// for every crisis and the UI table. Returns false when any placeholders are mismatched.
+pub fn i18n_report(args: &[String], languages: &[String], verbosity: u8) -> Result<bool, Box<dyn std::error::Error>> {
+    use full_crisis::translations::coverage::{self, CoverageReport};
+
+    let languages: Vec<String> = if languages.is_empty() {
+        full_crisis::language::get_available_languages().into_iter().map(|(code, _)| code).collect()
+    } else {
+        check_languages(languages)?
+    };
+    let selected = |subject: &str| args.is_empty() || args.iter().any(|arg| arg.eq_ignore_ascii_case(subject));
+
+    let mut reports = vec![];
+    let catalog = CrisisCatalog::get();
+    for entry in catalog.entries() {
+        if !selected(&entry.template_name) {
+            continue;
+        }
+        match full_crisis::crisis::load_crisis(&entry.template_name) {
+            Ok(crisis) => reports.push(CoverageReport::build(
+                &entry.template_name,
+                &crisis.story.default_language,
+                &coverage::crisis_text_entries(&crisis),
+                &languages,
+            ).ignore_placeholders(&coverage::gender_form_names(&crisis)).check_pronouns(&crisis)),
+            Err(e) => println!("❌ {}: cannot load: {}\n", entry.template_name, e),
+        }
+    }
+    if selected("ui") {
+        reports.push(CoverageReport::build("UI", "eng", &coverage::ui_text_entries(), &languages));
+    }
+    if reports.is_empty() {
+        return Err(format!("No crisis named {}", args.join(", ")).into());
+    }
+
+    for report in reports.iter() {
+        println!("{} ({} texts, placeholders checked against {})", report.subject, report.total, report.reference_language);
+        for language in languages.iter() {
+            let missing = report.missing_in(language);
+            if missing.is_empty() {
+                println!("  ✅ {}: complete", language);
+            } else if missing.len() == report.total && verbosity == 0 {
+                println!("  ⚠️  {}: not translated (run with -v to list every text)", language);
+            } else {
+                println!("  ⚠️  {}: {:.0}% ({} missing)", language, report.coverage(language) * 100.0, missing.len());
+                for id in missing {
+                    println!("    - {}", id);
+                }
+            }
+        }
+        for mismatch in report.mismatched.iter() {
+            println!("  ❌ {} [{}]: expected {} but found {}",
+                mismatch.id, mismatch.language, describe_placeholders(&mismatch.expected), describe_placeholders(&mismatch.found));
+        }
+        for (language, gender) in report.missing_pronouns.iter() {
+            println!("  ⚠️  {}: uses {{they}} placeholders but has no {} pronouns, add [pronouns.{}] {} = \"...\"",
+                language, gender.key(), gender.key(), language);
+        }
+        println!();
+    }
+
+    let mismatch_count: usize = reports.iter().map(|report| report.mismatched.len()).sum();
+    if mismatch_count > 0 {
+        println!("{} texts have mismatched placeholders", mismatch_count);
+    }
+    Ok(mismatch_count == 0)
+}
+
+/// `full-crisis i18n-export <crisis> --lang fra [output.po]` writes every crisis text into a PO file for translators
+pub fn i18n_export(args: &[String], languages: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    use full_crisis::translations::po::PoFile;
+
+    let crisis = match args.first() {
+        Some(crisis) => crisis,
+        None => return Err("Usage: full-crisis i18n-export <crisis> --lang <language> [output.po]".into()),
+    };
+    let language = match check_languages(languages)?.as_slice() {
+        [language] if !languages.is_empty() => language.clone(),
+        _ => return Err("Choose exactly one language to export, e.g. --lang fra".into()),
+    };
+
+    // A crisis folder on disk, or any crisis the game can load (built-in and packed ones included)
+    let (template_name, definition) = if Path::new(crisis).join("crisis.toml").exists() {
+        let template_name = Path::new(crisis).canonicalize()?
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| crisis.clone());
+        (template_name, full_crisis::crisis::migrate::load_folder(Path::new(crisis))?)
+    } else {
+        (crisis.clone(), full_crisis::crisis::load_crisis(crisis)?)
+    };
+
+    let po = PoFile::from_crisis(&template_name, &definition, &language);
+    let output = match args.get(1) {
+        Some(output) => PathBuf::from(output),
+        None => PathBuf::from(format!("{}.{}.po", template_name, language)),
+    };
+    std::fs::write(&output, po.to_po_string())?;
+
+    let translated = po.entries.iter().filter(|entry| !entry.translation.is_empty()).count();
+    println!("Exported {} texts ({} already translated) from '{}' into {:?}", po.entries.len(), translated, template_name, output);
+    Ok(())
+}
+
+/// `full-crisis i18n-import <crisis> <file.po>` merges translations into crisis.toml and the scene files
+pub fn i18n_import(args: &[String], languages: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    use full_crisis::translations::po::{self, PoFile};
+
+    let (crisis, po_path) = match (args.first(), args.get(1)) {
+        (Some(crisis), Some(po_path)) => (crisis, Path::new(po_path)),
+        _ => return Err("Usage: full-crisis i18n-import <crisis> <file.po>".into()),
+    };
+    let crisis_folder = find_crisis_folder(crisis)?;
+
+    let contents = std::fs::read_to_string(po_path)
+        .map_err(|e| format!("Cannot read {:?}: {}", po_path, e))?;
+    let po_file = PoFile::parse(&contents).map_err(|e| format!("{:?}: {}", po_path, e))?;
+    if !languages.is_empty() && !languages.iter().any(|language| language.trim() == po_file.language) {
+        return Err(format!("{:?} holds '{}' translations, not {}", po_path, po_file.language, languages.join(", ")).into());
+    }
+
+    let summary = po::import_into_folder(&crisis_folder, &po_file)?;
+    CrisisCatalog::invalidate();
+
+    println!("Imported {} '{}' translations into {:?}", summary.applied, po_file.language, crisis_folder);
+    if summary.skipped_fuzzy > 0 {
+        println!("Skipped {} translations marked fuzzy", summary.skipped_fuzzy);
+    }
+    if !summary.stale.is_empty() {
+        println!("⚠️  Skipped {} translations whose source text changed since the export; export again and translate these: {}", summary.stale.len(), summary.stale.join(", "));
+    }
+    if !summary.unknown.is_empty() {
+        println!("⚠️  Ignored texts that no longer exist in the crisis: {}", summary.unknown.join(", "));
+    }
+    Ok(())
+}
+
+fn describe_placeholders(placeholders: &std::collections::BTreeSet<String>) -> String {
+    if placeholders.is_empty() {
+        return "no placeholders".to_string();
+    }
+    placeholders.iter().map(|name| format!("{{{}}}", name)).collect::<Vec<_>>().join(", ")
+}
+
+/// A path to a crisis folder, or the name of a crisis in the crises folder
+fn find_crisis_folder(crisis: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    if Path::new(crisis).join("crisis.toml").exists() {
+        return Ok(PathBuf::from(crisis));
+    }
+    let crisis_folder = PathBuf::from(full_crisis::gui::GameWindow::load_settings().game_crises_folder).join(crisis);
+    if !crisis_folder.join("crisis.toml").exists() {
+        return Err(format!("{:?} is not a crisis folder; use `full-crisis fork {}` first for built-in or packed crises", crisis_folder, crisis).into());
+    }
+    Ok(crisis_folder)
+}
+
+const STARTING_SCENE: &str = "introduction";
+const ENDING_SCENE: &str = "ending";
+
+/// Assets every new crisis starts with, so its scenes reference files that exist
+const PLACEHOLDER_ASSETS: [(&str, &[u8]); 3] = [
+    ("background.png", include_bytes!("../scaffold/background.png")),
+    ("character.png", include_bytes!("../scaffold/character.png")),
+    ("ambience.wav", include_bytes!("../scaffold/ambience.wav")),
+];
+
+fn check_languages(languages: &[String]) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut checked: Vec<String> = vec![];
+    for language in languages.iter().map(|language| language.trim().to_lowercase()) {
+        if !full_crisis::language::is_language_supported(&language) {
+            let supported: Vec<String> = full_crisis::language::get_available_languages().into_iter().map(|(code, _)| code).collect();
+            return Err(format!("Unsupported language '{}'; use ISO 639-3 codes from: {}", language, supported.join(", ")).into());
+        }
+        if !checked.contains(&language) {
+            checked.push(language);
+        }
+    }
+    if checked.is_empty() {
+        checked.push("eng".to_string());
+    }
+    Ok(checked)
+}
+
+fn toml_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn crisis_toml(display_name: &str, crisis_id: &str, languages: &[String]) -> String {
+    let per_language = |text: &dyn Fn(&str) -> String| -> String {
+        languages.iter()
+            .map(|language| format!("{} = {}\n", language, toml_quote(&text(language))))
+            .collect()
+    };
+    // The player's starting names, per gender; other roles add [character_names.<role>.<gender>] tables
+    let character_names: String = [
+        ("female", "[\"Jordan Lee\", \"Morgan Hayes\"]"),
+        ("male", "[\"Alex Carter\", \"Sam Reyes\"]"),
+        ("neutral", "[\"Riley Quinn\", \"Casey Brooks\"]"),
+    ].iter()
+        .map(|(gender, names)| {
+            let lists: String = languages.iter()
+                .map(|language| format!("{} = {}\n", language, names))
+                .collect();
+            format!("[character_names.player.{}]\n{}\n", gender, lists)
+        })
+        .collect();
+
+    format!(r#"# {display_name}
+
+[metadata]
+id = {id}  # Must be unique across every installed crisis
+version = "0.1"
+author = "Your Name"
+description_key = {description_key}
+license = "All rights reserved"  # Copied into the manifest by `full-crisis pack`
+difficulty = "medium"  # easy, medium or hard, shown in the crisis browser
+tags = []              # Browser filters, e.g. ["fire", "dispatch"]
+# cover_image = "My_Crisis/cover.png"  # Defaults to the starting scene's background
+
+[name]
+{name}
+[description]
+{description}
+{character_names}[story]
+starting_scene = "{starting_scene}"
+default_language = "{default_language}"
+
+[mechanics]
+time_limit_minutes = 15
+save_progress = true
+allow_restart = true   # Show a Restart button that starts the crisis over
+allow_rewind = true    # Let players rewind to earlier decisions
+track_decisions = true # Keep a decision log players can open during the game
+# score_variable = "..." # Variable whose value at an ending is kept as the best score
+
+[conditions]
+
+# How the HUD shows each variable; a plain name such as "budget" is a counter
+[[conditions.variables]]
+name = "stress_level"
+initial = 5
+min = 0
+max = 10
+style = "bar"   # bar, counter or hidden
+warn_above = 7  # Drawn as a warning above this; warn_below works the same way
+
+[conditions.variables.label]
+{stress_label}
+[conditions.choice_effects]
+# Applied when a choice leads to the named scene
+"{ending_scene}" = {{ stress_level = -1 }}
+"#,
+        display_name = display_name,
+        id = toml_quote(crisis_id),
+        description_key = toml_quote(&format!("{}_desc", crisis_id)),
+        name = per_language(&|_| display_name.to_string()),
+        description = per_language(&|language| format!("[{}] A one or two sentence pitch for {}.", language, display_name)),
+        stress_label = per_language(&|language| format!("[{}] Stress", language)),
+        character_names = character_names,
+        starting_scene = STARTING_SCENE,
+        default_language = languages.first().map(|language| language.as_str()).unwrap_or("eng"),
+        ending_scene = ENDING_SCENE,
+    )
+}
+
+fn scene_toml(template_name: &str, languages: &[String], text: &dyn Fn(&str) -> String, leads_to: &[&str]) -> String {
+    let mut scene = format!(
+        "background_image = \"{template_name}/background.png\"\nspeaking_character_image = \"{template_name}/character.png\"\nbackground_audio = \"{template_name}/ambience.wav\"\n\n[text]\n",
+        template_name = template_name,
+    );
+    for language in languages {
+        scene.push_str(&format!("{} = {}\n", language, toml_quote(&text(language))));
+    }
+    for next_scene in leads_to {
+        scene.push_str("\n[[choices]]\n");
+        for language in languages {
+            scene.push_str(&format!("text.{} = {}\n", language, toml_quote(&format!("[{}] Continue to {}", language, next_scene))));
+        }
+        scene.push_str(&format!("leads_to = \"{}\"\n", next_scene));
+    }
+    scene
+}
@@ -125,10 +125,7 @@ pub async fn run() -> Result<(), full_crisis::err::BoxError> {
     
     // Create crisis menu items for new game
     let crisis_names = full_crisis::crisis::get_crisis_names_localized(&settings.language);
-    let crisis_items: Vec<MenuItem<String>> = crisis_names
-        .iter()
-        .map(|name| MenuItem::item(name.clone(), name.clone()))
-        .collect();
+    let crisis_items = crisis_menu_items(&crisis_names, &settings.language);
     
     let crises_folder = settings.game_crises_folder.clone();
     let mut app_data = AppData {
@@ -805,6 +802,7 @@ fn handle_crises_folder_input(app_data: &mut AppData, key: KeyEvent) {
             // Save the changes
             app_data.settings.game_crises_folder = app_data.crises_folder_input.clone();
             save_settings(&app_data.settings);
+            full_crisis::crisis::PlayableCrises::set_crises_folder(&app_data.settings.game_crises_folder);
             app_data.state = AppState::Settings;
         }
         KeyCode::Esc => {
@@ -998,10 +996,7 @@ async fn handle_in_game_input(
 fn reset_crisis_menu_to_default(app_data: &mut AppData) {
     // Recreate the crisis menu with current language
     let crisis_names = full_crisis::crisis::get_crisis_names_localized(&app_data.settings.language);
-    let crisis_items: Vec<MenuItem<String>> = crisis_names
-        .iter()
-        .map(|name| MenuItem::item(name.clone(), name.clone()))
-        .collect();
+    let crisis_items = crisis_menu_items(&crisis_names, &app_data.settings.language);
     
     app_data.new_game_crisis_menu_state = MenuState::new(vec![MenuItem::group("Crisis Types", crisis_items)]);
     
@@ -1016,6 +1011,20 @@ fn reset_crisis_menu_to_default(app_data: &mut AppData) {
     }
 }
 
+/// Menu items labelled with where each crisis comes from, valued by display name
+fn crisis_menu_items(crisis_names: &[String], language: &str) -> Vec<MenuItem<String>> {
+    let catalog = full_crisis::crisis::CrisisCatalog::get();
+    crisis_names
+        .iter()
+        .map(|name| {
+            let label = catalog.find_by_display_name(name, language)
+                .map(|entry| entry.picker_label(language))
+                .unwrap_or_else(|| name.clone());
+            MenuItem::item(label, name.clone())
+        })
+        .collect()
+}
+
 fn start_new_crisis_game(app_data: &mut AppData) {
     // Validate input
     if app_data.new_game_selected_crisis.is_none() {
@@ -1036,7 +1045,7 @@ fn start_new_crisis_game(app_data: &mut AppData) {
             }
             
             let character_name = if app_data.new_game_player_name.is_empty() {
-                full_crisis::crisis::get_random_character_name(&crisis, None, &app_data.settings.language)
+                full_crisis::crisis::get_random_character_name(&crisis, None, app_data.settings.last_player_gender, &app_data.settings.language)
             } else {
                 app_data.new_game_player_name.clone()
             };
@@ -1048,6 +1057,11 @@ fn start_new_crisis_game(app_data: &mut AppData) {
             );
             story_state.current_scene = crisis.story.starting_scene.clone();
             story_state.character_name = character_name;
+            story_state.variables = crisis.conditions.initial_variables();
+            // The CLI has no pronoun picker; it uses the ones last chosen in the GUI
+            story_state.player_gender = app_data.settings.last_player_gender;
+            story_state.pronouns = full_crisis::crisis::PronounSet::parse(&app_data.settings.last_custom_pronouns);
+            story_state.pronouns_language = Some(app_data.settings.language.clone());
             
             // For CLI mode, we transition to the in-game state
             // The actual game state management will be handled through the game's event loop
@@ -1072,6 +1086,6 @@ fn start_new_crisis_game(app_data: &mut AppData) {
 
 fn save_settings(settings: &GameSettings) {
     if let Ok(serialized) = serde_json::to_string(&settings) {
-        full_crisis::storage::set_attr("game_settings", &serialized);
+        full_crisis::storage::set_profile_attr("game_settings", &serialized);
     }
 }
@@ -23,6 +23,9 @@ use once_cell::sync::OnceCell;
 /// cli-based console UI to play the game with
 mod cli;
 
+/// Commands for crisis authors (pack, unpack, ...) which run and exit without a UI
+mod authoring;
+
 pub static CLI_ARGS: OnceCell<Args> = OnceCell::new();
 
 // TODO move beyond hello world
@@ -34,6 +37,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Store some globals for the cli + gui methods to reference
     let _ = CLI_ARGS.set(args.clone());
     let _ = full_crisis::VERBOSITY.set(args.verbosity);
+    if let Some(ref data_dir) = args.data_dir {
+        // Must be set before anything reads settings or saves
+        let _ = full_crisis::DATA_DIR_OVERRIDE.set(data_dir.clone());
+    }
 
     full_crisis::init_global_vars();
     // Use dark_light to detect OS theme
@@ -98,6 +105,82 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
         }
+        Command::Pack => {
+            if let Err(e) = authoring::pack(&args.args) {
+                eprintln!("Pack error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Unpack => {
+            if let Err(e) = authoring::unpack(&args.args) {
+                eprintln!("Unpack error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Fork => {
+            if let Err(e) = authoring::fork(&args.args) {
+                eprintln!("Fork error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Keygen => {
+            if let Err(e) = authoring::keygen(&args.args) {
+                eprintln!("Keygen error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Sign => {
+            if let Err(e) = authoring::sign(&args.args) {
+                eprintln!("Sign error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::NewCrisis => {
+            if let Err(e) = authoring::new_crisis(&args.args, &args.languages) {
+                eprintln!("New crisis error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::NewScene => {
+            if let Err(e) = authoring::new_scene(&args.args) {
+                eprintln!("New scene error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::I18nReport => {
+            match authoring::i18n_report(&args.args, &args.languages, args.verbosity) {
+                Ok(true) => {}
+                Ok(false) => std::process::exit(1),
+                Err(e) => {
+                    eprintln!("Translation report error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::I18nExport => {
+            if let Err(e) = authoring::i18n_export(&args.args, &args.languages) {
+                eprintln!("Translation export error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::I18nImport => {
+            if let Err(e) = authoring::i18n_import(&args.args, &args.languages) {
+                eprintln!("Translation import error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Migrate => {
+            if let Err(e) = authoring::migrate(&args.args) {
+                eprintln!("Migrate error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Flatten => {
+            if let Err(e) = authoring::flatten(&args.args) {
+                eprintln!("Flatten error: {}", e);
+                std::process::exit(1);
+            }
+        }
     }
 
     Ok(())
@@ -110,6 +193,7 @@ fn run_crisis_tests(verbosity: u8) -> Result<(), Box<dyn std::error::Error>> {
     
     println!("Starting crisis validation tests...\n");
     
+    let mut crisis_ids: std::collections::HashMap<String, String> = std::collections::HashMap::new();
     for pc in PlayableCrises::iter() {
         let path = pc.as_ref();
         if path.ends_with("crisis.toml") {
@@ -118,6 +202,9 @@ fn run_crisis_tests(verbosity: u8) -> Result<(), Box<dyn std::error::Error>> {
             
             match load_crisis(&folder_name) {
                 Ok(crisis) => {
+                    if let Some(other_folder) = crisis_ids.insert(crisis.metadata.id.clone(), folder_name.clone()) {
+                        println!("  ⚠️  metadata.id '{}' is also used by {}; give each crisis its own id", crisis.metadata.id, other_folder);
+                    }
                     validate_crisis(&crisis, &folder_name, verbosity)?;
                 }
                 Err(e) => {
@@ -213,6 +300,26 @@ fn validate_crisis(
         }
     }
     
+    if let Some(score_variable) = &crisis.mechanics.score_variable {
+        let declared = crisis.conditions.variable_specs().iter().any(|spec| spec.name == *score_variable);
+        if !declared {
+            warnings.push(format!("mechanics.score_variable: '{}' is not declared in conditions.variables", score_variable));
+        }
+    }
+    
+    for (language, gender) in full_crisis::crisis::pronouns::missing_pronoun_sets(&crisis) {
+        warnings.push(format!(
+            "pronouns: '{}' scene text uses {{they}} placeholders but has no {} pronouns; add [pronouns.{}] {} = \"they/them/their/theirs/themself\"",
+            language, gender.key(), gender.key(), language
+        ));
+    }
+    
+    if let Some(palette) = &crisis.palette {
+        for (name, hex) in palette.invalid_entries() {
+            warnings.push(format!("palette.{}: '{}' is not a #rrggbb color", name, hex));
+        }
+    }
+    
     // Check for disconnected scenes
     let disconnected: Vec<_> = scene_names.difference(&referenced_scenes).collect();
     if !disconnected.is_empty() {
@@ -247,6 +354,26 @@ fn validate_crisis(
 fn dark_light_fallback_theme_detections() -> full_crisis::game::OSColorTheme {
     #[cfg(target_os = "linux")]
     {
+        // GTK_THEME=Adwaita:dark, or a theme named like Adwaita-dark
+        if let Ok(gtk_theme) = std::env::var("GTK_THEME") {
+            if !gtk_theme.is_empty() {
+                return if gtk_theme.to_lowercase().contains("dark") {
+                    full_crisis::game::OSColorTheme::Dark
+                } else {
+                    full_crisis::game::OSColorTheme::Light
+                };
+            }
+        }
+
+        // Desktops without a portal often still set the GTK preference
+        for gtk_version in ["gtk-4.0", "gtk-3.0"] {
+            for prefer_dark in ["gtk-application-prefer-dark-theme=1", "gtk-application-prefer-dark-theme=true"] {
+                if let Some(true) = file_contains(&[".config", gtk_version, "settings.ini"], prefer_dark) {
+                    return full_crisis::game::OSColorTheme::Dark;
+                }
+            }
+        }
+
         // If gimp is installed, read ~/.config/GIMP/3.0/theme.css to see if there is an import to a file /usr/share/gimp/3.0/themes/Default/gimp-dark.css
         match file_contains(&[".config", "GIMP", "3.0", "theme.css",], "themes/Default/gimp-dark.css") {
             Some(true) => return full_crisis::game::OSColorTheme::Dark,
@@ -255,7 +382,7 @@ fn dark_light_fallback_theme_detections() -> full_crisis::game::OSColorTheme {
         }
     }
 
-    // TODO collect OS heuristics here.
+    // Players whose desktop is not detected can pick a theme on the settings screen
 
     full_crisis::game::OSColorTheme::Light
 }
@@ -304,8 +431,22 @@ pub struct Args {
     #[arg(value_enum, default_value_t = Command::Gui)]
     command: Command,
 
+    /// Arguments for the command, e.g. `pack <crisis folder> [output.fcpack]`, `unpack <pack> [destination]`, `fork <crisis>`, `sign <crisis folder> <key file>`,
+    /// `new-crisis <Name> [parent folder]`, `new-scene <crisis> <scene id>`, `migrate <crisis>`, `flatten <crisis> [output.toml]`
+    /// `i18n-report [crisis or ui ...]`, `i18n-export <crisis> [output.po]` or `i18n-import <crisis> <file.po>`
+    args: Vec<String>,
+
+    /// Languages for `new-crisis` to write placeholder text in (default eng), for `i18n-report`
+    /// to check (default all) or for `i18n-export` to export, e.g. `--languages eng,spa` or `--lang fra`
+    #[arg(long, alias = "lang", value_delimiter = ',')]
+    languages: Vec<String>,
+
     #[arg(short, action = clap::ArgAction::Count)]
     verbosity: u8,
+
+    /// Store settings, saved games and caches under this folder instead of the OS directories (portable installs)
+    #[arg(long)]
+    data_dir: Option<std::path::PathBuf>,
 }
 
 #[derive(Clone, Debug, clap::ValueEnum)]
@@ -313,6 +454,21 @@ pub enum Command {
     Gui,
     Cli,
     Test,
+    Pack,
+    Unpack,
+    Fork,
+    Keygen,
+    Sign,
+    NewCrisis,
+    NewScene,
+    Migrate,
+    Flatten,
+    #[value(name = "i18n-report")]
+    I18nReport,
+    #[value(name = "i18n-export")]
+    I18nExport,
+    #[value(name = "i18n-import")]
+    I18nImport,
 }
 
 impl std::fmt::Display for Command {
@@ -321,6 +477,18 @@ impl std::fmt::Display for Command {
             Command::Gui => write!(f, "gui"),
             Command::Cli => write!(f, "cli"),
             Command::Test => write!(f, "test"),
+            Command::Pack => write!(f, "pack"),
+            Command::Unpack => write!(f, "unpack"),
+            Command::Fork => write!(f, "fork"),
+            Command::Keygen => write!(f, "keygen"),
+            Command::Sign => write!(f, "sign"),
+            Command::NewCrisis => write!(f, "new-crisis"),
+            Command::NewScene => write!(f, "new-scene"),
+            Command::Migrate => write!(f, "migrate"),
+            Command::Flatten => write!(f, "flatten"),
+            Command::I18nReport => write!(f, "i18n-report"),
+            Command::I18nExport => write!(f, "i18n-export"),
+            Command::I18nImport => write!(f, "i18n-import"),
         }
     }
 }
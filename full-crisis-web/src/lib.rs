@@ -21,6 +21,9 @@ pub fn start() -> Result<(), JsValue> {
                 stop_background_audio();
             }),
         );
+        full_crisis::set_volume_callback(Box::new(|volume: f32| {
+            set_background_volume(volume);
+        }));
 
         // Detect browser theme + store in variable
         let _ = full_crisis::OS_COLOR_THEME.set(
@@ -38,7 +41,6 @@ pub fn start() -> Result<(), JsValue> {
     .subscription(full_crisis::gui::GameWindow::subscription)
     .theme(full_crisis::gui::GameWindow::theme)
     //.font(include_bytes!("../fonts/icons.ttf").as_slice())
-    .default_font(iced::Font::MONOSPACE)
     .settings(full_crisis::gui::GameWindow::make_app_settings())
     .window(full_crisis::gui::GameWindow::make_window_settings())
     .run()
@@ -60,6 +62,10 @@ export function browser_go_back() {
 const context = new AudioContext();
 let currentAudioSource = null;
 
+// Every source plays through this node so the volume setting applies to all of them
+const volumeNode = context.createGain();
+volumeNode.connect(context.destination);
+
 // Play background audio from a Vec<u8> (Uint8Array)
 export function play_background_audio(bytes) {
     try {
@@ -86,7 +92,7 @@ export function play_background_audio(bytes) {
             const source = context.createBufferSource();
             source.buffer = decodedBuffer;
             source.loop = true; // Loop the audio
-            source.connect(context.destination);
+            source.connect(volumeNode);
             
             // Play the audio
             source.start();
@@ -99,6 +105,11 @@ export function play_background_audio(bytes) {
     }
 }
 
+// Set the volume from 0.0 (muted) to 1.0
+export function set_background_volume(volume) {
+    volumeNode.gain.value = volume;
+}
+
 // Stop any currently playing background audio
 export function stop_background_audio() {
     if (currentAudioSource) {
@@ -112,5 +123,6 @@ extern "C" {
     pub fn browser_go_back();
     pub fn play_background_audio(bytes: &[u8]);
     pub fn stop_background_audio();
+    pub fn set_background_volume(volume: f32);
 }
 
@@ -1,9 +1,14 @@
 use std::env;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use sha2::{Digest, Sha256};
+
 fn main() {
+    check_english_translations();
+    collect_fonts();
+    
     println!("cargo:rerun-if-changed=../audio/");
     
     let out_dir = env::var("OUT_DIR").unwrap();
@@ -44,4 +49,113 @@ fn main() {
     println!("cargo:rustc-env=AUDIO_BUILD_DIR={}", audio_build_dir.display());
     
     println!("Audio files generated successfully");
-}
\ No newline at end of file
+}
+
+/// Collects the bundled UI fonts listed in ../fonts/fonts.toml into OUT_DIR/fonts for the
+/// FontAssets embed. The files come from ../fonts/downloaded/ (filled by fetch_fonts.py) or
+/// FULL_CRISIS_FONTS_DIR; the build never downloads them. A font that is not pinned, missing
+/// or does not match its recorded sha256 is left out with a warning.
+fn collect_fonts() {
+    println!("cargo:rerun-if-changed=../fonts/");
+    println!("cargo:rerun-if-env-changed=FULL_CRISIS_FONTS_DIR");
+    
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let font_build_dir = Path::new(&out_dir).join("fonts");
+    let _ = fs::remove_dir_all(&font_build_dir);
+    fs::create_dir_all(&font_build_dir).expect("Failed to create font build directory");
+    
+    // Set before anything can fail so the embed macro always has a folder
+    println!("cargo:rustc-env=FONT_BUILD_DIR={}", font_build_dir.display());
+    
+    let manifest: toml::Table = fs::read_to_string("../fonts/fonts.toml")
+        .expect("Failed to read ../fonts/fonts.toml")
+        .parse()
+        .expect("Failed to parse ../fonts/fonts.toml");
+    let fonts = manifest.get("fonts").and_then(|fonts| fonts.as_array()).expect("fonts.toml has no [[fonts]]");
+    let field = |font: &toml::Value, name: &str| font.get(name).and_then(|value| value.as_str()).unwrap_or("").to_string();
+    
+    let font_source_dir = env::var("FULL_CRISIS_FONTS_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("../fonts/downloaded"));
+    
+    // The web build leaves these fonts out of the wasm file and downloads them on demand
+    let web_build = env::var("CARGO_CFG_TARGET_ARCH").map(|arch| arch == "wasm32").unwrap_or(false);
+    let mut web_lazy_fonts = Vec::new();
+    let mut skipped = Vec::new();
+    
+    for font in fonts {
+        let file = field(font, "file");
+        let expected = field(font, "sha256");
+        if field(font, "commit").is_empty() || expected.is_empty() {
+            println!("cargo:warning={} is not pinned in ../fonts/fonts.toml, building without it", file);
+            skipped.push(file);
+            continue;
+        }
+        
+        let source = font_source_dir.join(&file);
+        let Ok(bytes) = fs::read(&source) else {
+            println!("cargo:warning={} not found, building without it", source.display());
+            skipped.push(file);
+            continue;
+        };
+        let actual = hex::encode(Sha256::digest(&bytes));
+        if actual != expected {
+            println!("cargo:warning={} has sha256 {}, fonts.toml expects {}; building without it", source.display(), actual, expected);
+            skipped.push(file);
+            continue;
+        }
+        
+        let languages: Vec<String> = font.get("web_lazy_languages")
+            .and_then(|languages| languages.as_array())
+            .map(|languages| languages.iter().filter_map(|language| language.as_str()).map(str::to_string).collect())
+            .unwrap_or_default();
+        if web_build && !languages.is_empty() {
+            web_lazy_fonts.push((file, languages));
+        } else {
+            fs::write(font_build_dir.join(&file), &bytes).expect("Failed to copy font into the build directory");
+        }
+    }
+    
+    if !skipped.is_empty() {
+        println!("cargo:warning=Arabic, Hebrew and CJK text may not render without the bundled fonts; run `uv run fetch_fonts.py` in fonts/ to download them");
+    }
+    
+    write_web_lazy_fonts(&out_dir, &web_lazy_fonts);
+}
+
+/// Writes OUT_DIR/web_lazy_fonts.rs, the (file, languages) table fonts.rs includes
+fn write_web_lazy_fonts(out_dir: &str, fonts: &[(String, Vec<String>)]) {
+    let entries: Vec<String> = fonts.iter()
+        .map(|(file, languages)| format!("({:?}, &{:?})", file, languages))
+        .collect();
+    let source = format!("pub const WEB_LAZY_FONTS: &[(&str, &[&str])] = &[{}];\n", entries.join(", "));
+    fs::write(Path::new(out_dir).join("web_lazy_fonts.rs"), source).expect("Failed to write web_lazy_fonts.rs");
+}
+
+fn check_english_translations() {
+    let keys_path = Path::new("src/translations/keys.rs");
+    let catalog_path = Path::new("src/translations/catalogs/eng.toml");
+    println!("cargo:rerun-if-changed={}", keys_path.display());
+    println!("cargo:rerun-if-changed={}", catalog_path.display());
+    
+    let keys_source = fs::read_to_string(keys_path).expect("Failed to read translation keys");
+    let catalog: toml::Table = fs::read_to_string(catalog_path)
+        .expect("Failed to read the English translation catalog")
+        .parse()
+        .expect("Failed to parse the English translation catalog");
+    
+    // Variants are the "Name," lines inside `pub enum TranslationKey { ... }`
+    let enum_body = keys_source
+        .split_once("pub enum TranslationKey {")
+        .and_then(|(_, rest)| rest.split_once('}'))
+        .map(|(body, _)| body)
+        .expect("Failed to find enum TranslationKey");
+    let missing: Vec<&str> = enum_body.lines()
+        .map(|line| line.split("//").next().unwrap_or("").trim().trim_end_matches(','))
+        .filter(|variant| !variant.is_empty() && !catalog.contains_key(*variant))
+        .collect();
+    
+    if !missing.is_empty() {
+        panic!("{} is missing English text for TranslationKey: {}", catalog_path.display(), missing.join(", "));
+    }
+}
@@ -0,0 +1,410 @@
+use super::types::*;
+use super::PlayableCrises;
+use serde::Deserialize;
+use super::{CrisisSource, SignatureStatus};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Cached catalog, built on first use and dropped whenever the crises folder changes
+static CATALOG: once_cell::sync::Lazy<RwLock<Option<Arc<CrisisCatalog>>>> = once_cell::sync::Lazy::new(|| RwLock::new(None));
+
+/// Only the parts of crisis.toml the menus need; scenes, mechanics and the story are skipped
+#[derive(Debug, Deserialize)]
+struct CrisisHeader {
+    metadata: Option<CrisisMetadata>,
+    #[serde(default)]
+    name: HashMap<String, String>,
+    #[serde(default)]
+    description: HashMap<String, String>,
+    story: Option<CrisisStory>,
+    mechanics: Option<HeaderMechanics>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HeaderMechanics {
+    time_limit_minutes: u32,
+}
+
+/// The one field of the starting scene the catalog reads, for crises without a cover image
+#[derive(Debug, Deserialize)]
+struct SceneBackground {
+    background_image: Option<String>,
+}
+
+/// Search, filters and order of the crisis browser on the New Game screen
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CrisisQuery {
+    pub search: String,
+    pub language: Option<String>, // Only crises available in this language
+    pub tag: Option<String>,
+    pub sort: CrisisSort,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CrisisSort {
+    #[default]
+    Name,
+    Author,
+    Length, // Shortest time limit first
+    Difficulty, // Easiest first
+}
+
+impl CrisisSort {
+    pub const ALL: [CrisisSort; 4] = [CrisisSort::Name, CrisisSort::Author, CrisisSort::Length, CrisisSort::Difficulty];
+
+    pub fn label(&self, language: &str) -> String {
+        use crate::translations::{t, TranslationKey};
+        t(match self {
+            CrisisSort::Name => TranslationKey::SortByName,
+            CrisisSort::Author => TranslationKey::SortByAuthor,
+            CrisisSort::Length => TranslationKey::SortByLength,
+            CrisisSort::Difficulty => TranslationKey::SortByDifficulty,
+        }, language)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CrisisCatalogEntry {
+    pub template_name: String, // Folder name, e.g. "Example_Crisis"
+    pub metadata: Option<CrisisMetadata>, // None when crisis.toml failed to parse
+    pub name: HashMap<String, String>,
+    pub description: HashMap<String, String>,
+    pub languages: Vec<String>, // Languages the crisis is named in, the one it was written in first
+    pub time_limit_minutes: Option<u32>,
+    pub cover_image: Option<String>,
+    pub assets: Vec<String>, // Every file under the crisis folder except crisis.toml and scenes
+    pub scene_files: Vec<String>,
+    pub source: CrisisSource, // Where every file of this crisis is read from
+    pub shadowed_sources: Vec<CrisisSource>, // Lower priority sources holding a crisis folder of the same name
+    pub signature: SignatureStatus,
+}
+
+impl CrisisCatalogEntry {
+    pub fn localized_name(&self, language: &str) -> String {
+        if self.name.is_empty() {
+            // Fallback to folder name with underscores replaced
+            return self.template_name.replace("_", " ");
+        }
+        super::get_localized_text(&self.name, language)
+    }
+
+    pub fn localized_description(&self, language: &str) -> String {
+        super::get_localized_text(&self.description, language)
+    }
+
+    pub fn author(&self) -> Option<&str> {
+        self.metadata.as_ref().map(|metadata| metadata.author.as_str())
+    }
+
+    pub fn tags(&self) -> &[String] {
+        self.metadata.as_ref().map(|metadata| metadata.tags.as_slice()).unwrap_or_default()
+    }
+
+    pub fn difficulty(&self) -> Option<crate::gui::DifficultyLevel> {
+        self.metadata.as_ref().and_then(|metadata| metadata.difficulty)
+    }
+
+    /// True when the name, description, author or a tag contains every word of the search, ignoring case
+    pub fn matches_search(&self, search: &str, language: &str) -> bool {
+        let haystack = [
+            self.localized_name(language),
+            self.localized_description(language),
+            self.author().unwrap_or_default().to_string(),
+            self.tags().join(" "),
+        ].join(" ").to_lowercase();
+        search.to_lowercase().split_whitespace().all(|word| haystack.contains(word))
+    }
+
+    /// Name plus source, e.g. "Fire Dispatch (Built-in)", for lists where several sources meet
+    pub fn picker_label(&self, language: &str) -> String {
+        format!("{} ({})", self.localized_name(language), self.source.label(language))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CrisisCatalog {
+    entries: Vec<CrisisCatalogEntry>,
+    files: Vec<String>,
+    folder_sources: HashMap<String, CrisisSource>, // Winning source for each top-level crisis folder
+    trusted_keys: Vec<String>, // From settings when the catalog was built
+    require_signed: bool,
+}
+
+impl CrisisCatalog {
+    /// Returns the cached catalog, building it if needed
+    pub fn get() -> Arc<CrisisCatalog> {
+        if let Ok(guard) = CATALOG.read() {
+            if let Some(catalog) = guard.as_ref() {
+                return catalog.clone();
+            }
+        }
+
+        let catalog = Arc::new(Self::build());
+        if let Ok(mut guard) = CATALOG.write() {
+            *guard = Some(catalog.clone());
+        }
+        catalog
+    }
+
+    /// Forget the cached catalog; the next lookup rescans every source
+    pub fn invalidate() {
+        if let Ok(mut guard) = CATALOG.write() {
+            *guard = None;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        super::pack::invalidate_packs();
+    }
+
+    fn build() -> CrisisCatalog {
+        let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
+        let settings = crate::gui::GameWindow::load_settings();
+        
+        // Resolve each top-level folder to the highest priority source that has it
+        let mut folder_sources: HashMap<String, CrisisSource> = HashMap::new();
+        let mut shadowed_sources: HashMap<String, Vec<CrisisSource>> = HashMap::new();
+        let mut files = vec![];
+        for (source, source_files) in PlayableCrises::scan_sources() {
+            let mut folders_in_source: Vec<&str> = source_files.iter()
+                .filter_map(|file| file.split_once('/').map(|(folder, _)| folder))
+                .collect();
+            folders_in_source.dedup();
+            for folder in folders_in_source {
+                if folder_sources.contains_key(folder) {
+                    shadowed_sources.entry(folder.to_string()).or_default().push(source.clone());
+                } else {
+                    folder_sources.insert(folder.to_string(), source.clone());
+                }
+            }
+            
+            for file in source_files {
+                let owned_by_source = match file.split_once('/') {
+                    Some((folder, _)) => folder_sources.get(folder) == Some(&source),
+                    None => !files.contains(&file), // Top-level files such as README.md
+                };
+                if owned_by_source {
+                    files.push(file);
+                }
+            }
+        }
+        files.sort();
+
+        let mut entries = vec![];
+        for path in files.iter() {
+            let Some(template_name) = path.strip_suffix("/crisis.toml") else {
+                continue;
+            };
+            let folder = template_name.split('/').next().unwrap_or(template_name);
+            let Some(source) = folder_sources.get(folder).cloned() else {
+                continue;
+            };
+
+            let header = PlayableCrises::get_from_source(&source, path)
+                .ok_or_else(|| "file not found".to_string())
+                .and_then(|file| String::from_utf8(file.data.into_owned()).map_err(|e| e.to_string()))
+                .and_then(|content| toml::from_str::<CrisisHeader>(&content).map_err(|e| e.to_string()));
+
+            let header = match header {
+                Ok(header) => header,
+                Err(e) => {
+                    if *verbosity > 0 {
+                        eprintln!("CrisisCatalog: Could not read header of '{}': {}", path, e);
+                    }
+                    CrisisHeader {
+                        metadata: None,
+                        name: HashMap::new(),
+                        description: HashMap::new(),
+                        story: None,
+                        mechanics: None,
+                    }
+                }
+            };
+
+            let folder_prefix = format!("{}/", template_name);
+            let scenes_prefix = format!("{}/scenes/", template_name);
+            let mut assets = vec![];
+            let mut scene_files = vec![];
+            for file in files.iter().filter(|f| f.starts_with(&folder_prefix)) {
+                if file.starts_with(&scenes_prefix) && file.ends_with(".toml") {
+                    scene_files.push(file.clone());
+                } else if file != path {
+                    assets.push(file.clone());
+                }
+            }
+
+            if *verbosity > 0 {
+                if let Some(shadowed) = shadowed_sources.get(folder) {
+                    eprintln!("CrisisCatalog: '{}' from {} shadows {:?}", template_name, source, shadowed.iter().map(|s| s.to_string()).collect::<Vec<_>>());
+                }
+            }
+
+            let signature = match source {
+                CrisisSource::Embedded => SignatureStatus::BuiltIn,
+                _ => super::signature::verify_files(&Self::read_crisis_files(&source, template_name, &files), &settings.trusted_keys),
+            };
+            if *verbosity > 0 && signature != SignatureStatus::Unsigned && signature != SignatureStatus::BuiltIn {
+                eprintln!("CrisisCatalog: '{}' signature: {:?}", template_name, signature);
+            }
+
+            let mut languages: Vec<String> = header.name.keys().cloned().collect();
+            languages.sort();
+            if let Some(ref story) = header.story {
+                languages.retain(|language| *language != story.default_language);
+                languages.insert(0, story.default_language.clone());
+            }
+
+            let cover_image = header.metadata.as_ref()
+                .and_then(|metadata| metadata.cover_image.clone())
+                .or_else(|| {
+                    let story = header.story.as_ref()?;
+                    let scene_path = format!("{}{}.toml", scenes_prefix, story.starting_scene);
+                    let scene = PlayableCrises::get_from_source(&source, &scene_path)?;
+                    let scene = toml::from_str::<SceneBackground>(std::str::from_utf8(&scene.data).ok()?).ok()?;
+                    scene.background_image
+                });
+
+            entries.push(CrisisCatalogEntry {
+                template_name: template_name.to_string(),
+                metadata: header.metadata,
+                name: header.name,
+                description: header.description,
+                languages,
+                time_limit_minutes: header.mechanics.map(|mechanics| mechanics.time_limit_minutes),
+                cover_image,
+                assets,
+                scene_files,
+                shadowed_sources: shadowed_sources.get(folder).cloned().unwrap_or_default(),
+                source,
+                signature,
+            });
+        }
+
+        if *verbosity > 0 {
+            eprintln!("CrisisCatalog: Indexed {} crises from {} files", entries.len(), files.len());
+        }
+
+        CrisisCatalog {
+            entries,
+            files,
+            folder_sources,
+            trusted_keys: settings.trusted_keys,
+            require_signed: settings.require_signed_crises,
+        }
+    }
+
+    /// Every file of one crisis, with paths relative to its folder
+    fn read_crisis_files(source: &CrisisSource, template_name: &str, files: &[String]) -> Vec<(String, Vec<u8>)> {
+        let prefix = format!("{}/", template_name);
+        files.iter()
+            .filter_map(|file| {
+                let relative_path = file.strip_prefix(&prefix)?;
+                let data = PlayableCrises::get_from_source(source, file)?;
+                Some((relative_path.to_string(), data.data.into_owned()))
+            })
+            .collect()
+    }
+
+    /// True when settings refuse crises that are not built in or signed by a trusted key
+    pub fn require_signed(&self) -> bool {
+        self.require_signed
+    }
+
+    /// Checks a crisis signature against the files as they are right now, not as they were when the catalog was built
+    pub fn verify(&self, template_name: &str) -> SignatureStatus {
+        self.read_verified(template_name).0
+    }
+
+    /// Reads every file of a crisis once, with paths relative to its folder, and checks the
+    /// signature against exactly those bytes. Loading from the returned files instead of reading
+    /// them again means nothing swapped in after the check is ever played.
+    pub fn read_verified(&self, template_name: &str) -> (SignatureStatus, Vec<(String, Vec<u8>)>) {
+        let folder = template_name.split('/').next().unwrap_or(template_name);
+        let Some(source) = self.folder_sources.get(folder) else {
+            return (SignatureStatus::Unsigned, vec![]);
+        };
+        let files = Self::read_crisis_files(source, template_name, &self.files);
+        let signature = match source {
+            CrisisSource::Embedded => SignatureStatus::BuiltIn,
+            _ => super::signature::verify_files(&files, &self.trusted_keys),
+        };
+        (signature, files)
+    }
+
+    /// Crises that may be offered to the player under the current signature settings
+    pub fn playable_entries(&self) -> impl Iterator<Item = &CrisisCatalogEntry> {
+        self.entries.iter().filter(|entry| !self.require_signed || entry.signature.is_trusted())
+    }
+
+    /// Playable crises matching the crisis browser's search and filters, in its sort order
+    pub fn browse(&self, query: &CrisisQuery, language: &str) -> Vec<&CrisisCatalogEntry> {
+        let mut entries: Vec<&CrisisCatalogEntry> = self.playable_entries()
+            .filter(|entry| query.language.as_ref().map_or(true, |wanted| entry.languages.contains(wanted)))
+            .filter(|entry| query.tag.as_ref().map_or(true, |wanted| entry.tags().contains(wanted)))
+            .filter(|entry| entry.matches_search(&query.search, language))
+            .collect();
+        let by_name = |a: &&CrisisCatalogEntry, b: &&CrisisCatalogEntry| a.localized_name(language).to_lowercase().cmp(&b.localized_name(language).to_lowercase());
+        match query.sort {
+            CrisisSort::Name => entries.sort_by(by_name),
+            CrisisSort::Author => entries.sort_by(|a, b| a.author().unwrap_or_default().to_lowercase().cmp(&b.author().unwrap_or_default().to_lowercase()).then_with(|| by_name(a, b))),
+            // Crises without a time limit or difficulty go last
+            CrisisSort::Length => entries.sort_by(|a, b| a.time_limit_minutes.unwrap_or(u32::MAX).cmp(&b.time_limit_minutes.unwrap_or(u32::MAX)).then_with(|| by_name(a, b))),
+            CrisisSort::Difficulty => entries.sort_by(|a, b| match (a.difficulty(), b.difficulty()) {
+                (Some(a_difficulty), Some(b_difficulty)) => a_difficulty.cmp(&b_difficulty),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            }.then_with(|| by_name(a, b))),
+        }
+        entries
+    }
+
+    /// Every language any playable crisis is named in, for the browser's language filter
+    pub fn languages(&self) -> Vec<String> {
+        let mut languages: Vec<String> = self.playable_entries()
+            .flat_map(|entry| entry.languages.iter().cloned())
+            .collect();
+        languages.sort();
+        languages.dedup();
+        languages
+    }
+
+    /// Every tag used by a playable crisis, for the browser's tag filter
+    pub fn tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self.playable_entries()
+            .flat_map(|entry| entry.tags().iter().cloned())
+            .collect();
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
+    /// The source a top-level crisis folder is read from, if any source has it
+    pub fn source_of(&self, crisis_folder: &str) -> Option<CrisisSource> {
+        self.folder_sources.get(crisis_folder).cloned()
+    }
+
+    pub fn entries(&self) -> &[CrisisCatalogEntry] {
+        &self.entries
+    }
+
+    pub fn files(&self) -> &[String] {
+        &self.files
+    }
+
+    pub fn get_entry(&self, template_name: &str) -> Option<&CrisisCatalogEntry> {
+        self.entries.iter().find(|entry| entry.template_name == template_name)
+    }
+
+    pub fn find_by_display_name(&self, display_name: &str, language: &str) -> Option<&CrisisCatalogEntry> {
+        self.entries.iter().find(|entry| entry.localized_name(language) == display_name)
+    }
+
+    /// Matches a label from `CrisisCatalogEntry::picker_label`
+    pub fn find_by_picker_label(&self, label: &str, language: &str) -> Option<&CrisisCatalogEntry> {
+        self.entries.iter().find(|entry| entry.picker_label(language) == label)
+    }
+
+    /// Matches the display name against every language a crisis is named in
+    pub fn find_by_any_display_name(&self, display_name: &str) -> Option<&CrisisCatalogEntry> {
+        self.entries.iter().find(|entry| entry.name.values().any(|name| name == display_name))
+    }
+}
@@ -0,0 +1,181 @@
+use super::types::GameState;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// What a decision changed, recorded right before it was made. The decision
+/// log and everything else a choice leaves alone come from the current state,
+/// so an entry stays small however long the story gets.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "HistoryEntryRecord")]
+pub struct HistoryEntry {
+    pub scene: String,
+    pub variables: HashMap<String, i32>,
+    pub text_inputs: HashMap<String, String>,
+    pub character_type: Option<String>, // Choices can hand the player another role, with its own name
+    pub character_name: String,
+    pub decision_count: usize, // Length of the decision log at that point
+}
+
+/// `HistoryEntry` as stored in saves. Saves written before entries were slimmed
+/// down hold full `GameState` snapshots, whose decision log gives the count.
+#[derive(Deserialize)]
+struct HistoryEntryRecord {
+    #[serde(alias = "current_scene")]
+    scene: String,
+    #[serde(default)]
+    variables: HashMap<String, i32>,
+    #[serde(default)]
+    text_inputs: HashMap<String, String>,
+    #[serde(default)]
+    character_type: Option<String>,
+    #[serde(default)]
+    character_name: String,
+    decision_count: Option<usize>,
+    #[serde(default)]
+    decisions: Vec<serde::de::IgnoredAny>,
+}
+
+impl From<HistoryEntryRecord> for HistoryEntry {
+    fn from(record: HistoryEntryRecord) -> Self {
+        Self {
+            decision_count: record.decision_count.unwrap_or(record.decisions.len()),
+            scene: record.scene,
+            variables: record.variables,
+            text_inputs: record.text_inputs,
+            character_type: record.character_type,
+            character_name: record.character_name,
+        }
+    }
+}
+
+impl HistoryEntry {
+    pub fn of(state: &GameState) -> Self {
+        Self {
+            scene: state.current_scene.clone(),
+            variables: state.variables.clone(),
+            text_inputs: state.text_inputs.clone(),
+            character_type: state.character_type.clone(),
+            character_name: state.character_name.clone(),
+            decision_count: state.decisions.len(),
+        }
+    }
+
+    /// `current` put back to this point, dropping the decisions made since
+    pub fn restore(&self, current: &GameState) -> GameState {
+        let mut state = current.clone();
+        state.current_scene = self.scene.clone();
+        state.variables = self.variables.clone();
+        state.text_inputs = self.text_inputs.clone();
+        state.character_type = self.character_type.clone();
+        state.character_name = self.character_name.clone();
+        state.decisions.truncate(self.decision_count);
+        state
+    }
+}
+
+/// Stack of `HistoryEntry`s taken right before each decision.
+/// Popping an entry puts the player back in front of the choice they made,
+/// so trainees can go back and try the other branch.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GameHistory {
+    entries: Vec<HistoryEntry>,
+}
+
+impl GameHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the state as it was before a choice was applied
+    pub fn record(&mut self, state: &GameState) {
+        self.entries.push(HistoryEntry::of(state));
+    }
+
+    pub fn can_rewind(&self) -> bool {
+        !self.entries.is_empty()
+    }
+
+    /// Undo the most recent decision
+    pub fn rewind(&mut self) -> Option<HistoryEntry> {
+        self.entries.pop()
+    }
+
+    /// Return to the scene at `index` in the timeline, discarding every later entry
+    pub fn jump_to(&mut self, index: usize) -> Option<HistoryEntry> {
+        if index >= self.entries.len() {
+            return None;
+        }
+        self.entries.truncate(index + 1);
+        self.entries.pop()
+    }
+
+    /// Past points in the order they were visited, oldest first
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crisis::DecisionRecord;
+
+    fn decision(scene: &str) -> DecisionRecord {
+        DecisionRecord {
+            scene: scene.to_string(),
+            choice_index: 0,
+            text_input: None,
+            variable_changes: Vec::new(),
+            scene_text: None,
+            choice_text: None,
+        }
+    }
+
+    #[test]
+    fn rewinding_drops_later_decisions_and_keeps_the_rest() {
+        let mut state = GameState::new("test".to_string(), "eng".to_string(), "Test".to_string());
+        state.current_scene = "intro".to_string();
+        let mut history = GameHistory::new();
+
+        history.record(&state);
+        state.decisions.push(decision("intro"));
+        state.current_scene = "middle".to_string();
+        state.variables.insert("score".to_string(), 3);
+        history.record(&state);
+        state.decisions.push(decision("middle"));
+        state.current_scene = "end".to_string();
+        state.play_time_ms = 5000;
+
+        let restored = history.jump_to(0).unwrap().restore(&state);
+        assert_eq!(restored.current_scene, "intro");
+        assert!(restored.decisions.is_empty());
+        assert!(restored.variables.is_empty());
+        assert_eq!(restored.play_time_ms, 5000);
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn reads_full_snapshots_from_older_saves() {
+        let mut state = GameState::new("test".to_string(), "eng".to_string(), "Test".to_string());
+        state.current_scene = "middle".to_string();
+        state.decisions.push(decision("intro"));
+        let old_save = serde_json::to_string(&vec![state]).unwrap();
+
+        let history: GameHistory = serde_json::from_str(&old_save).unwrap();
+        assert_eq!(history.entries()[0].scene, "middle");
+        assert_eq!(history.entries()[0].decision_count, 1);
+    }
+}
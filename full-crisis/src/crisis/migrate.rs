@@ -0,0 +1,377 @@
+use super::types::*;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, Item, Table};
+
+/// Moves every `[scenes.<id>]` table out of a crisis.toml. Returns the crisis.toml without
+/// its scenes plus one (scene id, `scenes/<id>.toml` contents) pair per scene, in file order.
+/// Comments and key order inside each scene are kept; comments above a scene header move
+/// to the top of its scene file.
+pub fn split_scenes(crisis_toml: &str) -> Result<(String, Vec<(String, String)>), Box<dyn std::error::Error>> {
+    let mut document: DocumentMut = crisis_toml.parse()?;
+    let Some(scenes) = document.remove("scenes") else {
+        return Ok((crisis_toml.to_string(), vec![]));
+    };
+    let scenes = item_into_table(scenes).map_err(|_| "`scenes` in crisis.toml must be a table")?;
+
+    let mut scene_files = vec![];
+    for (scene_id, scene) in scenes {
+        let mut scene = item_into_table(scene).map_err(|_| format!("Scene '{}' must be a table", scene_id))?;
+        let header_comments = comment_lines(scene.decor().prefix().and_then(|prefix| prefix.as_str()).unwrap_or(""));
+        scene.decor_mut().clear();
+        scene.set_implicit(false);
+
+        let mut scene_document = DocumentMut::new();
+        *scene_document.as_table_mut() = scene;
+        let body = scene_document.to_string();
+        scene_files.push((scene_id.to_string(), format!("{}{}\n", header_comments, body.trim_matches('\n'))));
+    }
+
+    Ok((format!("{}\n", document.to_string().trim_end()), scene_files))
+}
+
+/// The reverse of `split_scenes`: folds scene files into `[scenes.<id>]` tables of one crisis.toml.
+/// Inline scenes keep their place, and scene files replace inline scenes of the same id just as
+/// they do when the game loads a crisis. New scenes follow the story from the starting scene.
+pub fn flatten_scenes(crisis_toml: &str, scene_files: &[(String, String)]) -> Result<String, Box<dyn std::error::Error>> {
+    let definition = resolve_definition(crisis_toml, scene_files)?;
+    let mut document: DocumentMut = crisis_toml.parse()?;
+    let mut scenes = match document.remove("scenes") {
+        Some(scenes) => item_into_table(scenes).map_err(|_| "`scenes` in crisis.toml must be a table")?,
+        None => Table::new(),
+    };
+    scenes.set_implicit(true);
+
+    let scene_files: HashMap<&str, &str> = scene_files.iter()
+        .map(|(scene_id, contents)| (scene_id.as_str(), contents.as_str()))
+        .collect();
+    for scene_id in story_order(&definition) {
+        let Some(contents) = scene_files.get(scene_id.as_str()) else {
+            continue;
+        };
+        let (header_comments, body) = split_leading_comments(contents);
+        let mut scene = body.parse::<DocumentMut>()
+            .map_err(|e| format!("Scene file '{}.toml': {}", scene_id, e))?
+            .into_table();
+        scene.set_implicit(false);
+        scene.decor_mut().set_prefix(format!("\n{}", header_comments));
+        scenes.insert(&scene_id, Item::Table(scene));
+    }
+
+    // Scene files each count table positions from zero; lay them out after the rest of crisis.toml
+    let mut next_position = max_position(document.as_table()) + 1;
+    renumber_positions(&mut scenes, &mut next_position);
+    document.insert("scenes", Item::Table(scenes));
+
+    Ok(format!("{}\n", document.to_string().trim_end()))
+}
+
+/// The crisis exactly as `load_crisis` builds it: crisis.toml plus scene files, where a scene
+/// file wins over an inline scene with the same id
+pub fn resolve_definition(crisis_toml: &str, scene_files: &[(String, String)]) -> Result<CrisisDefinition, Box<dyn std::error::Error>> {
+    let mut definition = super::parse_crisis_toml(crisis_toml)?;
+    for (scene_id, contents) in scene_files {
+        let scene = super::parse_scene_toml(contents)
+            .map_err(|e| format!("Scene file '{}.toml': {}", scene_id, e))?;
+        definition.scenes.insert(scene_id.clone(), scene);
+    }
+    Ok(definition)
+}
+
+/// Compares two definitions field by field, ignoring map ordering
+pub fn same_definition(a: &CrisisDefinition, b: &CrisisDefinition) -> Result<bool, Box<dyn std::error::Error>> {
+    Ok(toml::Value::try_from(a)? == toml::Value::try_from(b)?)
+}
+
+/// `full-crisis migrate`: rewrites the inline scenes of a crisis folder into `scenes/<id>.toml`.
+/// Nothing is written unless the result loads to the same `CrisisDefinition`. Returns the scene ids moved.
+pub fn migrate_folder(crisis_folder: &Path) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let crisis_toml = std::fs::read_to_string(crisis_folder.join("crisis.toml"))?;
+    let mut scene_files = read_scene_files(crisis_folder)?;
+    let before = resolve_definition(&crisis_toml, &scene_files)?;
+
+    let (new_crisis_toml, new_scene_files) = split_scenes(&crisis_toml)?;
+    for (scene_id, _) in new_scene_files.iter() {
+        if scene_files.iter().any(|(existing_id, _)| existing_id == scene_id) {
+            return Err(format!("scenes/{}.toml already exists and overrides the inline scene '{}'; remove one of them first", scene_id, scene_id).into());
+        }
+    }
+    scene_files.extend(new_scene_files.iter().cloned());
+
+    let after = resolve_definition(&new_crisis_toml, &scene_files)?;
+    if !same_definition(&before, &after)? {
+        return Err("Migrated scenes do not load to the same crisis; nothing was changed".into());
+    }
+
+    let scenes_folder = crisis_folder.join("scenes");
+    std::fs::create_dir_all(&scenes_folder)?;
+    for (scene_id, contents) in new_scene_files.iter() {
+        std::fs::write(scenes_folder.join(format!("{}.toml", scene_id)), contents)?;
+    }
+    std::fs::write(crisis_folder.join("crisis.toml"), new_crisis_toml)?;
+
+    Ok(new_scene_files.into_iter().map(|(scene_id, _)| scene_id).collect())
+}
+
+/// `full-crisis flatten`: folds every scene file into one crisis.toml. With an output file the crisis
+/// folder is left alone; otherwise crisis.toml is rewritten and the scene files are removed.
+/// Returns the path of the flattened file.
+pub fn flatten_folder(crisis_folder: &Path, output: Option<&Path>) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let crisis_toml = std::fs::read_to_string(crisis_folder.join("crisis.toml"))?;
+    let scene_files = read_scene_files(crisis_folder)?;
+    let before = resolve_definition(&crisis_toml, &scene_files)?;
+
+    let flattened = flatten_scenes(&crisis_toml, &scene_files)?;
+    let after = resolve_definition(&flattened, &[])?;
+    if !same_definition(&before, &after)? {
+        return Err("Flattened crisis does not load to the same crisis; nothing was changed".into());
+    }
+
+    if let Some(output) = output {
+        std::fs::write(output, flattened)?;
+        return Ok(output.to_path_buf());
+    }
+
+    let crisis_path = crisis_folder.join("crisis.toml");
+    std::fs::write(&crisis_path, flattened)?;
+    for (scene_id, _) in scene_files.iter() {
+        std::fs::remove_file(crisis_folder.join("scenes").join(format!("{}.toml", scene_id)))?;
+    }
+    // Only succeeds when nothing else was kept in scenes/
+    let _ = std::fs::remove_dir(crisis_folder.join("scenes"));
+    Ok(crisis_path)
+}
+
+/// Loads a crisis straight from a folder on disk, without going through the crisis sources
+pub fn load_folder(crisis_folder: &Path) -> Result<CrisisDefinition, Box<dyn std::error::Error>> {
+    let crisis_toml = std::fs::read_to_string(crisis_folder.join("crisis.toml"))?;
+    resolve_definition(&crisis_toml, &read_scene_files(crisis_folder)?)
+}
+
+/// Every `scenes/*.toml` file of a crisis folder, sorted by scene id
+pub fn read_scene_files(crisis_folder: &Path) -> Result<Vec<(String, String)>, Box<dyn std::error::Error>> {
+    let scenes_folder = crisis_folder.join("scenes");
+    let mut scene_files = vec![];
+    if !scenes_folder.exists() {
+        return Ok(scene_files);
+    }
+    for entry in std::fs::read_dir(&scenes_folder)? {
+        let path = entry?.path();
+        if !path.is_file() || path.extension().map(|ext| ext != "toml").unwrap_or(true) {
+            continue;
+        }
+        if let Some(scene_id) = path.file_stem().map(|stem| stem.to_string_lossy().to_string()) {
+            scene_files.push((scene_id, std::fs::read_to_string(&path)?));
+        }
+    }
+    scene_files.sort();
+    Ok(scene_files)
+}
+
+/// Scene ids breadth first from the starting scene, then any unreachable scenes by id
+fn story_order(definition: &CrisisDefinition) -> Vec<String> {
+    let mut order = vec![];
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::from([definition.story.starting_scene.clone()]);
+    while let Some(scene_id) = queue.pop_front() {
+        let Some(scene) = definition.scenes.get(&scene_id) else {
+            continue;
+        };
+        if !seen.insert(scene_id.clone()) {
+            continue;
+        }
+        queue.extend(scene.choices.iter().map(|choice| choice.leads_to.clone()));
+        order.push(scene_id);
+    }
+
+    let mut unreachable: Vec<String> = definition.scenes.keys()
+        .filter(|scene_id| !seen.contains(*scene_id))
+        .cloned()
+        .collect();
+    unreachable.sort();
+    order.extend(unreachable);
+    order
+}
+
+fn item_into_table(item: Item) -> Result<Table, Item> {
+    match item {
+        Item::Value(toml_edit::Value::InlineTable(table)) => Ok(table.into_table()),
+        item => item.into_table(),
+    }
+}
+
+/// Only the `#` lines of a decor prefix, without the blank lines around them
+fn comment_lines(prefix: &str) -> String {
+    prefix.lines()
+        .map(|line| line.trim())
+        .filter(|line| line.starts_with('#'))
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+/// Splits the comment block at the top of a scene file from the rest of it
+fn split_leading_comments(contents: &str) -> (String, &str) {
+    let mut comments = String::new();
+    let mut rest = contents;
+    while let Some(line) = rest.lines().next() {
+        let trimmed = line.trim();
+        if !trimmed.is_empty() && !trimmed.starts_with('#') {
+            break;
+        }
+        if trimmed.starts_with('#') {
+            comments.push_str(trimmed);
+            comments.push('\n');
+        }
+        rest = rest[line.len()..].strip_prefix("\r\n").or_else(|| rest[line.len()..].strip_prefix('\n')).unwrap_or(&rest[line.len()..]);
+    }
+    (comments, rest)
+}
+
+fn max_position(table: &Table) -> usize {
+    let mut max = table.position().unwrap_or(0);
+    for (_, item) in table.iter() {
+        match item {
+            Item::Table(table) => max = max.max(max_position(table)),
+            Item::ArrayOfTables(tables) => {
+                for table in tables.iter() {
+                    max = max.max(max_position(table));
+                }
+            }
+            _ => {}
+        }
+    }
+    max
+}
+
+fn renumber_positions(table: &mut Table, next_position: &mut usize) {
+    table.set_position(*next_position);
+    *next_position += 1;
+    for (_, item) in table.iter_mut() {
+        match item {
+            Item::Table(table) => renumber_positions(table, next_position),
+            Item::ArrayOfTables(tables) => {
+                for table in tables.iter_mut() {
+                    renumber_positions(table, next_position);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INLINE_CRISIS: &str = r#"[metadata]
+id = "test_crisis"
+version = "1.0"
+author = "Tester"
+description_key = "test_crisis_desc"
+
+[name]
+eng = "Test Crisis"
+
+[description]
+eng = "A crisis for tests"
+
+[character_names.player.any]
+eng = ["Sam"]
+
+[story]
+starting_scene = "intro"
+default_language = "eng"
+
+[mechanics]
+time_limit_minutes = 5
+save_progress = true
+allow_restart = true
+track_decisions = false
+
+[conditions]
+variables = ["calm"]
+
+# The call comes in
+[scenes.intro]
+text.eng = "The phone rings."
+
+[[scenes.intro.choices]]
+text.eng = "Answer it"
+leads_to = "answered"
+
+[[scenes.intro.choices]]
+text.eng = "Let it ring"
+leads_to = "ending"
+
+[scenes.answered]
+text.eng = "A calm voice speaks."
+
+[[scenes.answered.choices]]
+text.eng = "Hang up"
+leads_to = "ending"
+
+[scenes.ending]
+text.eng = "Silence."
+"#;
+
+    #[test]
+    fn split_moves_every_scene_and_keeps_the_definition() {
+        let (crisis_toml, scene_files) = split_scenes(INLINE_CRISIS).unwrap();
+
+        let scene_ids: Vec<&str> = scene_files.iter().map(|(scene_id, _)| scene_id.as_str()).collect();
+        assert_eq!(scene_ids, vec!["intro", "answered", "ending"]);
+        assert!(!crisis_toml.contains("[scenes"));
+        assert!(scene_files[0].1.starts_with("# The call comes in\n"));
+
+        let before = resolve_definition(INLINE_CRISIS, &[]).unwrap();
+        let after = resolve_definition(&crisis_toml, &scene_files).unwrap();
+        assert!(same_definition(&before, &after).unwrap());
+    }
+
+    #[test]
+    fn flatten_undoes_split() {
+        let (crisis_toml, scene_files) = split_scenes(INLINE_CRISIS).unwrap();
+        let flattened = flatten_scenes(&crisis_toml, &scene_files).unwrap();
+
+        let original = resolve_definition(INLINE_CRISIS, &[]).unwrap();
+        let round_tripped = resolve_definition(&flattened, &[]).unwrap();
+        assert!(same_definition(&original, &round_tripped).unwrap());
+        assert!(flattened.contains("# The call comes in"));
+
+        // Splitting the flattened file again gives the same scenes
+        let (crisis_toml_again, split_again) = split_scenes(&flattened).unwrap();
+        let scene_ids = |files: &[(String, String)]| files.iter().map(|(scene_id, _)| scene_id.clone()).collect::<Vec<_>>();
+        assert_eq!(scene_ids(&split_again), scene_ids(&scene_files));
+        let split_twice = resolve_definition(&crisis_toml_again, &split_again).unwrap();
+        assert!(same_definition(&original, &split_twice).unwrap());
+    }
+
+    #[test]
+    fn flatten_orders_new_scenes_along_the_story() {
+        let (crisis_toml, mut scene_files) = split_scenes(INLINE_CRISIS).unwrap();
+        scene_files.reverse();
+        let flattened = flatten_scenes(&crisis_toml, &scene_files).unwrap();
+        let intro = flattened.find("[scenes.intro]").unwrap();
+        let answered = flattened.find("[scenes.answered]").unwrap();
+        let ending = flattened.find("[scenes.ending]").unwrap();
+        assert!(intro < answered && answered < ending);
+    }
+
+    #[test]
+    fn scene_files_override_inline_scenes() {
+        let scene_files = vec![("ending".to_string(), "text.eng = \"The end.\"\n".to_string())];
+        let definition = resolve_definition(INLINE_CRISIS, &scene_files).unwrap();
+        assert_eq!(definition.scenes["ending"].text["eng"], "The end.");
+
+        let inline = resolve_definition(INLINE_CRISIS, &[]).unwrap();
+        assert!(!same_definition(&inline, &definition).unwrap());
+    }
+
+    #[test]
+    fn split_leaves_a_crisis_without_inline_scenes_alone() {
+        let (crisis_toml, _) = split_scenes(INLINE_CRISIS).unwrap();
+        let (unchanged, scene_files) = split_scenes(&crisis_toml).unwrap();
+        assert_eq!(unchanged, crisis_toml);
+        assert!(scene_files.is_empty());
+    }
+}
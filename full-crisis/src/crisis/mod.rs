@@ -1,8 +1,34 @@
 pub mod types;
 pub mod operations;
+pub mod history;
+pub mod pronouns;
+pub mod catalog;
+pub mod pack;
+pub mod source;
+pub mod signature;
+pub mod stats;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod watcher;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod migrate;
 
 pub use types::*;
 pub use operations::*;
+pub use history::{GameHistory, HistoryEntry};
+pub use pronouns::PronounSet;
+pub use catalog::{CrisisCatalog, CrisisCatalogEntry, CrisisQuery, CrisisSort};
+pub use pack::{CrisisPack, PackManifest};
+pub use source::CrisisSource;
+pub use signature::SignatureStatus;
+pub use stats::{CrisisStats, PlayerStats, get_player_stats, save_player_stats};
+#[cfg(not(target_arch = "wasm32"))]
+pub use watcher::CrisisWatcher;
+
+/// The user's crises folder, read from settings once instead of on every file lookup
+#[cfg(not(target_arch = "wasm32"))]
+static CRISES_FOLDER: once_cell::sync::Lazy<std::sync::RwLock<String>> = once_cell::sync::Lazy::new(|| {
+    std::sync::RwLock::new(crate::gui::GameWindow::load_settings().game_crises_folder)
+});
 
 #[derive(rust_embed::Embed)]
 #[folder = "$CARGO_MANIFEST_DIR/../playable-crises/"]
@@ -10,48 +36,144 @@ struct _Interior_PlayableCrises;
 
 pub struct PlayableCrises;
 impl PlayableCrises {
+    /// Looks up a file such as "Fire_Dispatch/scenes/intro.toml". Files inside a crisis
+    /// folder only ever come from the source that crisis was resolved to (see `CrisisSource`).
     pub fn get(file_path: &str) -> Option<rust_embed::EmbeddedFile> {
-        // Try to get from embedded files first
-        if let Some(embedded_file) = _Interior_PlayableCrises::get(file_path) {
-            return Some(embedded_file);
+        let crisis_folder = file_path.split('/').next().unwrap_or(file_path);
+        if let Some(source) = CrisisCatalog::get().source_of(crisis_folder) {
+            return Self::get_from_source(&source, file_path);
         }
         
-        // On non-WASM platforms, also check the crises folder
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            Self::get_from_crises_folder(file_path)
+        // Files outside any known crisis, in priority order
+        CrisisSource::available()
+            .iter()
+            .find_map(|source| Self::get_from_source(source, file_path))
+    }
+    
+    /// Looks up a file a crisis refers to, such as a scene's image, in the source the crisis
+    /// was resolved to. Paths outside that crisis' folder are looked up like `get`.
+    pub fn get_for_crisis(template_name: &str, file_path: &str) -> Option<rust_embed::EmbeddedFile> {
+        let in_crisis_folder = file_path.strip_prefix(template_name).is_some_and(|rest| rest.starts_with('/'));
+        match CrisisCatalog::get().source_of(template_name) {
+            Some(source) if in_crisis_folder => Self::get_from_source(&source, file_path),
+            _ => Self::get(file_path),
         }
-        
-        #[cfg(target_arch = "wasm32")]
-        None
     }
     
+    /// All known crisis file paths, served from the cached `CrisisCatalog`
     pub fn iter() -> Box<dyn Iterator<Item = std::borrow::Cow<'static, str>>> {
-        let mut all_files = std::collections::HashSet::new();
+        let catalog = CrisisCatalog::get();
+        Box::new(catalog.files().to_vec().into_iter().map(std::borrow::Cow::Owned))
+    }
+    
+    /// Point lookups at a different crises folder and drop the cached catalog
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_crises_folder(folder: &str) {
+        if let Ok(mut crises_folder) = CRISES_FOLDER.write() {
+            if *crises_folder == folder {
+                return;
+            }
+            *crises_folder = folder.to_string();
+        }
+        CrisisCatalog::invalidate();
+        crate::translations::reload_translations();
+    }
+    
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn crises_folder() -> std::path::PathBuf {
+        match CRISES_FOLDER.read() {
+            Ok(crises_folder) => std::path::PathBuf::from(crises_folder.as_str()),
+            Err(_) => std::path::PathBuf::from(crate::gui::GameWindow::load_settings().game_crises_folder),
+        }
+    }
+    
+    /// Copies a built-in or packed crisis into the crises folder, where it shadows
+    /// the original and can be edited. Returns the new crisis folder.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn fork_to_crises_folder(template_name: &str) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+        let catalog = CrisisCatalog::get();
+        let source = match catalog.source_of(template_name) {
+            Some(CrisisSource::CrisesFolder) => return Err(format!("'{}' is already in the crises folder", template_name).into()),
+            Some(source) => source,
+            None => return Err(format!("Crisis '{}' not found", template_name).into()),
+        };
         
-        // Add embedded files
-        for file in _Interior_PlayableCrises::iter() {
-            all_files.insert(file);
+        let destination = Self::crises_folder().join(template_name);
+        if destination.exists() {
+            return Err(format!("{:?} already exists", destination).into());
         }
         
-        // On non-WASM platforms, also add files from crises folder
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            if let Some(folder_files) = Self::iter_crises_folder() {
-                for file in folder_files {
-                    all_files.insert(file);
-                }
+        let prefix = format!("{}/", template_name);
+        for file_path in catalog.files().iter().filter(|file_path| file_path.starts_with(&prefix)) {
+            let file = Self::get_from_source(&source, file_path)
+                .ok_or_else(|| format!("Cannot read '{}' from {}", file_path, source))?;
+            let target = Self::crises_folder().join(file_path);
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
             }
+            std::fs::write(&target, file.data.as_ref())?;
+        }
+        
+        CrisisCatalog::invalidate();
+        Ok(destination)
+    }
+    
+    /// Every source and the files it holds, highest priority first; only the catalog should call this
+    pub(crate) fn scan_sources() -> Vec<(CrisisSource, Vec<String>)> {
+        let mut sources = vec![];
+        
+        // On non-WASM platforms, loose files in the crises folder come first
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(folder_files) = Self::iter_crises_folder() {
+            let mut folder_files: Vec<String> = folder_files.into_iter().map(|file| file.into_owned()).collect();
+            folder_files.sort();
+            sources.push((CrisisSource::CrisesFolder, folder_files));
         }
         
-        Box::new(all_files.into_iter())
+        // Pack contents appear as if they were unpacked into the crises folder
+        for pack in pack::loaded_packs() {
+            let pack_files = pack.file_paths()
+                .map(|file| format!("{}/{}", pack.template_name(), file))
+                .collect();
+            sources.push((CrisisSource::Pack(pack.file_name.clone()), pack_files));
+        }
+        
+        let mut embedded_files: Vec<String> = _Interior_PlayableCrises::iter().map(|file| file.into_owned()).collect();
+        embedded_files.sort();
+        sources.push((CrisisSource::Embedded, embedded_files));
+        
+        sources
+    }
+    
+    pub(crate) fn get_from_source(source: &CrisisSource, file_path: &str) -> Option<rust_embed::EmbeddedFile> {
+        match source {
+            CrisisSource::Embedded => _Interior_PlayableCrises::get(file_path),
+            #[cfg(not(target_arch = "wasm32"))]
+            CrisisSource::CrisesFolder => Self::get_from_crises_folder(file_path),
+            #[cfg(target_arch = "wasm32")]
+            CrisisSource::CrisesFolder => None,
+            CrisisSource::Pack(pack_file_name) => Self::get_from_pack(pack_file_name, file_path),
+        }
+    }
+    
+    fn get_from_pack(pack_file_name: &str, file_path: &str) -> Option<rust_embed::EmbeddedFile> {
+        let (template_name, pack_path) = file_path.split_once('/')?;
+        let pack = pack::loaded_packs().into_iter()
+            .find(|pack| pack.file_name == pack_file_name && pack.template_name() == template_name)?;
+        let data = pack.get(pack_path)?;
+        Some(rust_embed::EmbeddedFile {
+            data: std::borrow::Cow::Owned(data.to_vec()),
+            metadata: rust_embed::Metadata::__rust_embed_new(
+                [0; 32], // hash
+                None, // last_modified
+                None, // created
+            ),
+        })
     }
     
     #[cfg(not(target_arch = "wasm32"))]
     fn get_from_crises_folder(file_path: &str) -> Option<rust_embed::EmbeddedFile> {
-        let settings = crate::gui::GameWindow::load_settings();
-        let crises_folder = std::path::Path::new(&settings.game_crises_folder);
-        let full_path = crises_folder.join(file_path);
+        let full_path = Self::crises_folder().join(file_path);
         
         if let Ok(data) = std::fs::read(full_path) {
             // Create a fake EmbeddedFile-like structure
@@ -70,15 +192,14 @@ impl PlayableCrises {
     
     #[cfg(not(target_arch = "wasm32"))]
     fn iter_crises_folder() -> Option<Vec<std::borrow::Cow<'static, str>>> {
-        let settings = crate::gui::GameWindow::load_settings();
-        let crises_folder = std::path::Path::new(&settings.game_crises_folder);
+        let crises_folder = Self::crises_folder();
         
         if !crises_folder.exists() {
             return None;
         }
         
         let mut files = Vec::new();
-        if let Ok(entries) = Self::walk_directory(crises_folder, crises_folder) {
+        if let Ok(entries) = Self::walk_directory(&crises_folder, &crises_folder) {
             files.extend(entries);
         }
         
@@ -97,7 +218,7 @@ impl PlayableCrises {
                 if path.is_dir() {
                     // Recursively walk subdirectories
                     files.extend(Self::walk_directory(base_path, &path)?);
-                } else if path.is_file() {
+                } else if path.is_file() && path.extension().map(|ext| ext != pack::PACK_EXTENSION).unwrap_or(true) {
                     // Convert absolute path to relative path from base
                     if let Ok(relative_path) = path.strip_prefix(base_path) {
                         let path_str = relative_path.to_string_lossy().replace('\\', "/");
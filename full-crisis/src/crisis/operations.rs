@@ -1,5 +1,5 @@
 use super::types::*;
-use super::PlayableCrises;
+use super::{CrisisCatalog, GameHistory, PlayableCrises};
 use std::collections::HashMap;
 use rand::prelude::*;
 
@@ -8,25 +8,10 @@ pub fn get_crisis_names() -> Vec<String> {
 }
 
 pub fn get_crisis_names_localized(language: &str) -> Vec<String> {
-    let mut names = vec![];
-    
-    for pc in PlayableCrises::iter() {
-        let path = pc.as_ref();
-        if path.ends_with("crisis.toml") {
-            let folder_name = path.replace("/crisis.toml", "");
-            match load_crisis(&folder_name) {
-                Ok(crisis) => {
-                    let localized_name = get_localized_text(&crisis.name, language);
-                    names.push(localized_name);
-                }
-                Err(_) => {
-                    // Fallback to folder name with underscores replaced
-                    let crisis_name = folder_name.replace("_", " ");
-                    names.push(crisis_name);
-                }
-            }
-        }
-    }
+    let catalog = CrisisCatalog::get();
+    let mut names: Vec<String> = catalog.playable_entries()
+        .map(|entry| entry.localized_name(language))
+        .collect();
     
     if names.is_empty() {
         names.push("No crises available".to_string());
@@ -37,38 +22,45 @@ pub fn get_crisis_names_localized(language: &str) -> Vec<String> {
     names
 }
 
-pub fn get_crisis_info_by_display_name(display_name: &str, language: &str) -> Option<(String, String)> {
-    for pc in PlayableCrises::iter() {
-        let path = pc.as_ref();
-        if path.ends_with("crisis.toml") {
-            let folder_name = path.replace("/crisis.toml", "");
-            if let Ok(crisis) = load_crisis(&folder_name) {
-                let localized_name = get_localized_text(&crisis.name, language);
-                if localized_name == display_name {
-                    let description = get_localized_text(&crisis.description, language);
-                    return Some((folder_name, description));
-                }
-            }
-        }
+/// Crisis names tagged with where each one comes from, for the new game picker
+pub fn get_crisis_picker_labels(language: &str) -> Vec<String> {
+    let catalog = CrisisCatalog::get();
+    let mut labels: Vec<String> = catalog.playable_entries()
+        .map(|entry| entry.picker_label(language))
+        .collect();
+    
+    if labels.is_empty() {
+        labels.push("No crises available".to_string());
     }
-    None
+
+    labels.sort_unstable();
+    
+    labels
+}
+
+pub fn get_crisis_info_by_picker_label(label: &str, language: &str) -> Option<(String, String)> {
+    let catalog = CrisisCatalog::get();
+    catalog.find_by_picker_label(label, language)
+        .map(|entry| (entry.template_name.clone(), entry.localized_description(language)))
+}
+
+pub fn get_template_name_from_picker_label(label: &str, language: &str) -> String {
+    match CrisisCatalog::get().find_by_picker_label(label, language) {
+        Some(entry) => entry.template_name.clone(),
+        None => get_template_name_from_display_name(label),
+    }
+}
+
+pub fn get_crisis_info_by_display_name(display_name: &str, language: &str) -> Option<(String, String)> {
+    let catalog = CrisisCatalog::get();
+    catalog.find_by_display_name(display_name, language)
+        .map(|entry| (entry.template_name.clone(), entry.localized_description(language)))
 }
 
 pub fn get_template_name_from_display_name(display_name: &str) -> String {
     // Try to find the folder name by matching display name across all languages
-    for pc in PlayableCrises::iter() {
-        let path = pc.as_ref();
-        if path.ends_with("crisis.toml") {
-            let folder_name = path.replace("/crisis.toml", "");
-            if let Ok(crisis) = load_crisis(&folder_name) {
-                // Check if display name matches any localized version
-                for (_, localized_name) in &crisis.name {
-                    if localized_name == display_name {
-                        return folder_name;
-                    }
-                }
-            }
-        }
+    if let Some(entry) = CrisisCatalog::get().find_by_any_display_name(display_name) {
+        return entry.template_name.clone();
     }
     
     // Fallback to old behavior if no match found
@@ -76,7 +68,7 @@ pub fn get_template_name_from_display_name(display_name: &str) -> String {
 }
 
 pub fn get_saved_games() -> SavedGames {
-    if let Some(content) = crate::internal_storage::get_attr("saved_games") {
+    if let Some(content) = crate::storage::get_profile_attr("saved_games") {
         serde_json::from_str(&content).unwrap_or_default()
     } else {
         SavedGames::default()
@@ -85,7 +77,7 @@ pub fn get_saved_games() -> SavedGames {
 
 pub fn save_games(saved_games: &SavedGames) {
     if let Ok(serialized) = serde_json::to_string(saved_games) {
-        crate::internal_storage::set_attr("saved_games", &serialized);
+        crate::storage::set_profile_attr("saved_games", &serialized);
     }
 }
 
@@ -100,6 +92,7 @@ pub fn get_saved_crisis_names() -> Vec<String> {
 
 pub fn save_current_game(
     story_state: &GameState, 
+    history: &GameHistory,
     template_name: &str, 
     save_name: Option<String>
 ) -> Result<String, String> {
@@ -128,9 +121,17 @@ pub fn save_current_game(
         variables: story_state.variables.clone(),
         text_inputs: story_state.text_inputs.clone(),
         character_type: story_state.character_type.clone(),
+        player_gender: story_state.player_gender,
+        pronouns: story_state.pronouns.clone(),
+        pronouns_language: story_state.pronouns_language.clone(),
+        decisions: story_state.decisions.clone(),
         language: story_state.language.clone(),
+        secondary_language: story_state.secondary_language.clone(),
         save_timestamp: format!("{}", timestamp),
         template_name: template_name.to_string(),
+        play_time_ms: story_state.play_time_ms,
+        run_id: story_state.run_id,
+        history: history.clone(),
     };
     
     saved_games.add_save(saved_game);
@@ -139,7 +140,8 @@ pub fn save_current_game(
     Ok(save_name)
 }
 
-pub fn load_saved_game(display_name: &str) -> Result<GameState, String> {
+/// The saved story state and the rewind history that led to it
+pub fn load_saved_game(display_name: &str) -> Result<(GameState, GameHistory), String> {
     let saved_games = get_saved_games();
     
     if let Some(saved_game) = saved_games.get_save_by_display_name(display_name) {
@@ -161,8 +163,15 @@ pub fn load_saved_game(display_name: &str) -> Result<GameState, String> {
         game_state.variables = saved_game.variables.clone();
         game_state.text_inputs = saved_game.text_inputs.clone();
         game_state.character_type = saved_game.character_type.clone();
+        game_state.player_gender = saved_game.player_gender;
+        game_state.pronouns = saved_game.pronouns.clone();
+        game_state.pronouns_language = saved_game.pronouns_language.clone();
+        game_state.decisions = saved_game.decisions.clone();
+        game_state.secondary_language = saved_game.secondary_language.clone();
+        game_state.play_time_ms = saved_game.play_time_ms;
+        game_state.run_id = saved_game.run_id;
         
-        Ok(game_state)
+        Ok((game_state, saved_game.history.clone()))
     } else {
         Err(format!("Saved game '{}' not found", display_name))
     }
@@ -179,6 +188,11 @@ pub fn delete_saved_game(display_name: &str) -> Result<(), String> {
     }
 }
 
+/// Parses a crisis.toml on its own; scenes/ files are only merged in by `load_crisis`
+pub fn parse_crisis_toml(contents: &str) -> Result<CrisisDefinition, Box<dyn std::error::Error>> {
+    Ok(toml::from_str::<CrisisDefinition>(contents)?)
+}
+
 // WARNING: Long Function
 pub fn load_crisis(crisis_name: &str) -> Result<CrisisDefinition, Box<dyn std::error::Error>> {
     let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
@@ -188,27 +202,47 @@ pub fn load_crisis(crisis_name: &str) -> Result<CrisisDefinition, Box<dyn std::e
         eprintln!("load_crisis: Attempting to load crisis '{}' from path '{}'", crisis_name, crisis_path);
     }
     
-    if let Some(file) = PlayableCrises::get(&crisis_path) {
+    let catalog = CrisisCatalog::get();
+    let crisis_folder = crisis_name.replace(" ", "_");
+    // When signatures are required the crisis is parsed from the same bytes the signature was
+    // checked against, so files changed on disk after the check cannot slip in
+    let verified_files: Option<HashMap<String, Vec<u8>>> = if catalog.require_signed() {
+        let (signature, files) = catalog.read_verified(&crisis_folder);
+        if !signature.is_trusted() {
+            return Err(format!("Crisis '{}' is not signed by a trusted key ({:?}) and settings refuse unsigned crises", crisis_name, signature).into());
+        }
+        Some(files.into_iter().map(|(path, data)| (format!("{}/{}", crisis_folder, path), data)).collect())
+    } else {
+        None
+    };
+    let read_file = |path: &str| -> Option<Vec<u8>> {
+        match verified_files {
+            Some(ref files) => files.get(path).cloned(),
+            None => PlayableCrises::get(path).map(|file| file.data.into_owned()),
+        }
+    };
+    
+    if let Some(data) = read_file(&crisis_path) {
         if *verbosity > 0 {
-            eprintln!("load_crisis: Found embedded file, size {} bytes", file.data.len());
+            eprintln!("load_crisis: Found embedded file, size {} bytes", data.len());
         }
         
-        let contents = std::str::from_utf8(file.data.as_ref())?;
+        let contents = std::str::from_utf8(&data)?;
         if *verbosity > 1 {
             eprintln!("load_crisis: File contents preview (first 200 chars): {}", 
                 &contents[..std::cmp::min(200, contents.len())]);
         }
         
-        match toml::from_str::<CrisisDefinition>(contents) {
+        match parse_crisis_toml(contents) {
             Ok(mut crisis) => {
                 if *verbosity > 0 {
                     eprintln!("load_crisis: Successfully parsed TOML, crisis id: {}", crisis.metadata.id);
                     eprintln!("load_crisis: Starting scene: {}", crisis.story.starting_scene);
-                    eprintln!("load_crisis: Character name keys: {:?}", crisis.character_names.names.keys().collect::<Vec<_>>());
+                    eprintln!("load_crisis: Character name keys: {:?}", crisis.character_names.entries.keys().collect::<Vec<_>>());
                 }
                 
                 // Load scene files from scenes/ directory
-                match load_crisis_scenes(crisis_name) {
+                match load_crisis_scenes(crisis_name, &read_file) {
                     Ok(scenes) => {
                         if *verbosity > 0 {
                             eprintln!("load_crisis: Loaded {} scenes from files", scenes.len());
@@ -233,7 +267,7 @@ pub fn load_crisis(crisis_name: &str) -> Result<CrisisDefinition, Box<dyn std::e
                 if *verbosity > 0 {
                     eprintln!("load_crisis: TOML parsing failed: {}", e);
                 }
-                Err(e.into())
+                Err(e)
             }
         }
     } else {
@@ -247,16 +281,19 @@ pub fn load_crisis(crisis_name: &str) -> Result<CrisisDefinition, Box<dyn std::e
     }
 }
 
-fn load_crisis_scenes(crisis_name: &str) -> Result<HashMap<String, CrisisScene>, Box<dyn std::error::Error>> {
+fn load_crisis_scenes(crisis_name: &str, read_file: &dyn Fn(&str) -> Option<Vec<u8>>) -> Result<HashMap<String, CrisisScene>, Box<dyn std::error::Error>> {
     let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
     let mut scenes = HashMap::new();
     let crisis_folder = crisis_name.replace(" ", "_");
     
+    let scenes_prefix = format!("{}/scenes/", crisis_folder);
+    let catalog = CrisisCatalog::get();
+    let scene_files = catalog.get_entry(&crisis_folder)
+        .map(|entry| entry.scene_files.clone())
+        .unwrap_or_default();
+    
     // Look for scene files in the scenes/ subdirectory
-    for file_path in PlayableCrises::iter() {
-        let path = file_path.as_ref();
-        let scenes_prefix = format!("{}/scenes/", crisis_folder);
-        
+    for path in scene_files.iter().map(|file_path| file_path.as_str()) {
         if path.starts_with(&scenes_prefix) && path.ends_with(".toml") {
             let scene_name = path
                 .strip_prefix(&scenes_prefix)
@@ -265,8 +302,8 @@ fn load_crisis_scenes(crisis_name: &str) -> Result<HashMap<String, CrisisScene>,
                 .unwrap()
                 .to_string();
             
-            if let Some(file) = PlayableCrises::get(path) {
-                let contents = std::str::from_utf8(file.data.as_ref())?;
+            if let Some(data) = read_file(path) {
+                let contents = std::str::from_utf8(&data)?;
                 
                 // Parse TOML into a flexible Value first, then convert to CrisisScene
                 match toml::from_str::<toml::Value>(contents) {
@@ -300,6 +337,11 @@ fn load_crisis_scenes(crisis_name: &str) -> Result<HashMap<String, CrisisScene>,
     Ok(scenes)
 }
 
+/// Parses one `scenes/<id>.toml` file the same way `load_crisis` does
+pub fn parse_scene_toml(contents: &str) -> Result<CrisisScene, Box<dyn std::error::Error>> {
+    parse_scene_from_toml_value(&toml::from_str::<toml::Value>(contents)?)
+}
+
 fn parse_scene_from_toml_value(value: &toml::Value) -> Result<CrisisScene, Box<dyn std::error::Error>> {
     let table = value.as_table().ok_or("Scene must be a table")?;
     
@@ -354,13 +396,16 @@ fn parse_scene_from_toml_value(value: &toml::Value) -> Result<CrisisScene, Box<d
                             .and_then(|v| v.as_str())
                             .map(|s| s.to_string());
                         
+                        let text_input = choice_table.get("text_input")
+                            .and_then(|v| v.clone().try_into::<CrisisTextInput>().ok());
+                        
                         Some(CrisisChoice {
                             text: choice_text,
                             leads_to,
                             subfolder,
                             requires,
                             character_type,
-                            text_input: None, // TODO: Implement if needed
+                            text_input,
                         })
                     })
                 })
@@ -401,6 +446,13 @@ fn parse_scene_from_toml_value(value: &toml::Value) -> Result<CrisisScene, Box<d
         })
         .flatten();
     
+    // A misspelt transition is an error, like it is for scenes written inline in crisis.toml
+    let transition = match table.get("transition") {
+        Some(value) => value.clone().try_into::<SceneTransition>()
+            .map_err(|e| format!("Invalid [transition]: {}", e))?,
+        None => SceneTransition::default(),
+    };
+    
     Ok(CrisisScene {
         text,
         choices,
@@ -408,82 +460,99 @@ fn parse_scene_from_toml_value(value: &toml::Value) -> Result<CrisisScene, Box<d
         background_image,
         speaking_character_image,
         background_audio,
+        transition,
     })
 }
 
-pub fn get_random_character_name(crisis: &CrisisDefinition, character_type: Option<&str>, language: &str) -> String {
-    let mut rng = thread_rng();
-    let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
-    
-    if *verbosity > 1 {
-        eprintln!("get_random_character_name: character_type={:?}, language={}", character_type, language);
-        eprintln!("get_random_character_name: available keys={:?}", crisis.character_names.names.keys().collect::<Vec<_>>());
-    }
-    
-    // Try different naming patterns based on character_type
-    let possible_keys = match character_type {
-        Some(ctype) => vec![
-            format!("{}_male_{}", ctype, language),
-            format!("{}_female_{}", ctype, language),
-            format!("{}_{}", ctype, language),
-            format!("male_{}", language),
-        ],
-        None => vec![
-            format!("male_{}", language),
-            format!("female_{}", language),
-        ],
+/// Applies the variable effects of a choice in the current scene and, when the crisis tracks
+/// decisions, adds the choice to the transcript. Call before moving on to the next scene.
+pub fn apply_choice_effects(crisis: &CrisisDefinition, story_state: &mut GameState, choice_index: usize) {
+    let Some(choice) = crisis.scenes.get(&story_state.current_scene).and_then(|scene| scene.choices.get(choice_index)) else {
+        return;
     };
     
-    if *verbosity > 1 {
-        eprintln!("get_random_character_name: trying keys={:?}", possible_keys);
-    }
-    
-    // Try each possible key
-    for name_key in &possible_keys {
-        if let Some(names) = crisis.character_names.names.get(name_key) {
-            if let Some(name) = names.choose(&mut rng) {
-                if *verbosity > 1 {
-                    eprintln!("get_random_character_name: found name '{}' using key '{}'", name, name_key);
-                }
-                return name.clone();
-            }
-        }
+    let mut variable_changes: Vec<(String, i32)> = crisis.conditions.choice_effects.as_ref()
+        .and_then(|choice_effects| choice_effects.get(&choice.leads_to))
+        .map(|effects| effects.iter().map(|(var, value)| (var.clone(), *value)).collect())
+        .unwrap_or_default();
+    variable_changes.sort();
+    for (var, value) in &variable_changes {
+        *story_state.variables.entry(var.clone()).or_insert(0) += value;
     }
     
-    if *verbosity > 1 {
-        eprintln!("get_random_character_name: no standard keys worked, trying any key with language");
+    if crisis.mechanics.track_decisions {
+        let text_input = choice.text_input.as_ref()
+            .and_then(|text_input| story_state.text_inputs.get(&text_input.variable_name))
+            .cloned();
+        // Kept as read, so later variable changes and crisis edits do not rewrite past scenes
+        let scene_text = crisis.scenes.get(&story_state.current_scene)
+            .map(|scene| get_scene_text_with_substitutions(scene, &story_state.language, crisis, story_state));
+        let choice_text = Some(crisis.localized_text(&choice.text, &story_state.language));
+        story_state.decisions.push(DecisionRecord {
+            scene: story_state.current_scene.clone(),
+            choice_index,
+            text_input,
+            variable_changes,
+            scene_text,
+            choice_text,
+        });
     }
+}
+
+/// Picks a name for the player in the role of `character_type` (the starting role when None).
+/// Languages are tried in fallback-chain order, then the crisis default language; in each one
+/// names of the player's gender win over gender-neutral ("any") names. Neutral players also
+/// accept names of any gender. A role without names falls back to the starting role, then to
+/// names of any role.
+pub fn get_random_character_name(crisis: &CrisisDefinition, character_type: Option<&str>, gender: PlayerGender, language: &str) -> String {
+    let mut rng = thread_rng();
+    let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
     
-    // If no standard keys worked, try to find ANY available character names
-    for (key, names) in &crisis.character_names.names {
-        if key.contains(language) && !names.is_empty() {
-            if let Some(name) = names.choose(&mut rng) {
-                if *verbosity > 1 {
-                    eprintln!("get_random_character_name: found name '{}' using fallback key '{}'", name, key);
-                }
-                return name.clone();
-            }
-        }
+    let languages = crisis.language_fallback_chain(language);
+    // None takes every role
+    let mut roles = vec![Some(character_type.unwrap_or(DEFAULT_CHARACTER_ROLE))];
+    if character_type.is_some_and(|role| role != DEFAULT_CHARACTER_ROLE) {
+        roles.push(Some(DEFAULT_CHARACTER_ROLE));
     }
+    roles.push(None);
     
     if *verbosity > 1 {
-        eprintln!("get_random_character_name: no language-specific keys worked, trying any key");
+        eprintln!("get_random_character_name: roles={:?}, gender={:?}, languages={:?}", roles, gender, languages);
     }
     
-    // Absolute final fallback - try any names at all
-    for (key, names) in &crisis.character_names.names {
-        if !names.is_empty() {
-            if let Some(name) = names.choose(&mut rng) {
+    let lists = crisis.character_names.lists();
+    for role in roles.iter() {
+        for candidate_language in languages.iter() {
+            let in_language: Vec<&CharacterNameList> = lists.iter()
+                .filter(|list| role.is_none_or(|role| list.role == role) && list.language == *candidate_language && !list.names.is_empty())
+                .collect();
+            
+            // None takes every gender
+            let names_with = |wanted: Option<&str>| {
+                in_language.iter()
+                    .filter(|list| wanted.is_none_or(|wanted| list.gender == wanted))
+                    .flat_map(|list| list.names.iter())
+                    .collect::<Vec<&String>>()
+            };
+            let mut candidates = names_with(Some(gender.key()));
+            if candidates.is_empty() {
+                candidates = names_with(Some(ANY_GENDER));
+            }
+            if candidates.is_empty() && gender == PlayerGender::Neutral {
+                candidates = names_with(None);
+            }
+            
+            if let Some(name) = candidates.choose(&mut rng) {
                 if *verbosity > 1 {
-                    eprintln!("get_random_character_name: found name '{}' using absolute fallback key '{}'", name, key);
+                    eprintln!("get_random_character_name: found name '{}' for role {:?} in '{}'", name, role, candidate_language);
                 }
-                return name.clone();
+                return (*name).clone();
             }
         }
     }
     
     if *verbosity > 0 {
-        eprintln!("get_random_character_name: all fallbacks failed, using 'Player'");
+        eprintln!("get_random_character_name: no names for {:?} in {:?}, using 'Player'", roles, languages);
     }
     
     "Player".to_string()
@@ -500,24 +569,27 @@ pub fn get_scene_text(scene: &CrisisScene, language: &str, character_name: &str)
     text.replace("{character_name}", character_name)
 }
 
-pub fn get_scene_text_with_substitutions(scene: &CrisisScene, language: &str, character_name: &str, text_inputs: &HashMap<String, String>) -> String {
-    let fallback_chain = crate::language::get_language_fallback_chain(language);
+pub fn get_scene_text_with_substitutions(scene: &CrisisScene, language: &str, crisis: &CrisisDefinition, story_state: &GameState) -> String {
+    let fallback_chain = crisis.language_fallback_chain(language);
     
-    let mut text = fallback_chain.iter()
-        .find_map(|lang| scene.text.get(lang))
-        .unwrap_or(&"Missing text".to_string())
-        .clone();
+    // Gendered forms and pronouns follow the language the text is written in, not the one asked for
+    let (text_language, text) = fallback_chain.iter()
+        .find_map(|lang| scene.text.get(lang).map(|text| (lang.as_str(), text.as_str())))
+        .unwrap_or((language, "Missing text"));
+    
+    let mut text = super::pronouns::apply_gender_forms(text, crisis, text_language, story_state.player_gender);
     
     // Replace character name
-    text = text.replace("{character_name}", character_name);
+    text = text.replace("{character_name}", &story_state.character_name);
     
     // Replace text input variables
-    for (variable_name, value) in text_inputs {
+    for (variable_name, value) in &story_state.text_inputs {
         let placeholder = format!("{{{}}}", variable_name);
         text = text.replace(&placeholder, value);
     }
     
-    text
+    let pronouns = super::pronouns::resolve_pronouns(crisis, story_state, text_language);
+    super::pronouns::substitute_pronouns(&text, &pronouns)
 }
 
 pub fn get_localized_text(text_map: &std::collections::HashMap<String, String>, language: &str) -> String {
@@ -0,0 +1,356 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::sync::{Arc, RwLock};
+
+/// File extension of crisis packs, single-file zip archives of one crisis folder
+pub const PACK_EXTENSION: &str = "fcpack";
+/// Name of the manifest at the root of every pack
+pub const MANIFEST_FILE_NAME: &str = "manifest.toml";
+/// Version of this engine, compared against `min_engine_version` in pack manifests
+pub const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Largest uncompressed size of a single file in a pack
+pub const MAX_ENTRY_BYTES: u64 = 64 * 1024 * 1024;
+/// Largest uncompressed size of all files in a pack together
+pub const MAX_PACK_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Packs read from the crises folder (native) or uploaded through the browser (web)
+static LOADED_PACKS: once_cell::sync::Lazy<RwLock<Option<Vec<Arc<CrisisPack>>>>> = once_cell::sync::Lazy::new(|| RwLock::new(None));
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackManifest {
+    pub name: String, // Template name the crisis is installed under, e.g. "Fire_Dispatch"
+    pub version: String,
+    pub author: String,
+    pub license: String,
+    #[serde(default)]
+    pub min_engine_version: String, // Older engines refuse the pack; empty accepts any
+}
+
+impl PackManifest {
+    pub fn is_engine_compatible(&self) -> bool {
+        parse_version(ENGINE_VERSION) >= parse_version(&self.min_engine_version)
+    }
+}
+
+/// Returned by `CrisisPack::from_bytes` for a pack made for a newer engine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EngineTooOld {
+    pub pack_name: String,
+    pub min_engine_version: String,
+}
+
+impl EngineTooOld {
+    pub fn to_translated_string(&self, language: &str) -> String {
+        let vars = std::collections::HashMap::from([
+            ("version".to_string(), self.min_engine_version.clone()),
+            ("engine".to_string(), ENGINE_VERSION.to_string()),
+        ]);
+        crate::translations::t_vars(crate::translations::TranslationKey::PackNeedsNewerEngine, language, &vars)
+    }
+}
+
+impl std::fmt::Display for EngineTooOld {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Pack '{}' needs engine version {} or newer, this is {}", self.pack_name, self.min_engine_version, ENGINE_VERSION)
+    }
+}
+
+impl std::error::Error for EngineTooOld {}
+
+/// A crisis loaded from an `.fcpack` archive. File paths are relative to the
+/// crisis folder, so `crisis.toml`, `scenes/intro.toml`, `assets/bg.png`, ...
+#[derive(Debug, Clone)]
+pub struct CrisisPack {
+    pub manifest: PackManifest,
+    pub file_name: String, // e.g. "Fire_Dispatch.fcpack", shown as the crisis source
+    files: BTreeMap<String, Vec<u8>>,
+}
+
+impl CrisisPack {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes))?;
+        let mut manifest = None;
+        let mut files = BTreeMap::new();
+        let mut total_bytes: u64 = 0;
+
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i)?;
+            if entry.is_dir() {
+                continue;
+            }
+            // enclosed_name() rejects absolute paths and "../" tricks
+            let path = match entry.enclosed_name() {
+                Some(path) => path.to_string_lossy().replace('\\', "/"),
+                None => return Err(format!("Pack contains an unsafe path: {}", entry.name()).into()),
+            };
+            // Sizes in the zip headers are whatever the pack claims, so only the bytes
+            // actually decompressed are counted, stopping one past the limit
+            let mut data = Vec::new();
+            (&mut entry).take(MAX_ENTRY_BYTES + 1).read_to_end(&mut data)?;
+            if data.len() as u64 > MAX_ENTRY_BYTES {
+                return Err(format!("Pack file '{}' is larger than {} MB", path, MAX_ENTRY_BYTES / (1024 * 1024)).into());
+            }
+            total_bytes += data.len() as u64;
+            if total_bytes > MAX_PACK_BYTES {
+                return Err(format!("Pack contents are larger than {} MB", MAX_PACK_BYTES / (1024 * 1024)).into());
+            }
+
+            if path == MANIFEST_FILE_NAME {
+                let contents = std::str::from_utf8(&data)?;
+                manifest = Some(toml::from_str::<PackManifest>(contents)
+                    .map_err(|e| format!("Invalid {}: {}", MANIFEST_FILE_NAME, e))?);
+            } else {
+                files.insert(path, data);
+            }
+        }
+
+        let manifest = manifest.ok_or_else(|| format!("Pack has no {}", MANIFEST_FILE_NAME))?;
+        if manifest.name.is_empty() || manifest.name.contains('/') || manifest.name.contains('\\') {
+            return Err(format!("Pack manifest has an invalid name: '{}'", manifest.name).into());
+        }
+        if !manifest.is_engine_compatible() {
+            return Err(Box::new(EngineTooOld {
+                pack_name: manifest.name.clone(),
+                min_engine_version: manifest.min_engine_version.clone(),
+            }));
+        }
+        if !files.contains_key("crisis.toml") {
+            return Err(format!("Pack '{}' has no crisis.toml", manifest.name).into());
+        }
+
+        let file_name = format!("{}.{}", manifest.name, PACK_EXTENSION);
+        Ok(Self { manifest, file_name, files })
+    }
+
+    pub fn template_name(&self) -> &str {
+        &self.manifest.name
+    }
+
+    /// Looks up a file by its crisis-relative path
+    pub fn get(&self, path: &str) -> Option<&[u8]> {
+        self.files.get(path).map(|data| data.as_slice())
+    }
+
+    pub fn file_paths(&self) -> impl Iterator<Item = &String> {
+        self.files.keys()
+    }
+
+    /// Builds a pack from a crisis folder on disk. The manifest is filled in from
+    /// the `[metadata]` table of the folder's crisis.toml.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn write_from_folder(crisis_folder: &std::path::Path, output: &std::path::Path) -> Result<PackManifest, Box<dyn std::error::Error>> {
+        use std::io::Write;
+        
+        let crisis_toml = std::fs::read_to_string(crisis_folder.join("crisis.toml"))
+            .map_err(|e| format!("Cannot read {:?}: {}", crisis_folder.join("crisis.toml"), e))?;
+        let crisis: super::CrisisDefinition = toml::from_str(&crisis_toml)?;
+
+        let name = match crisis_folder.file_name() {
+            Some(name) => name.to_string_lossy().to_string(),
+            None => return Err(format!("Cannot name a pack after {:?}", crisis_folder).into()),
+        };
+        let manifest = PackManifest {
+            name,
+            version: crisis.metadata.version.clone(),
+            author: crisis.metadata.author.clone(),
+            license: crisis.metadata.license.clone().unwrap_or_else(|| "All rights reserved".to_string()),
+            min_engine_version: ENGINE_VERSION.to_string(),
+        };
+
+        let mut paths = vec![];
+        collect_files(crisis_folder, crisis_folder, &mut paths)?;
+        paths.sort();
+
+        let mut writer = zip::ZipWriter::new(std::fs::File::create(output)?);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+
+        writer.start_file(MANIFEST_FILE_NAME, options)?;
+        writer.write_all(toml::to_string_pretty(&manifest)?.as_bytes())?;
+
+        for relative_path in paths.iter() {
+            if relative_path == MANIFEST_FILE_NAME {
+                continue;
+            }
+            writer.start_file(relative_path.as_str(), options)?;
+            writer.write_all(&std::fs::read(crisis_folder.join(relative_path))?)?;
+        }
+        writer.finish()?;
+
+        Ok(manifest)
+    }
+
+    /// Extracts the pack into `destination/<name>/`, returning that folder
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn unpack_to(&self, destination: &std::path::Path) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+        let crisis_folder = destination.join(self.template_name());
+        if crisis_folder.exists() {
+            return Err(format!("{:?} already exists", crisis_folder).into());
+        }
+        for (relative_path, data) in self.files.iter() {
+            let file_path = crisis_folder.join(relative_path);
+            if let Some(parent) = file_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&file_path, data)?;
+        }
+        Ok(crisis_folder)
+    }
+}
+
+/// Every pack currently available to `PlayableCrises`, loading them on first use
+pub fn loaded_packs() -> Vec<Arc<CrisisPack>> {
+    if let Ok(guard) = LOADED_PACKS.read() {
+        if let Some(packs) = guard.as_ref() {
+            return packs.clone();
+        }
+    }
+
+    let packs = scan_packs();
+    if let Ok(mut guard) = LOADED_PACKS.write() {
+        *guard = Some(packs.clone());
+    }
+    packs
+}
+
+/// Forget the loaded packs so the crises folder is scanned again on next use
+#[cfg(not(target_arch = "wasm32"))]
+pub fn invalidate_packs() {
+    if let Ok(mut guard) = LOADED_PACKS.write() {
+        *guard = None;
+    }
+}
+
+/// Adds a pack uploaded through the browser. It lasts until the page is reloaded.
+#[cfg(target_arch = "wasm32")]
+pub fn install_pack_bytes(bytes: &[u8]) -> Result<PackManifest, Box<dyn std::error::Error>> {
+    let pack = CrisisPack::from_bytes(bytes)?;
+    let manifest = pack.manifest.clone();
+    let mut packs = loaded_packs();
+    packs.retain(|existing| existing.template_name() != pack.template_name());
+    packs.push(Arc::new(pack));
+    if let Ok(mut guard) = LOADED_PACKS.write() {
+        *guard = Some(packs);
+    }
+    super::CrisisCatalog::invalidate();
+    Ok(manifest)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn scan_packs() -> Vec<Arc<CrisisPack>> {
+    let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
+    let crises_folder = super::PlayableCrises::crises_folder();
+    let mut packs = vec![];
+
+    let Ok(entries) = std::fs::read_dir(&crises_folder) else {
+        return packs;
+    };
+    let mut pack_paths: Vec<std::path::PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().map(|ext| ext == PACK_EXTENSION).unwrap_or(false))
+        .collect();
+    pack_paths.sort();
+
+    for pack_path in pack_paths {
+        let pack = std::fs::read(&pack_path)
+            .map_err(|e| e.into())
+            .and_then(|bytes| CrisisPack::from_bytes(&bytes));
+        match pack {
+            Ok(mut pack) => {
+                if let Some(file_name) = pack_path.file_name() {
+                    pack.file_name = file_name.to_string_lossy().to_string();
+                }
+                if *verbosity > 0 {
+                    eprintln!("Loaded crisis pack '{}' v{} from {:?}", pack.template_name(), pack.manifest.version, pack_path);
+                }
+                packs.push(Arc::new(pack));
+            }
+            Err(e) => {
+                eprintln!("Skipping crisis pack {:?}: {}", pack_path, e);
+            }
+        }
+    }
+    packs
+}
+
+#[cfg(target_arch = "wasm32")]
+fn scan_packs() -> Vec<Arc<CrisisPack>> {
+    vec![]
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn collect_files(base_path: &std::path::Path, current_path: &std::path::Path, paths: &mut Vec<String>) -> Result<(), std::io::Error> {
+    for entry in std::fs::read_dir(current_path)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(base_path, &path, paths)?;
+        } else if let Ok(relative_path) = path.strip_prefix(base_path) {
+            paths.push(relative_path.to_string_lossy().replace('\\', "/"));
+        }
+    }
+    Ok(())
+}
+
+/// "1.2.3" -> [1, 2, 3]; anything that is not a number counts as 0, and
+/// trailing zeros are dropped so "0.2" and "0.2.0" compare equal
+fn parse_version(version: &str) -> Vec<u64> {
+    let mut parts: Vec<u64> = version.trim().split('.')
+        .map(|part| part.trim().parse::<u64>().unwrap_or(0))
+        .collect();
+    while parts.last() == Some(&0) {
+        parts.pop();
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn pack_bytes(manifest: &str) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default();
+        writer.start_file(MANIFEST_FILE_NAME, options).unwrap();
+        writer.write_all(manifest.as_bytes()).unwrap();
+        writer.start_file("crisis.toml", options).unwrap();
+        writer.write_all(b"[metadata]\nid = \"test\"\n").unwrap();
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn manifest(min_engine_version: &str) -> String {
+        format!(
+            "name = \"Test_Pack\"\nversion = \"1.0\"\nauthor = \"Tester\"\nlicense = \"CC0\"\nmin_engine_version = \"{}\"\n",
+            min_engine_version
+        )
+    }
+
+    #[test]
+    fn versions_compare_numerically() {
+        assert!(parse_version("0.10.0") > parse_version("0.9.9"));
+        assert_eq!(parse_version("0.2"), parse_version("0.2.0"));
+        assert!(parse_version("1") > parse_version(""));
+    }
+
+    #[test]
+    fn accepts_packs_for_this_or_an_older_engine() {
+        for version in [ENGINE_VERSION, "0.0.1", ""] {
+            let pack = CrisisPack::from_bytes(&pack_bytes(&manifest(version))).unwrap();
+            assert_eq!(pack.template_name(), "Test_Pack");
+        }
+
+        // Packs written before the field existed have no min_engine_version at all
+        let without_field = "name = \"Test_Pack\"\nversion = \"1.0\"\nauthor = \"Tester\"\nlicense = \"CC0\"\n";
+        assert!(CrisisPack::from_bytes(&pack_bytes(without_field)).is_ok());
+    }
+
+    #[test]
+    fn rejects_packs_that_need_a_newer_engine() {
+        let error = CrisisPack::from_bytes(&pack_bytes(&manifest("999.0"))).unwrap_err();
+        let too_old = error.downcast_ref::<EngineTooOld>().expect("engine version error");
+        assert_eq!(too_old.pack_name, "Test_Pack");
+        assert_eq!(too_old.min_engine_version, "999.0");
+        assert!(too_old.to_translated_string("eng").contains("999.0"));
+    }
+}
@@ -0,0 +1,339 @@
+use serde::{Serialize, Deserialize};
+use super::types::{CrisisDefinition, GameState, PlayerGender};
+
+/// Placeholder names in scene text, in the order pronoun sets are written: "they/them/their/theirs/themself"
+pub const PRONOUN_PLACEHOLDERS: [&str; 5] = ["they", "them", "their", "theirs", "themself"];
+
+/// The words `{they}`, `{them}`, `{their}`, `{theirs}` and `{themself}` expand to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PronounSet {
+    pub they: String,
+    pub them: String,
+    pub their: String,
+    pub theirs: String,
+    pub themself: String,
+}
+
+/// Pronouns the game knows without help from the crisis, as (language, gender, "they/them/their/theirs/themself").
+/// Languages without an established neutral set leave it out; crises can add one under `[pronouns.neutral]`.
+const BUILTIN_PRONOUNS: &[(&str, PlayerGender, &str)] = &[
+    ("eng", PlayerGender::Female, "she/her/her/hers/herself"),
+    ("eng", PlayerGender::Male, "he/him/his/his/himself"),
+    ("eng", PlayerGender::Neutral, "they/them/their/theirs/themself"),
+    ("spa", PlayerGender::Female, "ella/la/su/suya/sí misma"),
+    ("spa", PlayerGender::Male, "él/lo/su/suyo/sí mismo"),
+    ("spa", PlayerGender::Neutral, "elle/le/su/suye/sí misme"),
+    ("fra", PlayerGender::Female, "elle/la/sa/la sienne/elle-même"),
+    ("fra", PlayerGender::Male, "il/le/son/le sien/lui-même"),
+    ("fra", PlayerGender::Neutral, "iel/iel/son/le sien/iel-même"),
+    ("deu", PlayerGender::Female, "sie/sie/ihr/ihrer/sich"),
+    ("deu", PlayerGender::Male, "er/ihn/sein/seiner/sich"),
+    ("ita", PlayerGender::Female, "lei/la/suo/suo/sé stessa"),
+    ("ita", PlayerGender::Male, "lui/lo/suo/suo/sé stesso"),
+    ("por", PlayerGender::Female, "ela/ela/dela/dela/ela mesma"),
+    ("por", PlayerGender::Male, "ele/ele/dele/dele/ele mesmo"),
+    ("por", PlayerGender::Neutral, "elu/elu/delu/delu/elu mesme"),
+];
+
+impl PronounSet {
+    /// Parses "xe/xem/xyr/xyrs/xemself"; all five forms are required
+    pub fn parse(text: &str) -> Option<Self> {
+        let forms: Vec<&str> = text.split('/').map(|form| form.trim()).collect();
+        if forms.len() != PRONOUN_PLACEHOLDERS.len() || forms.iter().any(|form| form.is_empty()) {
+            return None;
+        }
+        Some(Self {
+            they: forms[0].to_string(),
+            them: forms[1].to_string(),
+            their: forms[2].to_string(),
+            theirs: forms[3].to_string(),
+            themself: forms[4].to_string(),
+        })
+    }
+
+    pub fn builtin(gender: PlayerGender, language: &str) -> Option<Self> {
+        BUILTIN_PRONOUNS.iter()
+            .find(|(lang, builtin_gender, _)| *lang == language && *builtin_gender == gender)
+            .and_then(|(_, _, forms)| Self::parse(forms))
+    }
+
+    /// The word for a placeholder name such as "their"
+    pub fn form(&self, placeholder: &str) -> Option<&str> {
+        match placeholder {
+            "they" => Some(&self.they),
+            "them" => Some(&self.them),
+            "their" => Some(&self.their),
+            "theirs" => Some(&self.theirs),
+            "themself" => Some(&self.themself),
+            _ => None,
+        }
+    }
+}
+
+/// English singular they, which the placeholder names spell out
+impl Default for PronounSet {
+    fn default() -> Self {
+        Self {
+            they: "they".to_string(),
+            them: "them".to_string(),
+            their: "their".to_string(),
+            theirs: "theirs".to_string(),
+            themself: "themself".to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for PronounSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}/{}/{}/{}", self.they, self.them, self.their, self.theirs, self.themself)
+    }
+}
+
+/// Pronouns for the player in one language. Each language along the crisis' fallback chain
+/// is tried in turn for the player's custom set (only in the language it was entered in),
+/// the crisis' `[pronouns.<gender>]` table and the built-in set, so a language without
+/// pronouns for the player's gender borrows them from the next one, usually English.
+pub fn resolve_pronouns(crisis: &CrisisDefinition, story_state: &GameState, language: &str) -> PronounSet {
+    crisis.language_fallback_chain(language).iter()
+        .find_map(|language| {
+            let custom = story_state.pronouns.as_ref()
+                .filter(|_| story_state.pronouns_language.as_deref().is_none_or(|entered| entered == language.as_str()));
+            custom.cloned().or_else(|| pronoun_set(crisis, story_state.player_gender, language))
+        })
+        .unwrap_or_default()
+}
+
+/// The crisis' or the built-in pronouns for a gender in exactly this language
+fn pronoun_set(crisis: &CrisisDefinition, gender: PlayerGender, language: &str) -> Option<PronounSet> {
+    crisis.pronouns.get(gender.key())
+        .and_then(|languages| languages.get(language))
+        .and_then(|forms| PronounSet::parse(forms))
+        .or_else(|| PronounSet::builtin(gender, language))
+}
+
+/// (language, gender) pairs where scene text uses pronoun placeholders but neither the crisis
+/// nor the game has pronouns for that gender, so players of it get another language's words
+pub fn missing_pronoun_sets(crisis: &CrisisDefinition) -> Vec<(String, PlayerGender)> {
+    let languages: std::collections::BTreeSet<&String> = crisis.scenes.values()
+        .flat_map(|scene| scene.text.iter())
+        .filter(|(_, text)| uses_pronoun_placeholders(text))
+        .map(|(language, _)| language)
+        .collect();
+    languages.into_iter()
+        .flat_map(|language| {
+            PlayerGender::ALL.into_iter()
+                .filter(move |gender| pronoun_set(crisis, *gender, language).is_none())
+                .map(move |gender| (language.clone(), gender))
+        })
+        .collect()
+}
+
+fn uses_pronoun_placeholders(text: &str) -> bool {
+    PRONOUN_PLACEHOLDERS.iter().any(|placeholder| {
+        text.contains(&format!("{{{}}}", placeholder)) || text.contains(&format!("{{{}}}", capitalize(placeholder)))
+    })
+}
+
+/// Replaces `{they}` style placeholders; a capitalized placeholder such as `{They}` gives a capitalized word
+pub fn substitute_pronouns(text: &str, pronouns: &PronounSet) -> String {
+    let mut text = text.to_string();
+    for placeholder in PRONOUN_PLACEHOLDERS {
+        let Some(form) = pronouns.form(placeholder) else {
+            continue;
+        };
+        text = text.replace(&format!("{{{}}}", placeholder), form);
+        text = text.replace(&format!("{{{}}}", capitalize(placeholder)), &capitalize(form));
+    }
+    text
+}
+
+/// Resolves gendered word forms for the player. Inline groups such as
+/// `{male:Despachador|female:Despachadora}` pick the player's form, and words defined
+/// under `[gender_forms.<language>]` in crisis.toml can be used by name, e.g. `{despachador}`.
+/// When the player's gender has no form, every form is shown joined by "/".
+pub fn apply_gender_forms(text: &str, crisis: &CrisisDefinition, language: &str, gender: PlayerGender) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let group_and_rest = &rest[start + 1..];
+        let Some(end) = group_and_rest.find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let group = &group_and_rest[..end];
+
+        let forms = parse_inline_forms(group).or_else(|| {
+            crisis.gender_forms.get(language)
+                .and_then(|words| words.get(group))
+                .map(|forms| {
+                    let mut keyed: Vec<(PlayerGender, &str)> = forms.iter()
+                        .filter_map(|(key, form)| Some((PlayerGender::from_key(key)?, form.as_str())))
+                        .collect();
+                    keyed.sort_by_key(|(gender, _)| PlayerGender::ALL.iter().position(|g| g == gender));
+                    keyed
+                })
+        });
+        match forms {
+            Some(forms) if !forms.is_empty() => result.push_str(&pick_form(&forms, gender)),
+            _ => {
+                // Not a gender form, e.g. {character_name}; left for the later substitutions
+                result.push('{');
+                result.push_str(group);
+                result.push('}');
+            }
+        }
+        rest = &group_and_rest[end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// `female:Despachadora|male:Despachador` as (gender, form) pairs; None if any part is not `<gender>:<form>`
+fn parse_inline_forms(group: &str) -> Option<Vec<(PlayerGender, &str)>> {
+    group.split('|')
+        .map(|part| {
+            let (key, form) = part.split_once(':')?;
+            Some((PlayerGender::from_key(key.trim())?, form))
+        })
+        .collect()
+}
+
+fn pick_form(forms: &[(PlayerGender, &str)], gender: PlayerGender) -> String {
+    match forms.iter().find(|(form_gender, _)| *form_gender == gender) {
+        Some((_, form)) => form.to_string(),
+        None => forms.iter().map(|(_, form)| *form).collect::<Vec<_>>().join("/"),
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CRISIS: &str = r#"[metadata]
+id = "pronoun_test"
+version = "1.0"
+author = "Tester"
+description_key = "pronoun_test_desc"
+
+[name]
+eng = "Pronoun Test"
+
+[description]
+eng = "A crisis for tests"
+
+[character_names.player.any]
+eng = ["Anna"]
+
+[story]
+starting_scene = "intro"
+default_language = "eng"
+
+[mechanics]
+time_limit_minutes = 5
+save_progress = true
+allow_restart = true
+track_decisions = false
+
+[conditions]
+
+[pronouns.female]
+ita = "lei/la/sua/sua/sé stessa"
+
+[scenes.intro]
+text.eng = "{They} pick up {their} radio."
+text.deu = "{They} nimmt {their} Funkgerät."
+text.ita = "Prende la radio."
+"#;
+
+    fn crisis() -> CrisisDefinition {
+        super::super::operations::parse_crisis_toml(CRISIS).unwrap()
+    }
+
+    fn player(gender: PlayerGender) -> GameState {
+        let mut state = GameState::new("pronoun_test".to_string(), "eng".to_string(), "Pronoun_Test".to_string());
+        state.character_name = "Anna".to_string();
+        state.player_gender = gender;
+        state
+    }
+
+    #[test]
+    fn parse_needs_all_five_forms() {
+        let xe = PronounSet::parse(" xe / xem/xyr/xyrs/xemself").unwrap();
+        assert_eq!(xe.they, "xe");
+        assert_eq!(xe.to_string(), "xe/xem/xyr/xyrs/xemself");
+        assert!(PronounSet::parse("xe/xem/xyr/xyrs").is_none());
+        assert!(PronounSet::parse("xe/xem//xyrs/xemself").is_none());
+    }
+
+    #[test]
+    fn substitute_keeps_placeholder_capitalization() {
+        let pronouns = PronounSet::builtin(PlayerGender::Female, "eng").unwrap();
+        assert_eq!(
+            substitute_pronouns("{They} said {their} radio was {theirs}, not {Them}.", &pronouns),
+            "She said her radio was hers, not Her."
+        );
+        let spanish = PronounSet::builtin(PlayerGender::Male, "spa").unwrap();
+        assert_eq!(substitute_pronouns("{They} habla.", &spanish), "Él habla.");
+        assert_eq!(substitute_pronouns("{character_name} and {unknown}", &spanish), "{character_name} and {unknown}");
+    }
+
+    #[test]
+    fn crisis_table_comes_before_builtin_sets() {
+        let crisis = crisis();
+        let pronouns = resolve_pronouns(&crisis, &player(PlayerGender::Female), "ita");
+        assert_eq!(pronouns.their, "sua");
+        let pronouns = resolve_pronouns(&crisis, &player(PlayerGender::Male), "ita");
+        assert_eq!(pronouns.their, "suo");
+    }
+
+    #[test]
+    fn missing_language_falls_back_along_the_chain_not_to_the_name() {
+        // German has no established neutral set, so the English one stands in
+        let pronouns = resolve_pronouns(&crisis(), &player(PlayerGender::Neutral), "deu");
+        assert_eq!(pronouns, PronounSet::default());
+        assert!(!pronouns.to_string().contains("Anna"));
+    }
+
+    #[test]
+    fn custom_pronouns_only_apply_to_their_language() {
+        let crisis = crisis();
+        let mut state = player(PlayerGender::Female);
+        state.pronouns = PronounSet::parse("xe/xem/xyr/xyrs/xemself");
+        state.pronouns_language = Some("eng".to_string());
+
+        assert_eq!(resolve_pronouns(&crisis, &state, "eng").they, "xe");
+        assert_eq!(resolve_pronouns(&crisis, &state, "spa").they, "ella");
+
+        // A language with nothing for the player falls back to English, and so to the custom set
+        state.player_gender = PlayerGender::Neutral;
+        assert_eq!(resolve_pronouns(&crisis, &state, "deu").they, "xe");
+
+        // Saves from before pronouns_language apply the custom set everywhere
+        state.pronouns_language = None;
+        assert_eq!(resolve_pronouns(&crisis, &state, "spa").they, "xe");
+    }
+
+    #[test]
+    fn reports_languages_using_placeholders_without_a_set() {
+        let missing = missing_pronoun_sets(&crisis());
+        // German text has {They}; Italian has no placeholders, so its missing neutral set does not matter
+        assert_eq!(missing, vec![("deu".to_string(), PlayerGender::Neutral)]);
+    }
+
+    #[test]
+    fn gender_forms_pick_the_players_word() {
+        let crisis = crisis();
+        let text = "{male:Despachador|female:Despachadora} {character_name}";
+        assert_eq!(apply_gender_forms(text, &crisis, "spa", PlayerGender::Female), "Despachadora {character_name}");
+        assert_eq!(apply_gender_forms(text, &crisis, "spa", PlayerGender::Neutral), "Despachador/Despachadora {character_name}");
+    }
+}
@@ -0,0 +1,208 @@
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Signature file at the root of a signed crisis folder or pack
+pub const SIGNATURE_FILE_NAME: &str = "signature.toml";
+
+/// Contents of `signature.toml`, both fields hex encoded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrisisSignature {
+    pub public_key: String,
+    pub signature: String,
+}
+
+impl CrisisSignature {
+    /// Contents for `signature.toml`
+    pub fn to_toml(&self) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureStatus {
+    BuiltIn, // Shipped inside the game, trusted without a signature
+    Verified(String), // Signed by this trusted public key
+    UntrustedKey(String), // Correctly signed, but by a key that is not in settings
+    Invalid(String), // Has a signature that does not match the files; the reason
+    Unsigned,
+}
+
+impl SignatureStatus {
+    /// True for content that may be played when unsigned crises are refused
+    pub fn is_trusted(&self) -> bool {
+        matches!(self, SignatureStatus::BuiltIn | SignatureStatus::Verified(_))
+    }
+
+    pub fn label(&self, language: &str) -> String {
+        use crate::translations::{t, TranslationKey};
+        match self {
+            SignatureStatus::BuiltIn | SignatureStatus::Verified(_) => t(TranslationKey::SignatureVerified, language),
+            SignatureStatus::UntrustedKey(_) => t(TranslationKey::SignatureUntrusted, language),
+            SignatureStatus::Invalid(_) => t(TranslationKey::SignatureInvalid, language),
+            SignatureStatus::Unsigned => t(TranslationKey::SignatureUnsigned, language),
+        }
+    }
+}
+
+/// The bytes that get signed: a "<path>\n<sha256 hex>\n" pair per file, in path
+/// order, with paths relative to the crisis folder. The signature file itself
+/// is left out, so the same signature holds for a folder and its `.fcpack`.
+pub fn signing_message(files: &[(String, Vec<u8>)]) -> Vec<u8> {
+    let mut files: Vec<&(String, Vec<u8>)> = files.iter()
+        .filter(|(path, _)| path != SIGNATURE_FILE_NAME)
+        .collect();
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut message = String::new();
+    for (path, data) in files {
+        message.push_str(path);
+        message.push('\n');
+        message.push_str(&hex::encode(Sha256::digest(data)));
+        message.push('\n');
+    }
+    message.into_bytes()
+}
+
+pub fn sign_files(files: &[(String, Vec<u8>)], signing_key: &SigningKey) -> CrisisSignature {
+    let signature = signing_key.sign(&signing_message(files));
+    CrisisSignature {
+        public_key: hex::encode(signing_key.verifying_key().to_bytes()),
+        signature: hex::encode(signature.to_bytes()),
+    }
+}
+
+/// Checks the crisis files against their `signature.toml`, if they have one
+pub fn verify_files(files: &[(String, Vec<u8>)], trusted_keys: &[String]) -> SignatureStatus {
+    let Some((_, signature_file)) = files.iter().find(|(path, _)| path == SIGNATURE_FILE_NAME) else {
+        return SignatureStatus::Unsigned;
+    };
+
+    let signature = match std::str::from_utf8(signature_file)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| toml::from_str::<CrisisSignature>(contents).map_err(|e| e.to_string()))
+    {
+        Ok(signature) => signature,
+        Err(e) => return SignatureStatus::Invalid(format!("Unreadable {}: {}", SIGNATURE_FILE_NAME, e)),
+    };
+
+    let verifying_key = match parse_public_key(&signature.public_key) {
+        Ok(verifying_key) => verifying_key,
+        Err(e) => return SignatureStatus::Invalid(e),
+    };
+    let signature_bytes: [u8; 64] = match hex::decode(signature.signature.trim()).ok().and_then(|bytes| bytes.try_into().ok()) {
+        Some(signature_bytes) => signature_bytes,
+        None => return SignatureStatus::Invalid("Signature is not 64 hex encoded bytes".to_string()),
+    };
+
+    if let Err(e) = verifying_key.verify(&signing_message(files), &Signature::from_bytes(&signature_bytes)) {
+        return SignatureStatus::Invalid(format!("Files do not match the signature: {}", e));
+    }
+
+    let public_key = hex::encode(verifying_key.to_bytes());
+    if trusted_keys.iter().any(|trusted_key| trusted_key.trim().eq_ignore_ascii_case(&public_key)) {
+        SignatureStatus::Verified(public_key)
+    } else {
+        SignatureStatus::UntrustedKey(public_key)
+    }
+}
+
+pub fn parse_public_key(public_key: &str) -> Result<VerifyingKey, String> {
+    let key_bytes: [u8; 32] = hex::decode(public_key.trim()).ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| "Public key is not 32 hex encoded bytes".to_string())?;
+    VerifyingKey::from_bytes(&key_bytes).map_err(|e| format!("Invalid public key: {}", e))
+}
+
+pub fn parse_signing_key(secret_key: &str) -> Result<SigningKey, String> {
+    let key_bytes: [u8; 32] = hex::decode(secret_key.trim()).ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| "Secret key is not 32 hex encoded bytes".to_string())?;
+    Ok(SigningKey::from_bytes(&key_bytes))
+}
+
+/// Hex encoded (secret key, public key) for a new signing key made from 32 random bytes
+pub fn keypair_from_seed(seed: [u8; 32]) -> (String, String) {
+    let signing_key = SigningKey::from_bytes(&seed);
+    (hex::encode(signing_key.to_bytes()), hex::encode(signing_key.verifying_key().to_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crisis_files() -> Vec<(String, Vec<u8>)> {
+        vec![
+            ("crisis.toml".to_string(), b"[metadata]\nid = \"test\"\n".to_vec()),
+            ("scenes/intro.toml".to_string(), b"[text]\neng = \"Hello\"\n".to_vec()),
+        ]
+    }
+
+    fn signed_files(seed: [u8; 32]) -> (Vec<(String, Vec<u8>)>, String) {
+        let signing_key = SigningKey::from_bytes(&seed);
+        let mut files = crisis_files();
+        let signature = sign_files(&files, &signing_key);
+        files.push((SIGNATURE_FILE_NAME.to_string(), signature.to_toml().unwrap().into_bytes()));
+        (files, signature.public_key)
+    }
+
+    #[test]
+    fn accepts_files_signed_by_a_trusted_key() {
+        let (files, public_key) = signed_files([7; 32]);
+        assert_eq!(verify_files(&files, &[public_key.to_uppercase()]), SignatureStatus::Verified(public_key));
+    }
+
+    #[test]
+    fn reports_a_valid_signature_by_an_unknown_key() {
+        let (files, public_key) = signed_files([7; 32]);
+        let (_, other_key) = keypair_from_seed([8; 32]);
+        assert_eq!(verify_files(&files, &[other_key]), SignatureStatus::UntrustedKey(public_key));
+    }
+
+    #[test]
+    fn rejects_altered_files() {
+        let (mut files, public_key) = signed_files([7; 32]);
+        files[1].1 = b"[text]\neng = \"Goodbye\"\n".to_vec();
+        assert!(matches!(verify_files(&files, &[public_key.clone()]), SignatureStatus::Invalid(_)));
+
+        let (mut files, _) = signed_files([7; 32]);
+        files.push(("scenes/extra.toml".to_string(), b"".to_vec()));
+        assert!(matches!(verify_files(&files, &[public_key]), SignatureStatus::Invalid(_)));
+    }
+
+    #[test]
+    fn rejects_a_signature_made_by_another_key() {
+        let (mut files, public_key) = signed_files([7; 32]);
+        let forged = sign_files(&crisis_files(), &SigningKey::from_bytes(&[9; 32]));
+        let forged = CrisisSignature { public_key: public_key.clone(), signature: forged.signature };
+        files.retain(|(path, _)| path != SIGNATURE_FILE_NAME);
+        files.push((SIGNATURE_FILE_NAME.to_string(), forged.to_toml().unwrap().into_bytes()));
+        assert!(matches!(verify_files(&files, &[public_key]), SignatureStatus::Invalid(_)));
+    }
+
+    #[test]
+    fn unsigned_and_unreadable_signatures() {
+        assert_eq!(verify_files(&crisis_files(), &[]), SignatureStatus::Unsigned);
+
+        let mut files = crisis_files();
+        files.push((SIGNATURE_FILE_NAME.to_string(), b"not toml".to_vec()));
+        assert!(matches!(verify_files(&files, &[]), SignatureStatus::Invalid(_)));
+    }
+
+    #[test]
+    fn signing_message_ignores_file_order_and_the_signature_file() {
+        let mut reordered = crisis_files();
+        reordered.reverse();
+        reordered.push((SIGNATURE_FILE_NAME.to_string(), b"anything".to_vec()));
+        assert_eq!(signing_message(&reordered), signing_message(&crisis_files()));
+    }
+
+    #[test]
+    fn parses_keys_only_as_32_hex_bytes() {
+        let (secret_key, public_key) = keypair_from_seed([3; 32]);
+        assert_eq!(hex::encode(parse_signing_key(&secret_key).unwrap().verifying_key().to_bytes()), public_key);
+        assert!(parse_public_key(&format!(" {} ", public_key)).is_ok());
+        assert!(parse_public_key("abcd").is_err());
+        assert!(parse_signing_key("zz").is_err());
+    }
+}
@@ -0,0 +1,47 @@
+/// Where a crisis was loaded from.
+///
+/// The same crisis folder name may exist in several sources. The one that
+/// comes first in `available()` wins and shadows the others as a whole, so a
+/// crisis never mixes files from two sources. Loose folders beat packs so
+/// authors can fork and edit, and packs beat the built-in crises so installed
+/// updates take effect.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CrisisSource {
+    CrisesFolder, // The crises folder from settings (native only)
+    Pack(String), // An .fcpack archive, by file name
+    Embedded, // Built into the game from playable-crises/
+}
+
+impl CrisisSource {
+    /// Kinds of source in priority order, highest first. Packs are listed once
+    /// per loaded archive, in file name order.
+    pub fn available() -> Vec<CrisisSource> {
+        let mut sources = vec![];
+        #[cfg(not(target_arch = "wasm32"))]
+        sources.push(CrisisSource::CrisesFolder);
+        for pack in super::pack::loaded_packs() {
+            sources.push(CrisisSource::Pack(pack.file_name.clone()));
+        }
+        sources.push(CrisisSource::Embedded);
+        sources
+    }
+
+    pub fn label(&self, language: &str) -> String {
+        use crate::translations::{t, TranslationKey};
+        match self {
+            CrisisSource::CrisesFolder => t(TranslationKey::SourceCrisesFolder, language),
+            CrisisSource::Pack(file_name) => format!("{} {}", t(TranslationKey::SourcePack, language), file_name),
+            CrisisSource::Embedded => t(TranslationKey::SourceBuiltIn, language),
+        }
+    }
+}
+
+impl std::fmt::Display for CrisisSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CrisisSource::CrisesFolder => write!(f, "crises folder"),
+            CrisisSource::Pack(file_name) => write!(f, "pack {}", file_name),
+            CrisisSource::Embedded => write!(f, "built-in"),
+        }
+    }
+}
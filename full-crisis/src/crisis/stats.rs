@@ -0,0 +1,283 @@
+use super::types::{CrisisDefinition, GameState};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+const PLAYER_STATS_ATTR: &str = "player_stats";
+
+/// Completed run ids kept per crisis; plenty to recognize a rewound or reloaded run
+const COMPLETED_RUNS_KEPT: usize = 64;
+
+/// What the player has done in one crisis, across every playthrough
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CrisisStats {
+    pub runs: u32,        // Playthroughs started, restarts included
+    pub completions: u32, // Playthroughs that reached an ending
+    pub endings_reached: BTreeSet<String>,
+    pub scenes_discovered: BTreeSet<String>,
+    pub scene_count: usize,  // Scenes in the crisis the last time it was played
+    pub ending_count: usize, // Scenes without choices in the crisis the last time it was played
+    pub best_score: Option<i32>,     // Highest `mechanics.score_variable` at an ending
+    pub best_time_ms: Option<u64>, // Least play time to an ending
+    pub last_played: Option<u64>,    // Unix seconds
+    pub completed_runs: Vec<u64>, // `GameState::run_id` of recent completions, oldest first
+}
+
+impl CrisisStats {
+    /// Share of the crisis' scenes seen so far, 0 to 100
+    pub fn discovered_percent(&self) -> u32 {
+        if self.scene_count == 0 {
+            return 0;
+        }
+        ((self.scenes_discovered.len() * 100 / self.scene_count) as u32).min(100)
+    }
+
+    /// Count the crisis' scenes and endings as they are now; scenes seen in an older
+    /// version of the crisis stay discovered
+    fn refresh_totals(&mut self, crisis: &CrisisDefinition) {
+        self.scene_count = crisis.scenes.len();
+        self.ending_count = crisis.scenes.values().filter(|scene| scene.choices.is_empty()).count();
+        self.last_played = Some(unix_now());
+    }
+}
+
+/// Per-crisis statistics of one player profile, keyed by template name like saved games
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerStats {
+    pub crises: BTreeMap<String, CrisisStats>,
+}
+
+impl PlayerStats {
+    pub fn get(&self, template_name: &str) -> Option<&CrisisStats> {
+        self.crises.get(template_name)
+    }
+
+    pub fn total_runs(&self) -> u32 {
+        self.crises.values().map(|stats| stats.runs).sum()
+    }
+
+    pub fn total_completions(&self) -> u32 {
+        self.crises.values().map(|stats| stats.completions).sum()
+    }
+
+    pub fn total_endings(&self) -> usize {
+        self.crises.values().map(|stats| stats.endings_reached.len()).sum()
+    }
+
+    /// Counts a new playthrough and gives it a new run id; call once the story state is on the starting scene
+    pub fn record_run_started(&mut self, crisis: &CrisisDefinition, story_state: &mut GameState) {
+        story_state.run_id = new_run_id();
+        let stats = self.crises.entry(story_state.template_name.clone()).or_default();
+        stats.runs += 1;
+        stats.refresh_totals(crisis);
+        stats.scenes_discovered.insert(story_state.current_scene.clone());
+    }
+
+    /// Marks the story state's scene as discovered. When it is an ending this also keeps the
+    /// play time and score if they beat the best so far, and counts a completion unless this
+    /// run already ended once, e.g. before a rewind or in a save that was loaded again.
+    pub fn record_scene_reached(&mut self, crisis: &CrisisDefinition, story_state: &mut GameState) {
+        let Some(scene) = crisis.scenes.get(&story_state.current_scene) else {
+            return;
+        };
+        // Saves from before run ids get one here
+        if story_state.run_id == 0 {
+            story_state.run_id = new_run_id();
+        }
+
+        let stats = self.crises.entry(story_state.template_name.clone()).or_default();
+        stats.refresh_totals(crisis);
+        stats.scenes_discovered.insert(story_state.current_scene.clone());
+
+        if scene.choices.is_empty() {
+            if !stats.completed_runs.contains(&story_state.run_id) {
+                stats.completions += 1;
+                stats.completed_runs.push(story_state.run_id);
+                if stats.completed_runs.len() > COMPLETED_RUNS_KEPT {
+                    stats.completed_runs.remove(0);
+                }
+            }
+            stats.endings_reached.insert(story_state.current_scene.clone());
+            stats.best_time_ms = Some(match stats.best_time_ms {
+                Some(best) => best.min(story_state.play_time_ms),
+                None => story_state.play_time_ms,
+            });
+            let score = crisis.mechanics.score_variable.as_ref()
+                .and_then(|name| story_state.variables.get(name).copied());
+            if let Some(score) = score {
+                stats.best_score = Some(stats.best_score.map_or(score, |best| best.max(score)));
+            }
+        }
+    }
+}
+
+pub fn get_player_stats() -> PlayerStats {
+    if let Some(content) = crate::storage::get_profile_attr(PLAYER_STATS_ATTR) {
+        serde_json::from_str(&content).unwrap_or_default()
+    } else {
+        PlayerStats::default()
+    }
+}
+
+pub fn save_player_stats(player_stats: &PlayerStats) {
+    if let Ok(serialized) = serde_json::to_string(player_stats) {
+        crate::storage::set_profile_attr(PLAYER_STATS_ATTR, &serialized);
+    }
+}
+
+/// A random nonzero run id
+fn new_run_id() -> u64 {
+    rand::random::<u64>().max(1)
+}
+
+fn unix_now() -> u64 {
+    crate::internal_storage::time_now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Play time as m:ss, or h:mm:ss from an hour up
+pub fn format_play_time(play_time_ms: u64) -> String {
+    let secs = play_time_ms / 1000;
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+/// A unix timestamp as a YYYY-MM-DD date in UTC
+pub fn format_date(unix_secs: u64) -> String {
+    // Days since 1970-01-01 to a civil date, after Howard Hinnant's `civil_from_days`
+    let days = (unix_secs / 86_400) as i64 + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CRISIS: &str = r#"[metadata]
+id = "stats_test"
+version = "1.0"
+author = "Tester"
+description_key = "stats_test_desc"
+
+[name]
+eng = "Stats Test"
+
+[description]
+eng = "A crisis for tests"
+
+[character_names.player.any]
+eng = ["Sam"]
+
+[story]
+starting_scene = "intro"
+default_language = "eng"
+
+[mechanics]
+time_limit_minutes = 5
+save_progress = true
+allow_restart = true
+track_decisions = false
+score_variable = "calm"
+
+[conditions]
+
+[scenes.intro]
+text.eng = "The phone rings."
+
+[[scenes.intro.choices]]
+text.eng = "Answer it"
+leads_to = "ending"
+
+[scenes.ending]
+text.eng = "Silence."
+"#;
+
+    fn start_run(stats: &mut PlayerStats, crisis: &CrisisDefinition) -> GameState {
+        let mut story_state = GameState::new("stats_test".to_string(), "eng".to_string(), "Stats_Test".to_string());
+        story_state.current_scene = "intro".to_string();
+        stats.record_run_started(crisis, &mut story_state);
+        story_state
+    }
+
+    #[test]
+    fn discovered_percent_is_a_share_of_the_scenes() {
+        let mut stats = CrisisStats::default();
+        assert_eq!(stats.discovered_percent(), 0);
+
+        stats.scene_count = 3;
+        stats.scenes_discovered.insert("intro".to_string());
+        assert_eq!(stats.discovered_percent(), 33);
+
+        // Scenes seen in an older, larger version of the crisis do not push it past 100
+        stats.scene_count = 1;
+        stats.scenes_discovered.insert("removed".to_string());
+        assert_eq!(stats.discovered_percent(), 100);
+    }
+
+    #[test]
+    fn format_date_gives_utc_calendar_dates() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(86_399), "1970-01-01");
+        assert_eq!(format_date(946_598_400), "1999-12-31");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(951_868_800), "2000-03-01");
+        assert_eq!(format_date(1_709_164_800), "2024-02-29");
+    }
+
+    #[test]
+    fn format_play_time_adds_hours_only_when_needed() {
+        assert_eq!(format_play_time(0), "0:00");
+        assert_eq!(format_play_time(59_999), "0:59");
+        assert_eq!(format_play_time(61_000), "1:01");
+        assert_eq!(format_play_time(3_600_000), "1:00:00");
+        assert_eq!(format_play_time(3_725_000), "1:02:05");
+    }
+
+    #[test]
+    fn a_run_completes_once_even_when_its_ending_is_reached_again() {
+        let crisis = super::super::operations::parse_crisis_toml(CRISIS).unwrap();
+        let mut stats = PlayerStats::default();
+        let mut story_state = start_run(&mut stats, &crisis);
+        let before_ending = story_state.clone();
+
+        story_state.current_scene = "ending".to_string();
+        story_state.variables.insert("calm".to_string(), 4);
+        stats.record_scene_reached(&crisis, &mut story_state);
+
+        // Rewinding, or loading a save made before the ending, and choosing again is the same run
+        let mut replayed = before_ending.clone();
+        replayed.current_scene = "ending".to_string();
+        replayed.variables.insert("calm".to_string(), 7);
+        stats.record_scene_reached(&crisis, &mut replayed);
+
+        let crisis_stats = stats.get("Stats_Test").unwrap();
+        assert_eq!(crisis_stats.runs, 1);
+        assert_eq!(crisis_stats.completions, 1);
+        assert_eq!(crisis_stats.best_score, Some(7));
+        assert_eq!(crisis_stats.ending_count, 1);
+        assert_eq!(crisis_stats.discovered_percent(), 100);
+
+        // Restarting is a new run
+        let mut story_state = start_run(&mut stats, &crisis);
+        story_state.current_scene = "ending".to_string();
+        stats.record_scene_reached(&crisis, &mut story_state);
+        assert_eq!(stats.total_runs(), 2);
+        assert_eq!(stats.total_completions(), 2);
+        assert_eq!(stats.total_endings(), 1);
+    }
+}
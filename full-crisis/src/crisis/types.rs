@@ -7,12 +7,137 @@ pub struct CrisisMetadata {
     pub version: String,
     pub author: String,
     pub description_key: String,
+    #[serde(default)]
+    pub license: Option<String>, // Copied into the manifest by `full-crisis pack`
+    #[serde(default)]
+    pub difficulty: Option<crate::gui::DifficultyLevel>,
+    #[serde(default)]
+    pub tags: Vec<String>, // Shown on the crisis browser's cards and offered as a filter
+    #[serde(default)]
+    pub cover_image: Option<String>, // e.g. "Fire_Dispatch/cover.png"; defaults to the starting scene's background
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Role used when a choice sets no `character_type`, i.e. the name the player starts with
+pub const DEFAULT_CHARACTER_ROLE: &str = "player";
+/// Gender key of names that suit every player gender
+pub const ANY_GENDER: &str = "any";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CrisisCharacterNames {
     #[serde(flatten)]
-    pub names: HashMap<String, Vec<String>>,
+    pub entries: HashMap<String, CharacterNamesEntry>,
+}
+
+/// One `[character_names]` entry: either a structured role table or a legacy flat name list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CharacterNamesEntry {
+    Names(Vec<String>), // Legacy key "<role>_<gender>_<language>", e.g. "student_male_eng" or "female_spa"
+    Table(HashMap<String, HashMap<String, Vec<String>>>), // [character_names.<role>.<gender>] language = [names]
+}
+
+/// Names for one role, gender and language, however the crisis wrote them
+#[derive(Debug, Clone, PartialEq)]
+pub struct CharacterNameList<'a> {
+    pub role: String,
+    pub gender: String, // "male", "female", "neutral", or ANY_GENDER
+    pub language: String,
+    pub names: &'a [String],
+}
+
+impl CrisisCharacterNames {
+    /// Every name list, with legacy keys split into role, gender and language
+    pub fn lists(&self) -> Vec<CharacterNameList<'_>> {
+        let mut lists = vec![];
+        for (key, entry) in self.entries.iter() {
+            match entry {
+                CharacterNamesEntry::Names(names) => {
+                    if let Some((role, gender, language)) = split_legacy_name_key(key) {
+                        lists.push(CharacterNameList { role, gender, language, names });
+                    }
+                }
+                CharacterNamesEntry::Table(genders) => {
+                    for (gender, languages) in genders {
+                        for (language, names) in languages {
+                            lists.push(CharacterNameList {
+                                role: key.clone(),
+                                gender: gender.clone(),
+                                language: language.clone(),
+                                names,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        lists.sort_by(|a, b| (&a.role, &a.gender, &a.language).cmp(&(&b.role, &b.gender, &b.language)));
+        lists
+    }
+    
+    /// Names of a role in exactly this gender and language
+    pub fn names_for(&self, role: &str, gender: &str, language: &str) -> Vec<&String> {
+        self.lists().into_iter()
+            .filter(|list| list.role == role && list.gender == gender && list.language == language)
+            .flat_map(|list| list.names.iter())
+            .collect()
+    }
+}
+
+/// "student_male_eng" is (student, male, eng), "female_spa" is (player, female, spa) and
+/// "dispatcher_eng" is (dispatcher, any, eng). The last part must be a three letter language code.
+fn split_legacy_name_key(key: &str) -> Option<(String, String, String)> {
+    let (rest, language) = key.rsplit_once('_')?;
+    if language.len() != 3 || !language.chars().all(|c| c.is_ascii_lowercase()) {
+        return None;
+    }
+    let (role, gender) = match rest.rsplit_once('_') {
+        Some((role, gender)) if PlayerGender::from_key(gender).is_some() => (role, gender),
+        _ if PlayerGender::from_key(rest).is_some() => (DEFAULT_CHARACTER_ROLE, rest),
+        _ => (rest, ANY_GENDER),
+    };
+    Some((role.to_string(), gender.to_string(), language.to_string()))
+}
+
+/// The player's gender, chosen on the New Game screen. It picks gendered character names and
+/// the pronouns scene text uses for the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayerGender {
+    Female,
+    Male,
+    #[default]
+    Neutral,
+}
+
+impl PlayerGender {
+    pub const ALL: [PlayerGender; 3] = [
+        PlayerGender::Female,
+        PlayerGender::Male,
+        PlayerGender::Neutral,
+    ];
+    
+    /// Gender key used in `[character_names]`
+    pub fn key(self) -> &'static str {
+        match self {
+            PlayerGender::Female => "female",
+            PlayerGender::Male => "male",
+            PlayerGender::Neutral => "neutral",
+        }
+    }
+    
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|gender| gender.key() == key)
+    }
+    
+    /// Pronoun label for pickers, e.g. "She/Her"
+    pub fn to_translated_string(self, language: &str) -> String {
+        let key = match self {
+            PlayerGender::Female => crate::translations::TranslationKey::PronounsShe,
+            PlayerGender::Male => crate::translations::TranslationKey::PronounsHe,
+            PlayerGender::Neutral => crate::translations::TranslationKey::PronounsThey,
+        };
+        crate::translations::t(key, language)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,20 +146,198 @@ pub struct CrisisStory {
     pub default_language: String,
 }
 
+/// `[palette]` in crisis.toml: accent colors laid over the player's theme while the crisis is played.
+/// Colors are hex, e.g. "#c0392b"; the background and text colors always come from the theme.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CrisisPalette {
+    pub primary: Option<String>, // Buttons, selections and the focus ring
+    pub success: Option<String>,
+    pub danger: Option<String>,
+}
+
+impl CrisisPalette {
+    /// The palette's colors as (name, hex) in the order they are written
+    pub fn entries(&self) -> [(&'static str, Option<&str>); 3] {
+        [
+            ("primary", self.primary.as_deref()),
+            ("success", self.success.as_deref()),
+            ("danger", self.danger.as_deref()),
+        ]
+    }
+
+    /// Colors set to something other than "#rrggbb" or "#rgb", which the game ignores
+    pub fn invalid_entries(&self) -> Vec<(&'static str, &str)> {
+        self.entries().into_iter()
+            .filter_map(|(name, hex)| Some((name, hex?)))
+            .filter(|(_, hex)| parse_hex_color(hex).is_none())
+            .collect()
+    }
+}
+
+/// "#c0392b" or "#c33" as red, green and blue; the leading '#' is optional
+pub fn parse_hex_color(hex: &str) -> Option<[u8; 3]> {
+    let digits = hex.trim().trim_start_matches('#');
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    match digits.len() {
+        6 => Some([
+            u8::from_str_radix(&digits[0..2], 16).ok()?,
+            u8::from_str_radix(&digits[2..4], 16).ok()?,
+            u8::from_str_radix(&digits[4..6], 16).ok()?,
+        ]),
+        3 => {
+            let mut rgb = [0u8; 3];
+            for (channel, c) in rgb.iter_mut().zip(digits.chars()) {
+                *channel = u8::from_str_radix(&c.to_string(), 16).ok()? * 17;
+            }
+            Some(rgb)
+        }
+        _ => None,
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrisisMechanics {
     pub time_limit_minutes: u32,
     pub save_progress: bool,
     pub allow_restart: bool,
+    #[serde(default = "default_true")]
+    pub allow_rewind: bool,
     pub track_decisions: bool,
+    #[serde(default)]
+    pub score_variable: Option<String>, // Variable whose value at an ending is kept as the player's best score
+}
+
+fn default_true() -> bool {
+    true
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrisisConditions {
-    pub variables: Option<Vec<String>>,
+    pub variables: Option<Vec<CrisisVariable>>,
     pub choice_effects: Option<HashMap<String, HashMap<String, i32>>>,
 }
 
+impl CrisisConditions {
+    pub fn variable_specs(&self) -> Vec<VariableSpec> {
+        self.variables.iter()
+            .flatten()
+            .map(|variable| variable.spec())
+            .collect()
+    }
+    
+    /// Starting values of the variables that declare one
+    pub fn initial_variables(&self) -> HashMap<String, i32> {
+        self.variable_specs().into_iter()
+            .filter_map(|spec| Some((spec.name.clone(), spec.initial?)))
+            .collect()
+    }
+    
+    /// What the HUD shows, with current values: declared variables in declaration order, then
+    /// undeclared ones a choice effect created, by name. Hidden variables, and ones with
+    /// neither a value nor an initial value yet, are left out.
+    pub fn hud_variables(&self, values: &HashMap<String, i32>) -> Vec<(VariableSpec, i32)> {
+        let specs = self.variable_specs();
+        let mut undeclared: Vec<&String> = values.keys()
+            .filter(|name| !specs.iter().any(|spec| spec.name == **name))
+            .collect();
+        undeclared.sort();
+        
+        let undeclared_specs = undeclared.into_iter().map(|name| CrisisVariable::Name(name.clone()).spec());
+        specs.into_iter()
+            .chain(undeclared_specs)
+            .filter(|spec| spec.style != VariableStyle::Hidden)
+            .filter_map(|spec| {
+                let value = values.get(&spec.name).copied().or(spec.initial)?;
+                Some((spec, value))
+            })
+            .collect()
+    }
+}
+
+/// One `conditions.variables` entry: a bare name, or a table describing how the HUD shows it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CrisisVariable {
+    Name(String),
+    Spec(VariableSpec),
+}
+
+impl CrisisVariable {
+    pub fn name(&self) -> &str {
+        match self {
+            CrisisVariable::Name(name) => name,
+            CrisisVariable::Spec(spec) => &spec.name,
+        }
+    }
+    
+    /// The display spec; a bare name is a counter labelled with the name
+    pub fn spec(&self) -> VariableSpec {
+        match self {
+            CrisisVariable::Name(name) => VariableSpec {
+                name: name.clone(),
+                label: HashMap::new(),
+                icon: None,
+                initial: None,
+                min: None,
+                max: None,
+                style: VariableStyle::default(),
+                warn_below: None,
+                warn_above: None,
+            },
+            CrisisVariable::Spec(spec) => spec.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariableSpec {
+    pub name: String,
+    #[serde(default)]
+    pub label: HashMap<String, String>, // Language code to label; the name with spaces for underscores when missing
+    pub icon: Option<String>, // Image path like background_image, or a short symbol drawn as text
+    pub initial: Option<i32>, // Value when the crisis starts; unset variables start at 0 once a choice changes them
+    pub min: Option<i32>,
+    pub max: Option<i32>,
+    #[serde(default)]
+    pub style: VariableStyle,
+    pub warn_below: Option<i32>, // Drawn as a warning when the value drops under this
+    pub warn_above: Option<i32>, // Drawn as a warning when the value climbs over this
+}
+
+impl VariableSpec {
+    pub fn label(&self, language: &str) -> String {
+        if self.label.is_empty() {
+            return self.name.replace('_', " ");
+        }
+        crate::crisis::get_localized_text(&self.label, language)
+    }
+    
+    pub fn is_warning(&self, value: i32) -> bool {
+        self.warn_below.is_some_and(|threshold| value < threshold)
+            || self.warn_above.is_some_and(|threshold| value > threshold)
+    }
+    
+    /// How full a bar is, 0.0 to 1.0; None without both a min and a max
+    pub fn fraction(&self, value: i32) -> Option<f32> {
+        let (min, max) = (self.min?, self.max?);
+        if max <= min {
+            return None;
+        }
+        Some(((value - min) as f32 / (max - min) as f32).clamp(0.0, 1.0))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VariableStyle {
+    Bar, // A meter between min and max; shown as a counter when either is missing
+    #[default]
+    Counter,
+    Hidden, // Tracked for requirements and effects but never shown to the player
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrisisChoice {
     pub text: HashMap<String, String>,
@@ -80,6 +383,46 @@ pub struct CrisisScene {
     #[serde(default)]
     pub speaking_character_image: Option<SpeakingCharacterImage>,
     pub background_audio: Option<String>,
+    #[serde(default)]
+    pub transition: SceneTransition,
+}
+
+/// How a scene arrives on screen, from the scene's optional `[transition]` table. Without
+/// one the scene cuts in with instant text, as before transitions existed. Players who turn
+/// on "reduce motion" always get a cut and instant text.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SceneTransition {
+    pub background: BackgroundTransition,
+    pub duration_ms: u64,
+    pub text: TextReveal,
+    pub characters_per_second: f32,
+}
+
+impl Default for SceneTransition {
+    fn default() -> Self {
+        Self {
+            background: BackgroundTransition::Cut,
+            duration_ms: 400, // Used once a scene picks "fade" or "crossfade"
+            text: TextReveal::Instant,
+            characters_per_second: 40.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackgroundTransition {
+    Cut, // The new background replaces the old one at once
+    Fade, // The old background fades to black, then the new one fades in
+    Crossfade, // The new background fades in over the old one
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TextReveal {
+    Instant,
+    Typewriter, // Characters appear one at a time; Enter or the A button shows the rest
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,19 +435,97 @@ pub struct CrisisDefinition {
     pub mechanics: CrisisMechanics,
     pub conditions: CrisisConditions,
     #[serde(default)]
+    pub pronouns: HashMap<String, HashMap<String, String>>, // [pronouns.<gender>] language = "they/them/their/theirs/themself"
+    #[serde(default)]
+    pub gender_forms: HashMap<String, HashMap<String, HashMap<String, String>>>, // [gender_forms.<language>] word = { female = "...", male = "..." }
+    #[serde(default)]
+    pub palette: Option<CrisisPalette>,
+    #[serde(default)]
     pub scenes: HashMap<String, CrisisScene>,
 }
 
-#[derive(Debug, Clone)]
+impl CrisisDefinition {
+    /// Every image the game screen can show for this crisis: scene backgrounds, character
+    /// images and animation frames, and HUD icons. Icons drawn as text are listed too.
+    pub fn image_paths(&self) -> Vec<String> {
+        let mut paths: Vec<String> = vec![];
+        for scene in self.scenes.values() {
+            paths.extend(scene.background_image.iter().cloned());
+            match scene.speaking_character_image {
+                Some(SpeakingCharacterImage::Single(ref path)) => paths.push(path.clone()),
+                Some(SpeakingCharacterImage::Animation(ref frames)) => paths.extend(frames.iter().cloned()),
+                None => {}
+            }
+        }
+        paths.extend(self.conditions.variable_specs().into_iter().filter_map(|spec| spec.icon));
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+
+    /// Languages to try for this crisis' text: the requested one, the game's usual fallbacks,
+    /// then the language the crisis was written in
+    pub fn language_fallback_chain(&self, language: &str) -> Vec<String> {
+        let mut chain = crate::language::get_language_fallback_chain(language);
+        if !chain.contains(&self.story.default_language) {
+            chain.push(self.story.default_language.clone());
+        }
+        chain
+    }
+
+    /// `get_localized_text` with this crisis' fallback chain, for crises not written in English
+    pub fn localized_text(&self, text_map: &HashMap<String, String>, language: &str) -> String {
+        self.language_fallback_chain(language).iter()
+            .find_map(|lang| text_map.get(lang))
+            .cloned()
+            .unwrap_or_else(|| "Missing text".to_string())
+    }
+
+    /// Languages the crisis has scene text in, default language first
+    pub fn languages(&self) -> Vec<String> {
+        let mut languages: Vec<String> = self.scenes.values()
+            .flat_map(|scene| scene.text.keys())
+            .chain(self.name.keys())
+            .cloned()
+            .collect::<std::collections::BTreeSet<String>>()
+            .into_iter()
+            .collect();
+        languages.sort_by_key(|language| *language != self.story.default_language);
+        languages
+    }
+}
+
+/// One transcript entry: the scene the player was in, what they chose and what it changed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionRecord {
+    pub scene: String,
+    pub choice_index: usize,
+    pub text_input: Option<String>, // What the player typed, for text input choices
+    pub variable_changes: Vec<(String, i32)>, // Sorted by variable name
+    #[serde(default)]
+    pub scene_text: Option<String>, // The scene as the player read it, in the story language; None in older saves
+    #[serde(default)]
+    pub choice_text: Option<String>, // The chosen option's text, in the same language
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GameState {
     pub current_scene: String,
     pub character_name: String,
     pub character_type: Option<String>,
+    pub player_gender: PlayerGender,
+    pub pronouns: Option<crate::crisis::PronounSet>, // Set by the player on the New Game screen; otherwise picked from player_gender
+    pub pronouns_language: Option<String>, // Language `pronouns` were entered in, the only one they apply to; None applies them everywhere
     pub variables: HashMap<String, i32>,
+    pub decisions: Vec<DecisionRecord>, // Transcript of the choices made so far, when the crisis tracks decisions
     pub text_inputs: HashMap<String, String>,
     pub language: String,
+    pub secondary_language: Option<String>, // Shown side by side with `language` in dual-language mode
     pub crisis_id: String,
     pub template_name: String,
+    pub play_time_ms: u64, // Time spent in this playthrough, carried through saves
+    pub run_id: u64, // Identifies the playthrough so statistics count its ending once; 0 until stats assign one
 }
 
 impl GameState {
@@ -113,11 +534,18 @@ impl GameState {
             current_scene: String::new(),
             character_name: String::new(),
             character_type: None,
+            player_gender: PlayerGender::default(),
+            pronouns: None,
+            pronouns_language: None,
             variables: HashMap::new(),
+            decisions: Vec::new(),
             text_inputs: HashMap::new(),
             language,
+            secondary_language: None,
             crisis_id,
             template_name,
+            play_time_ms: 0,
+            run_id: 0,
         }
     }
 }
@@ -132,9 +560,25 @@ pub struct SavedGame {
     #[serde(default)]
     pub text_inputs: HashMap<String, String>,
     pub character_type: Option<String>,
+    #[serde(default)]
+    pub player_gender: PlayerGender,
+    #[serde(default)]
+    pub pronouns: Option<crate::crisis::PronounSet>,
+    #[serde(default)]
+    pub pronouns_language: Option<String>,
+    #[serde(default)]
+    pub decisions: Vec<DecisionRecord>,
     pub language: String,
+    #[serde(default)]
+    pub secondary_language: Option<String>,
     pub save_timestamp: String,
     pub template_name: String,
+    #[serde(default)]
+    pub play_time_ms: u64,
+    #[serde(default)]
+    pub run_id: u64,
+    #[serde(default)]
+    pub history: crate::crisis::GameHistory, // Points the player can rewind to, so a loaded game can still go back
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -0,0 +1,71 @@
+use notify::Watcher;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, channel};
+
+/// Watches the user's crises folder so writers can see edits without restarting.
+pub struct CrisisWatcher {
+    folder: PathBuf,
+    _watcher: notify::RecommendedWatcher, // Dropping this stops the watch
+    events: Receiver<notify::Result<notify::Event>>,
+}
+
+impl CrisisWatcher {
+    pub fn new(crises_folder: &str) -> Result<Self, notify::Error> {
+        let folder = std::fs::canonicalize(crises_folder)
+            .map_err(notify::Error::io)?;
+
+        let (sender, events) = channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        watcher.watch(&folder, notify::RecursiveMode::Recursive)?;
+
+        let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
+        if *verbosity > 0 {
+            eprintln!("CrisisWatcher: Watching {:?}", folder);
+        }
+
+        Ok(Self {
+            folder,
+            _watcher: watcher,
+            events,
+        })
+    }
+
+    /// Drains pending file events and returns the template names of every crisis that changed
+    pub fn changed_crises(&self) -> BTreeSet<String> {
+        let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
+        let mut changed = BTreeSet::new();
+
+        for event in self.events.try_iter() {
+            match event {
+                Ok(event) => {
+                    if matches!(event.kind, notify::EventKind::Access(_)) {
+                        continue;
+                    }
+                    for path in event.paths.iter() {
+                        if let Some(template_name) = self.template_name_for(path) {
+                            changed.insert(template_name);
+                        }
+                    }
+                }
+                Err(e) => {
+                    if *verbosity > 0 {
+                        eprintln!("CrisisWatcher: Watch error: {}", e);
+                    }
+                }
+            }
+        }
+
+        if *verbosity > 0 && !changed.is_empty() {
+            eprintln!("CrisisWatcher: Changed crises: {:?}", changed);
+        }
+
+        changed
+    }
+
+    fn template_name_for(&self, path: &Path) -> Option<String> {
+        let relative_path = path.strip_prefix(&self.folder).ok()?;
+        let first_component = relative_path.components().next()?;
+        Some(first_component.as_os_str().to_string_lossy().to_string())
+    }
+}
@@ -0,0 +1,56 @@
+use rust_embed::RustEmbed;
+use std::borrow::Cow;
+use std::sync::Mutex;
+
+// `WEB_LAZY_FONTS: &[(file, languages)]`, the fonts build.rs left out of a wasm build
+// because of their size; always empty for native builds
+include!(concat!(env!("OUT_DIR"), "/web_lazy_fonts.rs"));
+
+/// Lazy fonts already requested, each is downloaded once per session
+static REQUESTED_WEB_FONTS: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+/// Fonts collected and sha256-checked by build.rs from ../fonts/fonts.toml
+#[derive(RustEmbed)]
+#[folder = "$FONT_BUILD_DIR"]
+pub struct FontAssets;
+
+/// Every bundled font, to be loaded into iced before the first frame. They are fallbacks
+/// for the scripts the monospace UI font lacks (Arabic, Hebrew, CJK).
+pub fn bundled_fonts() -> Vec<Cow<'static, [u8]>> {
+    let mut names: Vec<String> = FontAssets::iter().map(|name| name.to_string()).collect();
+    names.sort();
+    names.into_iter()
+        .filter(|name| name.ends_with(".ttf") || name.ends_with(".otf"))
+        .filter_map(|name| FontAssets::get(&name).map(|file| file.data))
+        .collect()
+}
+
+/// Starts downloading the fonts `language` needs that the web build did not embed. Returns
+/// true when a download was started; its bytes then arrive through `take_fetched_font`.
+pub fn request_fonts_for(language: &str) -> bool {
+    let Ok(mut requested) = REQUESTED_WEB_FONTS.lock() else {
+        return false;
+    };
+    let mut started = false;
+    for (file, languages) in WEB_LAZY_FONTS {
+        if languages.contains(&language) && !requested.contains(file) {
+            requested.push(file);
+            #[cfg(target_arch = "wasm32")]
+            crate::wasm32_fonts::request_font(&format!("fonts/{}", file));
+            started = true;
+        }
+    }
+    started
+}
+
+/// The next lazily downloaded font, ready for `iced::font::load`
+#[cfg(target_arch = "wasm32")]
+pub fn take_fetched_font() -> Option<Vec<u8>> {
+    crate::wasm32_fonts::take_fetched_font()
+}
+
+/// True while a lazily requested font is still downloading
+#[cfg(target_arch = "wasm32")]
+pub fn font_fetches_running() -> bool {
+    crate::wasm32_fonts::font_fetches_running()
+}
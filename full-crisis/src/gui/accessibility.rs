@@ -0,0 +1,195 @@
+use super::types::*;
+use crate::accessibility::{AccessibleControl, ControlKind};
+use crate::translations::{t, TranslationKey};
+
+impl GameWindow {
+    /// Sends the scene text and the focused control to the screen reader bridge
+    pub fn publish_accessibility(&self) {
+        crate::accessibility::set_scene_text(&self.accessible_scene_text());
+        let control = self.focus_state.current_focus
+            .and_then(|focus_id| self.describe_control(focus_id));
+        crate::accessibility::set_focused_control(control);
+    }
+
+    /// All of the scene text, even while the typewriter is still revealing it
+    fn accessible_scene_text(&self) -> String {
+        let (Some(crisis), Some(story_state)) = (&self.current_crisis, &self.story_state) else {
+            return String::new();
+        };
+        crisis.scenes.get(&story_state.current_scene)
+            .map(|scene| crate::crisis::get_scene_text_with_substitutions(scene, &story_state.language, crisis, story_state))
+            .unwrap_or_default()
+    }
+
+    /// What a screen reader should say for a focusable element, in the language it is shown in
+    fn describe_control(&self, focus_id: FocusId) -> Option<AccessibleControl> {
+        let story_language = self.layout_language();
+        let language = &self.settings_language;
+        let button = |label: String| AccessibleControl { kind: ControlKind::Button, label, value: None };
+        let labelled = |kind: ControlKind, key: TranslationKey, value: Option<String>| AccessibleControl {
+            kind,
+            label: control_label(key, language),
+            value,
+        };
+        let toggle = |key: TranslationKey, on: bool| labelled(
+            ControlKind::Toggle(on),
+            key,
+            Some(t(if on { TranslationKey::On } else { TranslationKey::Off }, language)),
+        );
+
+        let control = match (focus_id.0, focus_id.1) {
+            ("menu", 6) => labelled(ControlKind::PickList, TranslationKey::Profile,
+                self.shown_profile_choice().map(|choice| choice.label)),
+            ("menu", 7) => button(t(TranslationKey::SwitchProfile, language)),
+            ("menu", 8) => button(t(TranslationKey::Cancel, language)),
+            ("menu", index) => button(t(match index {
+                0 => TranslationKey::ContinueGame,
+                1 => TranslationKey::NewGame,
+                2 => TranslationKey::Settings,
+                3 => TranslationKey::Licenses,
+                5 => TranslationKey::Statistics,
+                _ => TranslationKey::QuitGame,
+            }, language)),
+
+            ("newgame_input", 0) => labelled(ControlKind::TextInput, TranslationKey::PlayerName, Some(self.new_game_player_name.clone())),
+            ("newgame_input", 1) => labelled(ControlKind::TextInput, TranslationKey::SearchCrises, Some(self.crisis_query.search.clone())),
+            ("newgame_input", 2) => labelled(ControlKind::PickList, TranslationKey::Pronouns,
+                Some(self.new_game_player_gender.to_translated_string(language))),
+            ("newgame_input", 3) => labelled(ControlKind::TextInput, TranslationKey::CustomPronouns, Some(self.new_game_custom_pronouns.clone())),
+            ("newgame_input", 4) => labelled(ControlKind::PickList, TranslationKey::Language, Some(match self.crisis_query.language {
+                Some(ref code) => crate::language::get_language_display_name(code),
+                None => t(TranslationKey::AllLanguages, language),
+            })),
+            ("newgame_input", 5) => labelled(ControlKind::PickList, TranslationKey::CrisisTags,
+                Some(self.crisis_query.tag.clone().unwrap_or_else(|| t(TranslationKey::AllTags, language)))),
+            ("newgame_input", 6) => labelled(ControlKind::PickList, TranslationKey::SortOrder, Some(self.crisis_query.sort.label(language))),
+            ("crisis_card", index) => {
+                let entry = self.browse_results().get(index)?;
+                let mut parts = vec![entry.localized_name(language)];
+                parts.extend(self.crisis_card_lines(entry, language));
+                button(parts.join(", "))
+            }
+            ("stats_row", 0) => button([t(TranslationKey::AllCrisesTotals, language)].into_iter()
+                .chain(self.stats_total_lines(language))
+                .collect::<Vec<_>>()
+                .join(", ")),
+            ("stats_row", index) => {
+                let catalog = crate::crisis::CrisisCatalog::get();
+                let entry = *self.stats_entries(&catalog).get(index - 1)?;
+                let mut parts = vec![entry.localized_name(language)];
+                parts.extend(self.crisis_stats_lines(&entry.template_name, language));
+                button(parts.join(", "))
+            }
+            ("newgame_button", 0) => button(t(TranslationKey::Go, language)),
+            ("newgame_button", 1) => button(t(TranslationKey::ForkCrisis, language)),
+
+            ("continue_input", _) => labelled(ControlKind::PickList, TranslationKey::SavedGame, self.continue_game_game_choice.clone()),
+            ("continue_button", 0) => button(t(TranslationKey::Play, language)),
+            ("continue_button", 1) => button(t(TranslationKey::Delete, language)),
+            ("continue_confirm", 0) => button(t(TranslationKey::ConfirmDelete, language)),
+            ("continue_confirm", 1) => button(t(TranslationKey::Cancel, language)),
+
+            ("settings_input", 0) => labelled(ControlKind::TextInput, TranslationKey::GameCrisesFolder, Some(self.settings_game_crises_folder_input.clone())),
+            ("settings_input", 2) => labelled(ControlKind::TextInput, TranslationKey::ProfileName, Some(self.settings_profile_name.clone())),
+            ("settings_input", 1) => labelled(ControlKind::TextInput, TranslationKey::TrustedKeys, Some(self.settings_trusted_keys.clone())),
+            ("settings_button", 0) => button(t(TranslationKey::OpenFolder, language)),
+            ("settings_button", 1) => button(t(TranslationKey::LoadCrisisPack, language)),
+            ("settings_picker", 0) => labelled(ControlKind::PickList, TranslationKey::DifficultyLevel,
+                Some(self.settings_difficulty_level.to_translated_string(language))),
+            ("settings_picker", 1) => labelled(ControlKind::PickList, TranslationKey::Language,
+                Some(crate::language::get_language_display_name(language))),
+            ("settings_picker", 2) => labelled(ControlKind::PickList, TranslationKey::Theme,
+                Some(self.settings_theme.to_translated_string(language))),
+            ("settings_toggle", 0) => toggle(TranslationKey::Autosave, self.settings_autosave),
+            ("settings_toggle", 1) => toggle(TranslationKey::RequireSignedCrises, self.settings_require_signed_crises),
+            ("settings_toggle", 2) => toggle(TranslationKey::ReduceMotion, self.settings_reduce_motion),
+            ("settings_slider", 0) => labelled(ControlKind::Slider, TranslationKey::FontScale, Some(format!("{:.1}x", self.settings_font_scale))),
+            ("settings_slider", 1) => labelled(ControlKind::Slider, TranslationKey::Volume, Some(format!("{:.0}%", self.settings_volume * 100.0))),
+
+            ("choice", index) => return self.describe_choice(index),
+            ("control", index) => button(t(match index {
+                0 => TranslationKey::SaveAndQuit,
+                1 => TranslationKey::Quit,
+                2 => TranslationKey::Rewind,
+                3 => TranslationKey::RestartCrisis,
+                _ => TranslationKey::PauseMenu,
+            }, story_language)),
+            ("timeline", index) => {
+                let label = self.timeline_labels().get(index)?;
+                button(format!("{}. {}", index + 1, label))
+            }
+            ("transcript", 0) => button(t(if self.transcript_expanded {
+                TranslationKey::HideDecisionLog
+            } else {
+                TranslationKey::ShowDecisionLog
+            }, story_language)),
+            ("transcript", position) => return self.describe_transcript_entry(position - 1),
+            ("game_language", 0) => AccessibleControl {
+                kind: ControlKind::PickList,
+                label: control_label(TranslationKey::Language, story_language),
+                value: Some(crate::language::get_language_display_name(story_language)),
+            },
+            ("game_language", _) => {
+                let secondary_language = self.story_state.as_ref()?.secondary_language.as_ref()
+                    .map(|code| crate::language::get_language_display_name(code))
+                    .unwrap_or_else(|| t(TranslationKey::NoSecondLanguage, story_language));
+                AccessibleControl {
+                    kind: ControlKind::PickList,
+                    label: control_label(TranslationKey::SecondLanguage, story_language),
+                    value: Some(secondary_language),
+                }
+            }
+
+            ("pause", index) => button(t(match index {
+                0 => TranslationKey::Resume,
+                1 => TranslationKey::SaveGame,
+                2 => TranslationKey::LoadGame,
+                3 => TranslationKey::Settings,
+                4 => TranslationKey::RestartCrisis,
+                5 => TranslationKey::Quit,
+                6 => TranslationKey::Back,
+                7 => TranslationKey::SaveAndQuit,
+                9 => TranslationKey::LoadWithoutSaving,
+                _ => TranslationKey::QuitWithoutSaving,
+            }, language)),
+            ("pause_load", index) => button(crate::crisis::get_saved_games().get_save_names().into_iter().nth(index)?),
+
+            _ => return None,
+        };
+        Some(control)
+    }
+
+    fn describe_choice(&self, index: usize) -> Option<AccessibleControl> {
+        let (crisis, story_state) = (self.current_crisis.as_ref()?, self.story_state.as_ref()?);
+        let choice = crisis.scenes.get(&story_state.current_scene)?.choices.get(index)?;
+        let label = crisis.localized_text(&choice.text, &story_state.language);
+        Some(match choice.text_input {
+            Some(_) => AccessibleControl {
+                kind: ControlKind::TextInput,
+                label,
+                value: self.choice_text_inputs.get(&index).cloned(),
+            },
+            None => AccessibleControl { kind: ControlKind::Button, label, value: None },
+        })
+    }
+
+    /// Decision log entries are focused newest first, as they are listed
+    fn describe_transcript_entry(&self, position: usize) -> Option<AccessibleControl> {
+        let (crisis, story_state) = (self.current_crisis.as_ref()?, self.story_state.as_ref()?);
+        let decision = story_state.decisions.iter().rev().nth(position)?;
+        let choice_text = crisis.scenes.get(&decision.scene)
+            .and_then(|scene| scene.choices.get(decision.choice_index))
+            .map(|choice| crisis.localized_text(&choice.text, &story_state.language))
+            .unwrap_or_else(|| decision.scene.replace('_', " "));
+        Some(AccessibleControl {
+            kind: ControlKind::Button,
+            label: format!("{}. {}", story_state.decisions.len() - position, choice_text),
+            value: decision.text_input.clone(),
+        })
+    }
+}
+
+/// A settings label without the colon it is written with, e.g. "Volume:" becomes "Volume"
+fn control_label(key: TranslationKey, language: &str) -> String {
+    t(key, language).trim_end_matches([':', '：', ' ']).to_string()
+}
@@ -10,6 +10,9 @@ static PLAY_BACKGROUND_AUDIO: OnceCell<Box<dyn Fn(&[u8]) + Send + Sync>> = OnceC
 #[cfg(target_arch = "wasm32")]
 static STOP_BACKGROUND_AUDIO: OnceCell<Box<dyn Fn() + Send + Sync>> = OnceCell::new();
 
+#[cfg(target_arch = "wasm32")]
+static SET_AUDIO_VOLUME: OnceCell<Box<dyn Fn(f32) + Send + Sync>> = OnceCell::new();
+
 #[cfg(target_arch = "wasm32")]
 pub fn set_audio_callbacks(
     play_fn: Box<dyn Fn(&[u8]) + Send + Sync>,
@@ -33,14 +36,52 @@ pub fn web_stop_background_audio() {
     }
 }
 
+#[cfg(target_arch = "wasm32")]
+pub fn set_volume_callback(volume_fn: Box<dyn Fn(f32) + Send + Sync>) {
+    let _ = SET_AUDIO_VOLUME.set(volume_fn);
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn web_set_audio_volume(volume: f32) {
+    if let Some(volume_fn) = SET_AUDIO_VOLUME.get() {
+        volume_fn(volume);
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 use rodio::{Decoder, OutputStream, OutputStreamBuilder, Sink, Source};
 
 #[cfg(not(target_arch = "wasm32"))]
-#[derive(Default)]
 struct DesktopAudioState {
     _stream: Option<OutputStream>,
     sink: Option<Sink>,
+    volume: f32,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+static DESKTOP_AUDIO_STATE: once_cell::sync::Lazy<std::sync::Arc<std::sync::Mutex<DesktopAudioState>>> = 
+    once_cell::sync::Lazy::new(|| std::sync::Arc::new(std::sync::Mutex::new(DesktopAudioState::default())));
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for DesktopAudioState {
+    fn default() -> Self {
+        Self {
+            _stream: None,
+            sink: None,
+            volume: 1.0,
+        }
+    }
+}
+
+/// Sets the scene audio volume from 0.0 (muted) to 1.0, including for audio already playing
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_scene_audio_volume(volume: f32) {
+    if let Ok(mut state) = DESKTOP_AUDIO_STATE.lock() {
+        state.volume = volume.clamp(0.0, 1.0);
+        if let Some(ref sink) = state.sink {
+            sink.set_volume(state.volume);
+        }
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -83,6 +124,7 @@ impl DesktopAudioState {
                     
                     // Add the source to the sink with infinite looping
                     sink.append(source.repeat_infinite());
+                    sink.set_volume(self.volume);
                     sink.play();
                     self.sink = Some(sink);
                 }
@@ -99,11 +141,16 @@ impl GameWindow {
         #[cfg(not(target_arch = "wasm32"))]
         let start_time = std::time::Instant::now();
         
+        // Timer ticks change nothing a screen reader reads, and arrive many times a second
+        let is_timer_tick = matches!(message, GameMessage::FrameRate_Tick | GameMessage::Game_AnimationTick | GameMessage::Game_CrisisFilesPoll);
+        let was_on_settings_screen = self.is_on_settings_screen();
+        
         let result = match message {
             GameMessage::Menu_NewGameRequested => {
                 if let Ok(mut evt_loop_wguard) = self.game_state.active_event_loop.write() {
                     *evt_loop_wguard = crate::game::ActiveEventLoop::WelcomeScreen(crate::game::WelcomeScreenView::NewGame);
                 }
+                self.load_crisis_covers();
                 self.update_focus_for_new_game_screen();
                 self.view_needs_redraw.mark_menu_dirty();
                 Task::none()
@@ -114,18 +161,72 @@ impl GameWindow {
                 self.view_needs_redraw.mark_menu_dirty();
                 Task::none()
             }
-            GameMessage::Menu_NewGameTemplateChoiceAltered(game_template) => {
-                let template_name = crate::crisis::get_template_name_from_display_name(&game_template);
+            GameMessage::Menu_NewGamePlayerGenderChanged(gender) => {
+                self.new_game_player_gender = gender;
+                self.save_settings();
+                self.view_needs_redraw.mark_menu_dirty();
+                Task::none()
+            }
+            GameMessage::Menu_NewGameCustomPronounsAltered(pronouns) => {
+                self.new_game_custom_pronouns = pronouns;
+                self.save_settings();
+                self.view_needs_redraw.mark_menu_dirty();
+                Task::none()
+            }
+            GameMessage::Menu_NewGameTemplateChoiceAltered(template_name) => {
+                self.new_game_selected_description = crate::crisis::CrisisCatalog::get().get_entry(&template_name)
+                    .map(|entry| entry.localized_description(&self.settings_language));
                 self.new_game_game_template = Some(template_name);
+                self.new_game_fork_status = None;
                 
-                if let Some((_, description)) = crate::crisis::get_crisis_info_by_display_name(&game_template, &self.settings_language) {
-                    self.new_game_selected_description = Some(description);
-                } else {
-                    self.new_game_selected_description = None;
-                }
+                // The fork button comes and goes with the selected crisis
+                let elements = self.new_game_focus_elements();
+                self.focus_state.set_focusable_elements(elements);
+                self.view_needs_redraw.mark_menu_dirty();
                 
                 Task::none()
             }
+            GameMessage::Menu_NewGameSearchChanged(search) => {
+                self.crisis_query.search = search;
+                self.refresh_crisis_browser();
+                Task::none()
+            }
+            GameMessage::Menu_NewGameLanguageFilterChanged(language) => {
+                self.crisis_query.language = language;
+                self.refresh_crisis_browser();
+                Task::none()
+            }
+            GameMessage::Menu_NewGameTagFilterChanged(tag) => {
+                self.crisis_query.tag = tag;
+                self.refresh_crisis_browser();
+                Task::none()
+            }
+            GameMessage::Menu_NewGameSortChanged(sort) => {
+                self.crisis_query.sort = sort;
+                self.refresh_crisis_browser();
+                Task::none()
+            }
+            GameMessage::Menu_NewGameForkRequested => {
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(template_name) = self.new_game_game_template.clone() {
+                    self.new_game_fork_status = Some(match crate::crisis::PlayableCrises::fork_to_crises_folder(&template_name) {
+                        Ok(crisis_folder) => format!(
+                            "{}: {}",
+                            crate::translations::t(crate::translations::TranslationKey::CrisisForked, &self.settings_language),
+                            crisis_folder.display()
+                        ),
+                        Err(e) => format!(
+                            "{}: {}",
+                            crate::translations::t(crate::translations::TranslationKey::CrisisForkFailed, &self.settings_language),
+                            e
+                        ),
+                    });
+                    let elements = self.new_game_focus_elements();
+                    self.focus_state.set_focusable_elements(elements);
+                    self.view_needs_redraw.mark_menu_dirty();
+                }
+                Task::none()
+            }
             GameMessage::Menu_NewGameStartClicked => {
                 // Stop menu music when starting game
                 self.stop_menu_audio();
@@ -160,6 +261,47 @@ impl GameWindow {
                 self.update_focus_for_settings_screen();
                 Task::none()
             }
+            GameMessage::Menu_StatsRequested => {
+                if let Ok(mut evt_loop_wguard) = self.game_state.active_event_loop.write() {
+                    *evt_loop_wguard = crate::game::ActiveEventLoop::WelcomeScreen(crate::game::WelcomeScreenView::Stats);
+                }
+                self.update_focus_for_stats_screen();
+                Task::none()
+            }
+            GameMessage::Menu_ProfileSelected(choice) => {
+                // Switching drops the choices made on the current screens, so it waits for Switch
+                if choice.profile_id.as_deref() == Some(self.profiles.active.as_str()) {
+                    self.pending_profile_switch = None;
+                } else {
+                    self.pending_profile_switch = Some(choice);
+                }
+                self.refresh_menu_panel_focus();
+                self.view_needs_redraw.mark_menu_dirty();
+                Task::none()
+            }
+            GameMessage::Menu_ProfileSwitchConfirmed => {
+                let Some(choice) = self.pending_profile_switch.take() else {
+                    return Task::none();
+                };
+                let profile_id = match choice.profile_id {
+                    Some(profile_id) => profile_id,
+                    None => {
+                        let name = crate::profile::numbered_profile_name(self.profiles.profiles.len() + 1, &self.settings_language);
+                        crate::profile::create_profile(&name).id
+                    }
+                };
+                self.switch_profile(&profile_id);
+                self.refresh_menu_panel_focus();
+                self.view_needs_redraw.mark_menu_dirty();
+                Task::none()
+            }
+            GameMessage::Menu_ProfileSwitchCancelled => {
+                self.pending_profile_switch = None;
+                self.refresh_menu_panel_focus();
+                self.focus_state.current_focus = Some(FocusId::menu_button(6));
+                self.view_needs_redraw.mark_menu_dirty();
+                Task::none()
+            }
             GameMessage::Menu_LicensesRequested => {
                 if let Ok(mut evt_loop_wguard) = self.game_state.active_event_loop.write() {
                     *evt_loop_wguard = crate::game::ActiveEventLoop::WelcomeScreen(crate::game::WelcomeScreenView::Licenses);
@@ -167,9 +309,39 @@ impl GameWindow {
                 Task::none()
             }
             GameMessage::Menu_SettingsGameCrisesFolderChanged(folder_path) => {
-                eprintln!("Settings: Game Crises Folder changed to: {}", folder_path);
-                self.settings_game_crises_folder = folder_path;
-                self.save_settings();
+                // Only kept while typing; half-typed paths are not loaded, watched or saved
+                self.settings_game_crises_folder_input = folder_path;
+                Task::none()
+            }
+            GameMessage::Menu_SettingsGameCrisesFolderSubmitted => {
+                self.apply_crises_folder_input();
+                Task::none()
+            }
+            GameMessage::Menu_SettingsUploadPackRequested => {
+                #[cfg(target_arch = "wasm32")]
+                {
+                    crate::wasm32_upload::request_pack_upload();
+                    self.pack_upload_pending = true;
+                }
+                Task::none()
+            }
+            GameMessage::Menu_PackUploadPoll => {
+                #[cfg(target_arch = "wasm32")]
+                {
+                    self.handle_pack_upload_poll();
+                }
+                Task::none()
+            }
+            #[cfg(target_arch = "wasm32")]
+            GameMessage::Menu_FontFetchPoll => {
+                self.handle_font_fetch_poll()
+            }
+            GameMessage::Menu_FontLoaded(result) => {
+                if let Err(e) = result {
+                    eprintln!("Could not load a downloaded font: {:?}", e);
+                }
+                // Text laid out before the font arrived has to be shaped again
+                self.view_needs_redraw.force_redraw();
                 Task::none()
             }
             GameMessage::Menu_SettingsOpenCrisesFolder => {
@@ -180,6 +352,17 @@ impl GameWindow {
                 }
                 Task::none()
             }
+            GameMessage::Menu_SettingsProfileNameChanged(name) => {
+                // Only kept while typing; the name is saved once it is committed
+                self.settings_profile_name = name;
+                Task::none()
+            }
+            GameMessage::Menu_SettingsProfileNameSubmitted => {
+                crate::profile::rename_active_profile(&self.settings_profile_name);
+                self.profiles = crate::profile::get_profiles();
+                self.view_needs_redraw.mark_menu_dirty();
+                Task::none()
+            }
             GameMessage::Menu_SettingsDifficultyLevelChanged(difficulty) => {
                 eprintln!("Settings: Difficulty Level changed to: {:?}", difficulty);
                 self.settings_difficulty_level = difficulty;
@@ -204,8 +387,70 @@ impl GameWindow {
                 self.save_settings();
                 Task::none()
             }
+            GameMessage::Menu_SettingsVolumeChanged(volume) => {
+                eprintln!("Settings: Volume changed to: {}", volume);
+                self.settings_volume = volume.clamp(0.0, 1.0);
+                self.save_settings();
+                self.apply_volume();
+                Task::none()
+            }
+            GameMessage::Menu_SettingsReduceMotionToggled(reduce_motion) => {
+                eprintln!("Settings: Reduce motion toggled to: {}", reduce_motion);
+                self.settings_reduce_motion = reduce_motion;
+                if reduce_motion {
+                    self.scene_transition = None;
+                }
+                self.save_settings();
+                self.view_needs_redraw.mark_game_dirty();
+                Task::none()
+            }
+            GameMessage::Menu_SettingsThemeChanged(theme) => {
+                eprintln!("Settings: Theme changed to: {:?}", theme);
+                self.settings_theme = theme;
+                self.save_settings();
+                self.view_needs_redraw.force_redraw();
+                Task::none()
+            }
+            GameMessage::Menu_SettingsTrustedKeysChanged(_) | GameMessage::Menu_SettingsTrustedKeysSubmitted
+                if crate::policy::AdminPolicy::get().trusted_keys.is_some() => {
+                Task::none()
+            }
+            GameMessage::Menu_SettingsTrustedKeysChanged(trusted_keys) => {
+                // Half-typed keys are not saved; the catalog re-verifies every crisis once they are committed
+                self.settings_trusted_keys = trusted_keys;
+                Task::none()
+            }
+            GameMessage::Menu_SettingsTrustedKeysSubmitted => {
+                eprintln!("Settings: Trusted keys changed to: {}", self.settings_trusted_keys);
+                self.save_settings();
+                crate::crisis::CrisisCatalog::invalidate();
+                self.view_needs_redraw.mark_menu_dirty();
+                Task::none()
+            }
+            GameMessage::Menu_SettingsRequireSignedToggled(_) if crate::policy::AdminPolicy::get().require_signed_crises.is_some() => {
+                Task::none()
+            }
+            GameMessage::Menu_SettingsRequireSignedToggled(require_signed) => {
+                eprintln!("Settings: Require signed crises toggled to: {}", require_signed);
+                self.settings_require_signed_crises = require_signed;
+                self.save_settings();
+                crate::crisis::CrisisCatalog::invalidate();
+                Task::none()
+            }
             GameMessage::QuitGameRequested => {
-                crate::quit_game_gui()
+                // The controller's back button leaves the pause menu rather than the application
+                if self.pause_menu.is_some() {
+                    self.handle_pause_menu_toggle()
+                } else {
+                    crate::quit_game_gui()
+                }
+            }
+            GameMessage::StartButtonPressed => {
+                if self.story_state.is_some() {
+                    self.handle_pause_menu_toggle()
+                } else {
+                    Task::done(GameMessage::Menu_SettingsRequested)
+                }
             }
             GameMessage::Game_ChoiceSelected(choice_index) => {
                 self.handle_choice_selection(choice_index)
@@ -220,6 +465,15 @@ impl GameWindow {
             GameMessage::Game_RestartRequested => {
                 self.handle_game_restart()
             }
+            GameMessage::Game_RestartCrisisRequested => {
+                self.handle_crisis_restart()
+            }
+            GameMessage::Game_RewindRequested => {
+                self.handle_rewind()
+            }
+            GameMessage::Game_TimelineJumpRequested(index) => {
+                self.handle_timeline_jump(index)
+            }
             GameMessage::Game_SaveAndQuitRequested => {
                 self.handle_save_and_quit()
             }
@@ -234,22 +488,31 @@ impl GameWindow {
             GameMessage::Focus_NavigateUp => {
                 self.focus_state.navigate_up();
                 self.view_needs_redraw.mark_focus_dirty();
-                Task::none()
+                self.scroll_focus_into_view()
             }
             GameMessage::Focus_NavigateDown => {
                 self.focus_state.navigate_down();
                 self.view_needs_redraw.mark_focus_dirty();
-                Task::none()
+                self.scroll_focus_into_view()
             }
+            // Rows are mirrored for right-to-left languages, so left and right swap with them
             GameMessage::Focus_NavigateLeft => {
-                self.focus_state.navigate_left();
+                if self.is_rtl(self.layout_language()) {
+                    self.focus_state.navigate_right();
+                } else {
+                    self.focus_state.navigate_left();
+                }
                 self.view_needs_redraw.mark_focus_dirty();
-                Task::none()
+                self.scroll_focus_into_view()
             }
             GameMessage::Focus_NavigateRight => {
-                self.focus_state.navigate_right();
+                if self.is_rtl(self.layout_language()) {
+                    self.focus_state.navigate_left();
+                } else {
+                    self.focus_state.navigate_right();
+                }
                 self.view_needs_redraw.mark_focus_dirty();
-                Task::none()
+                self.scroll_focus_into_view()
             }
             GameMessage::Focus_Activate => {
                 self.handle_focus_activation()
@@ -266,6 +529,75 @@ impl GameWindow {
                 eprintln!("Recieved a GameMessage::Nop");
                 Task::none()
             }
+            GameMessage::Game_CrisisFilesPoll => {
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    self.handle_crisis_files_poll();
+                }
+                Task::none()
+            }
+            GameMessage::Game_ReloadErrorDismissed => {
+                self.crisis_reload_error = None;
+                self.view_needs_redraw.mark_game_dirty();
+                Task::none()
+            }
+            GameMessage::Game_TranscriptToggled => {
+                self.transcript_expanded = !self.transcript_expanded;
+                if let Some(num_choices) = self.current_scene_choice_count() {
+                    self.update_focus_for_game_screen(num_choices);
+                }
+                self.focus_state.current_focus = Some(FocusId::game_transcript(0));
+                self.view_needs_redraw.mark_game_dirty();
+                Task::none()
+            }
+            GameMessage::Game_PauseMenuToggled => {
+                self.handle_pause_menu_toggle()
+            }
+            GameMessage::Game_PauseMenuViewChanged(view) => {
+                self.show_pause_menu(Some(view));
+                Task::none()
+            }
+            GameMessage::Game_PauseSaveRequested => {
+                self.handle_pause_save()
+            }
+            GameMessage::Game_PauseLoadRequested(saved_game_name) => {
+                // Like quitting, loading over unsaved progress asks first
+                if self.unsaved_changes {
+                    self.pause_pending_load = Some(saved_game_name);
+                    self.show_pause_menu(Some(PauseMenuView::ConfirmLoad));
+                } else {
+                    self.handle_pause_load(&saved_game_name);
+                }
+                Task::none()
+            }
+            GameMessage::Game_PauseLoadConfirmed => {
+                if let Some(saved_game_name) = self.pause_pending_load.take() {
+                    self.handle_pause_load(&saved_game_name);
+                }
+                Task::none()
+            }
+            GameMessage::Game_PauseQuitRequested => {
+                if self.unsaved_changes {
+                    self.show_pause_menu(Some(PauseMenuView::ConfirmQuit));
+                    Task::none()
+                } else {
+                    self.handle_quit_without_save()
+                }
+            }
+            GameMessage::Game_LanguageChanged(language) => {
+                self.handle_game_language_change(language)
+            }
+            GameMessage::Game_SecondaryLanguageChanged(secondary_language) => {
+                if let Some(ref mut story_state) = self.story_state {
+                    story_state.secondary_language = secondary_language.filter(|secondary| *secondary != story_state.language);
+                }
+                self.view_needs_redraw.mark_game_dirty();
+                Task::none()
+            }
+            GameMessage::Game_TextRevealSkipped => {
+                self.skip_text_reveal();
+                Task::none()
+            }
             GameMessage::Controller_PollInput => {
                 self.handle_controller_input()
             }
@@ -275,6 +607,35 @@ impl GameWindow {
             _ => Task::none(),
         };
         
+        // However settings was left, a typed crises folder takes effect with it
+        if was_on_settings_screen && !self.is_on_settings_screen() {
+            self.apply_crises_folder_input();
+        }
+        
+        // The catalog can be rebuilt under the open browser, by a file poll or a pack install
+        let on_new_game_screen = matches!(
+            self.game_state.active_event_loop.read().map(|event_loop| event_loop.clone()),
+            Ok(crate::game::ActiveEventLoop::WelcomeScreen(crate::game::WelcomeScreenView::NewGame))
+        );
+        if on_new_game_screen && self.refresh_browse_results() {
+            self.refresh_crisis_browser();
+        }
+        
+        // Timers leave the history alone, but a hot reload (a file poll) drops the labels
+        if !is_timer_tick || self.cached_views.timeline_labels.is_none() {
+            self.refresh_timeline_labels();
+        }
+        
+        if !is_timer_tick {
+            self.publish_accessibility();
+        }
+        
+        // The web build downloads its CJK font only once a language needing it is on screen
+        #[cfg(target_arch = "wasm32")]
+        if !is_timer_tick {
+            self.request_shown_language_fonts();
+        }
+        
         #[cfg(not(target_arch = "wasm32"))]
         {
             let elapsed = start_time.elapsed();
@@ -303,7 +664,7 @@ impl GameWindow {
                     }
                     
                     let character_name = if self.new_game_player_name.is_empty() {
-                        crate::crisis::get_random_character_name(&crisis, None, &self.settings_language)
+                        crate::crisis::get_random_character_name(&crisis, None, self.new_game_player_gender, &self.settings_language)
                     } else {
                         self.new_game_player_name.clone()
                     };
@@ -316,6 +677,10 @@ impl GameWindow {
                     );
                     story_state.current_scene = crisis.story.starting_scene.clone();
                     story_state.character_name = character_name;
+                    story_state.variables = crisis.conditions.initial_variables();
+                    story_state.player_gender = self.new_game_player_gender;
+                    story_state.pronouns = crate::crisis::PronounSet::parse(&self.new_game_custom_pronouns);
+                    story_state.pronouns_language = Some(user_language.clone());
                     
                     // Load background audio for the starting scene
                     self.load_scene_background_audio(&crisis, &crisis.story.starting_scene);
@@ -327,6 +692,12 @@ impl GameWindow {
                     
                     self.current_crisis = Some(crisis);
                     self.story_state = Some(story_state);
+                    self.load_crisis_images();
+                    self.game_history.clear();
+                    self.crisis_reload_error = None;
+                    self.unsaved_changes = false;
+                    self.play_clock = crate::time::now();
+                    self.record_run_started();
                     
                     if let Ok(mut evt_loop_wguard) = self.game_state.active_event_loop.write() {
                         *evt_loop_wguard = crate::game::ActiveEventLoop::ActiveGame(crate::game::GameView::StoryScene);
@@ -334,6 +705,7 @@ impl GameWindow {
                     
                     // Set up focus for game screen
                     self.update_focus_for_game_screen(num_choices);
+                    self.begin_scene_transition(None);
                 }
                 Err(e) => {
                     if *verbosity > 0 {
@@ -345,49 +717,54 @@ impl GameWindow {
         Task::none()
     }
 
-    // WARNING: Long Function
     fn handle_continue_game_start(&mut self) -> Task<GameMessage> {
-        let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
-        
-        if let Some(ref saved_game_name) = self.continue_game_game_choice {
-            match crate::crisis::load_saved_game(saved_game_name) {
-                Ok(loaded_story_state) => {
-                    match crate::crisis::load_crisis(&loaded_story_state.template_name) {
-                        Ok(crisis) => {
-                            // Load background audio for the current scene
-                            self.load_scene_background_audio(&crisis, &loaded_story_state.current_scene);
-                            
-                            // Set up focus for game screen before moving values
-                            let num_choices = crisis.scenes.get(&loaded_story_state.current_scene)
-                                .map(|scene| scene.choices.len())
-                                .unwrap_or(0);
-                            
-                            self.current_crisis = Some(crisis);
-                            self.story_state = Some(loaded_story_state);
-                            
-                            if let Ok(mut evt_loop_wguard) = self.game_state.active_event_loop.write() {
-                                *evt_loop_wguard = crate::game::ActiveEventLoop::ActiveGame(crate::game::GameView::StoryScene);
-                            }
-                            
-                            self.update_focus_for_game_screen(num_choices);
-                        }
-                        Err(e) => {
-                            if *verbosity > 0 {
-                                eprintln!("Failed to load crisis: {}", e);
-                            }
-                        }
-                    }
-                }
-                Err(e) => {
-                    if *verbosity > 0 {
-                        eprintln!("Failed to load saved game: {}", e);
-                    }
+        if let Some(saved_game_name) = self.continue_game_game_choice.clone() {
+            if let Err(e) = self.start_saved_game(&saved_game_name) {
+                let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
+                if *verbosity > 0 {
+                    eprintln!("{}", e);
                 }
             }
         }
         Task::none()
     }
 
+    /// Starts a saved game, replacing the running one if there is one
+    fn start_saved_game(&mut self, saved_game_name: &str) -> Result<(), String> {
+        let (loaded_story_state, loaded_history) = crate::crisis::load_saved_game(saved_game_name)
+            .map_err(|e| format!("Failed to load saved game: {}", e))?;
+        let crisis = crate::crisis::load_crisis(&loaded_story_state.template_name)
+            .map_err(|e| format!("Failed to load crisis: {}", e))?;
+        
+        // Load background audio for the current scene
+        self.load_scene_background_audio(&crisis, &loaded_story_state.current_scene);
+        
+        // Set up focus for game screen before moving values
+        let num_choices = crisis.scenes.get(&loaded_story_state.current_scene)
+            .map(|scene| scene.choices.len())
+            .unwrap_or(0);
+        
+        self.current_crisis = Some(crisis);
+        self.story_state = Some(loaded_story_state);
+        self.load_crisis_images();
+        self.game_history = loaded_history;
+        self.crisis_reload_error = None;
+        self.choice_text_inputs.clear();
+        self.animation_frame_index = 0;
+        self.pause_menu = None;
+        self.pause_menu_status = None;
+        self.unsaved_changes = false;
+        self.play_clock = crate::time::now();
+        
+        if let Ok(mut evt_loop_wguard) = self.game_state.active_event_loop.write() {
+            *evt_loop_wguard = crate::game::ActiveEventLoop::ActiveGame(crate::game::GameView::StoryScene);
+        }
+        
+        self.update_focus_for_game_screen(num_choices);
+        self.view_needs_redraw.mark_game_dirty();
+        Ok(())
+    }
+
     fn handle_delete_game_request(&mut self, game_name: String) -> Task<GameMessage> {
         if game_name.is_empty() {
             self.continue_game_delete_confirmation = None;
@@ -423,11 +800,14 @@ impl GameWindow {
         
         // Update focus to remove confirmation dialog buttons
         self.update_focus_for_continue_game_screen();
+        self.begin_scene_transition(None);
         
         Task::none()
     }
 
     fn handle_choice_selection(&mut self, choice_index: usize) -> Task<GameMessage> {
+        let previous_scene = self.current_scene_snapshot();
+        
         // Clone the needed data to avoid borrowing issues
         let leads_to = if let (Some(crisis), Some(story_state)) = (&self.current_crisis, &self.story_state) {
             if let Some(current_scene) = crisis.scenes.get(&story_state.current_scene) {
@@ -447,13 +827,9 @@ impl GameWindow {
             if let (Some(crisis), Some(story_state)) = (&self.current_crisis, &mut self.story_state) {
                 if let Some(current_scene) = crisis.scenes.get(&story_state.current_scene) {
                     if let Some(choice) = current_scene.choices.get(choice_index) {
-                        if let Some(ref choice_effects) = crisis.conditions.choice_effects {
-                            if let Some(effects) = choice_effects.get(&choice.leads_to) {
-                                for (var, value) in effects {
-                                    *story_state.variables.entry(var.clone()).or_insert(0) += value;
-                                }
-                            }
-                        }
+                        self.game_history.record(story_state);
+                        crate::crisis::apply_choice_effects(crisis, story_state, choice_index);
+                        self.unsaved_changes = true;
                         
                         story_state.current_scene = choice.leads_to.clone();
                         
@@ -462,6 +838,7 @@ impl GameWindow {
                             story_state.character_name = crate::crisis::get_random_character_name(
                                 crisis, 
                                 Some(char_type), 
+                                story_state.player_gender,
                                 &story_state.language
                             );
                         }
@@ -475,6 +852,8 @@ impl GameWindow {
                 }
             }
             
+            self.record_scene_reached();
+            
             // Load background audio for the new scene after updating the state
             if let Some(crisis) = self.current_crisis.clone() {
                 self.load_scene_background_audio(&crisis, &leads_to);
@@ -484,11 +863,15 @@ impl GameWindow {
                     self.update_focus_for_game_screen(scene.choices.len());
                 }
             }
+            self.begin_scene_transition(previous_scene);
         }
         Task::none()
     }
 
     fn handle_text_input_submission(&mut self, choice_index: usize, value: String) -> Task<GameMessage> {
+        let previous_scene = self.current_scene_snapshot();
+        let mut scene_changed = false;
+        
         if let (Some(crisis), Some(story_state)) = (&self.current_crisis, &mut self.story_state) {
             if let Some(current_scene) = crisis.scenes.get(&story_state.current_scene) {
                 if let Some(choice) = current_scene.choices.get(choice_index) {
@@ -526,16 +909,14 @@ impl GameWindow {
                             }
                         };
                         
+                        self.game_history.record(story_state);
                         story_state.text_inputs.insert(text_input.variable_name.clone(), validated_value);
+                    } else {
+                        self.game_history.record(story_state);
                     }
                     
-                    if let Some(ref choice_effects) = crisis.conditions.choice_effects {
-                        if let Some(effects) = choice_effects.get(&choice.leads_to) {
-                            for (var, value) in effects {
-                                *story_state.variables.entry(var.clone()).or_insert(0) += value;
-                            }
-                        }
-                    }
+                    crate::crisis::apply_choice_effects(crisis, story_state, choice_index);
+                    self.unsaved_changes = true;
                     
                     story_state.current_scene = choice.leads_to.clone();
                     
@@ -544,6 +925,7 @@ impl GameWindow {
                         story_state.character_name = crate::crisis::get_random_character_name(
                             crisis, 
                             Some(char_type), 
+                            story_state.player_gender,
                             &story_state.language
                         );
                     }
@@ -555,6 +937,7 @@ impl GameWindow {
                     }
                     
                     self.choice_text_inputs.clear();
+                    scene_changed = true;
                     
                     // Update focus for the new scene's choices
                     if let Some(scene) = crisis.scenes.get(&choice.leads_to) {
@@ -563,61 +946,488 @@ impl GameWindow {
                 }
             }
         }
+        if scene_changed {
+            self.record_scene_reached();
+        }
+        self.begin_scene_transition(previous_scene);
         Task::none()
     }
 
+    /// Name and background image of the scene on screen, taken before a choice moves the story on
+    fn current_scene_snapshot(&self) -> Option<(String, Option<String>)> {
+        let (crisis, story_state) = (self.current_crisis.as_ref()?, self.story_state.as_ref()?);
+        let background = crisis.scenes.get(&story_state.current_scene)
+            .and_then(|scene| scene.background_image.clone());
+        Some((story_state.current_scene.clone(), background))
+    }
+
+    /// Adds the time played since the last call to the story state
+    fn bank_play_time(&mut self) {
+        if let Some(ref mut story_state) = self.story_state {
+            story_state.play_time_ms += self.play_clock.elapsed().as_millis() as u64;
+        }
+        self.play_clock = crate::time::now();
+    }
+
+    /// Counts a new playthrough of the running crisis in the player's statistics
+    fn record_run_started(&mut self) {
+        if let (Some(crisis), Some(story_state)) = (&self.current_crisis, &mut self.story_state) {
+            self.player_stats.record_run_started(crisis, story_state);
+            crate::crisis::save_player_stats(&self.player_stats);
+        }
+    }
+
+    /// Records the scene a choice just led to, and the run's result when it is an ending
+    fn record_scene_reached(&mut self) {
+        self.bank_play_time();
+        if let (Some(crisis), Some(story_state)) = (&self.current_crisis, &mut self.story_state) {
+            self.player_stats.record_scene_reached(crisis, story_state);
+            crate::crisis::save_player_stats(&self.player_stats);
+        }
+    }
+
+    /// Starts the current scene's transition, unless the story is still on the `previous` scene.
+    /// With no previous scene the background fades in from black.
+    fn begin_scene_transition(&mut self, previous: Option<(String, Option<String>)>) {
+        let (Some(crisis), Some(story_state)) = (&self.current_crisis, &self.story_state) else {
+            self.scene_transition = None;
+            return;
+        };
+        if previous.as_ref().is_some_and(|(scene_name, _)| *scene_name == story_state.current_scene) {
+            return;
+        }
+        let Some(scene) = crisis.scenes.get(&story_state.current_scene) else {
+            self.scene_transition = None;
+            return;
+        };
+        if self.settings_reduce_motion {
+            self.scene_transition = None;
+            return;
+        }
+        
+        let text_length = std::iter::once(&story_state.language)
+            .chain(story_state.secondary_language.as_ref())
+            .map(|language| crate::crisis::get_scene_text_with_substitutions(scene, language, crisis, story_state).chars().count())
+            .max()
+            .unwrap_or(0);
+        self.scene_transition = Some(SceneTransitionState::new(
+            scene.transition,
+            previous.and_then(|(_, background)| background),
+            text_length,
+        ));
+        self.view_needs_redraw.mark_animation_dirty();
+    }
+
+    /// Shows the rest of a typewriter reveal; returns false if no text was still appearing
+    fn skip_text_reveal(&mut self) -> bool {
+        match self.scene_transition {
+            Some(ref mut transition) if transition.is_text_revealing() => {
+                transition.text_skipped = true;
+                self.view_needs_redraw.mark_game_dirty();
+                true
+            }
+            _ => false,
+        }
+    }
+
     fn handle_game_restart(&mut self) -> Task<GameMessage> {
         if let Ok(mut evt_loop_wguard) = self.game_state.active_event_loop.write() {
             *evt_loop_wguard = crate::game::ActiveEventLoop::WelcomeScreen(crate::game::WelcomeScreenView::Empty);
         }
         self.current_crisis = None;
         self.story_state = None;
+        self.scene_transition = None;
+        self.game_history.clear();
+        self.crisis_reload_error = None;
         self.new_game_game_template = None;
         self.new_game_selected_description = None;
+        self.new_game_fork_status = None;
         Task::none()
     }
 
-    fn handle_save_and_quit(&mut self) -> Task<GameMessage> {
-        let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
+    fn handle_crisis_restart(&mut self) -> Task<GameMessage> {
+        if !self.restart_allowed() {
+            return Task::none();
+        }
         
-        if let (Some(story_state), Some(_crisis)) = (&self.story_state, &self.current_crisis) {
-            match crate::crisis::save_current_game(story_state, &story_state.template_name, None) {
-                Ok(save_name) => {
-                    if *verbosity > 0 {
-                        eprintln!("Game saved as: {}", save_name);
-                    }
-                }
-                Err(e) => {
-                    if *verbosity > 0 {
-                        eprintln!("Failed to save game: {}", e);
-                    }
-                }
+        let fresh_state = if let (Some(crisis), Some(story_state)) = (&self.current_crisis, &self.story_state) {
+            // Same player and languages; only the progress through the story starts over
+            let mut fresh_state = story_state.clone();
+            fresh_state.current_scene = crisis.story.starting_scene.clone();
+            fresh_state.variables = crisis.conditions.initial_variables();
+            fresh_state.decisions.clear();
+            fresh_state.text_inputs.clear();
+            // A role taken during the story came with its own name; the new run starts in the starting role
+            if fresh_state.character_type.take().is_some() {
+                fresh_state.character_name = crate::crisis::get_random_character_name(
+                    crisis,
+                    None,
+                    fresh_state.player_gender,
+                    &fresh_state.language
+                );
             }
-        }
+            Some(fresh_state)
+        } else {
+            None
+        };
         
-        self.clear_game_state();
+        if let Some(fresh_state) = fresh_state {
+            self.game_history.clear();
+            self.crisis_reload_error = None;
+            self.pause_menu = None;
+            self.restore_story_state(fresh_state);
+            
+            // Starting over is a new run, timed from zero
+            if let Some(ref mut story_state) = self.story_state {
+                story_state.play_time_ms = 0;
+            }
+            self.play_clock = crate::time::now();
+            self.record_run_started();
+        }
         Task::none()
     }
 
-    fn handle_quit_without_save(&mut self) -> Task<GameMessage> {
-        let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
-        if *verbosity > 0 {
-            eprintln!("Game_QuitWithoutSaveRequested");
+    fn handle_rewind(&mut self) -> Task<GameMessage> {
+        if !self.rewind_allowed() {
+            return Task::none();
+        }
+        if let (Some(entry), Some(current_state)) = (self.game_history.rewind(), self.story_state.as_ref()) {
+            let previous_state = entry.restore(current_state);
+            self.restore_story_state(previous_state);
         }
-        
-        self.clear_game_state();
         Task::none()
     }
 
-    fn clear_game_state(&mut self) {
-        if let Ok(mut evt_loop_wguard) = self.game_state.active_event_loop.write() {
-            *evt_loop_wguard = crate::game::ActiveEventLoop::WelcomeScreen(crate::game::WelcomeScreenView::Empty);
+    fn handle_timeline_jump(&mut self, index: usize) -> Task<GameMessage> {
+        if !self.rewind_allowed() {
+            return Task::none();
         }
-        
+        if let (Some(entry), Some(current_state)) = (self.game_history.jump_to(index), self.story_state.as_ref()) {
+            let previous_state = entry.restore(current_state);
+            self.restore_story_state(previous_state);
+        }
+        Task::none()
+    }
+
+    /// Swap in a story state taken from history (or a fresh one) and refresh everything derived from the scene
+    fn restore_story_state(&mut self, mut state: crate::crisis::GameState) {
+        // Going back in the story keeps the languages the player reads it in now
+        // and the time already played, so rewinding does not shorten the run
+        self.bank_play_time();
+        if let Some(ref current_state) = self.story_state {
+            state.language = current_state.language.clone();
+            state.secondary_language = current_state.secondary_language.clone();
+            state.play_time_ms = current_state.play_time_ms;
+        }
+        let scene_name = state.current_scene.clone();
+        self.story_state = Some(state);
+        self.scene_transition = None; // Going back shows the scene as it was left, without animating it again
+        self.unsaved_changes = true;
+        self.choice_text_inputs.clear();
+        self.animation_frame_index = 0;
+        
+        if let Some(crisis) = self.current_crisis.clone() {
+            self.load_scene_background_audio(&crisis, &scene_name);
+            let num_choices = crisis.scenes.get(&scene_name)
+                .map(|scene| scene.choices.len())
+                .unwrap_or(0);
+            self.update_focus_for_game_screen(num_choices);
+        }
+        
+        self.view_needs_redraw.mark_game_dirty();
+        if self.has_character_animation() {
+            self.view_needs_redraw.mark_animation_dirty();
+        }
+    }
+
+    /// Reloads the running crisis after its files changed on disk. The player stays
+    /// where they are as long as their scene still exists; otherwise the previous
+    /// version keeps running and the problem is shown over the game.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn handle_crisis_files_poll(&mut self) {
+        let changed_crises = match self.crisis_watcher {
+            Some(ref watcher) => watcher.changed_crises(),
+            None => return,
+        };
+        if changed_crises.is_empty() {
+            return;
+        }
+        
+        crate::crisis::CrisisCatalog::invalidate();
+        if changed_crises.contains(crate::translations::catalog::OVERRIDES_FOLDER) {
+            crate::translations::reload_translations();
+        }
+        self.view_needs_redraw.mark_menu_dirty();
+        
+        let (template_name, scene_name) = match self.story_state {
+            Some(ref story_state) => (story_state.template_name.clone(), story_state.current_scene.clone()),
+            None => return,
+        };
+        // A changed .fcpack may hold any crisis, so it always triggers a reload
+        let pack_changed = changed_crises.iter().any(|name| name.ends_with(crate::crisis::pack::PACK_EXTENSION));
+        if !changed_crises.contains(&template_name) && !pack_changed {
+            return;
+        }
+        
+        let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
+        match crate::crisis::load_crisis(&template_name) {
+            Ok(crisis) => {
+                if let Some(scene) = crisis.scenes.get(&scene_name) {
+                    let num_choices = scene.choices.len();
+                    if *verbosity > 0 {
+                        eprintln!("Hot-reloaded crisis '{}' at scene '{}'", template_name, scene_name);
+                    }
+                    // The scene's background, character images and audio may have been replaced too
+                    self.cached_views.timeline_labels = None;
+                    self.load_scene_background_audio(&crisis, &scene_name);
+                    self.current_crisis = Some(crisis);
+                    self.load_crisis_images();
+                    self.crisis_reload_error = None;
+                    self.animation_frame_index = 0;
+                    self.update_focus_for_game_screen(num_choices);
+                } else {
+                    let vars = std::collections::HashMap::from([("scene".to_string(), scene_name.clone())]);
+                    self.crisis_reload_error = Some(format!(
+                        "{}: {}",
+                        crate::translations::t(crate::translations::TranslationKey::CrisisReloadFailed, &self.settings_language),
+                        crate::translations::t_vars(crate::translations::TranslationKey::CrisisSceneRemoved, &self.settings_language, &vars)
+                    ));
+                }
+            }
+            Err(e) => {
+                if *verbosity > 0 {
+                    eprintln!("Failed to hot-reload crisis '{}': {}", template_name, e);
+                }
+                self.crisis_reload_error = Some(format!(
+                    "{}: {}",
+                    crate::translations::t(crate::translations::TranslationKey::CrisisReloadFailed, &self.settings_language),
+                    e
+                ));
+            }
+        }
+        self.view_needs_redraw.mark_game_dirty();
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn handle_font_fetch_poll(&mut self) -> Task<GameMessage> {
+        let mut loads = Vec::new();
+        while let Some(bytes) = crate::fonts::take_fetched_font() {
+            loads.push(iced::font::load(bytes).map(GameMessage::Menu_FontLoaded));
+        }
+        if !crate::fonts::font_fetches_running() {
+            self.font_fetch_pending = false;
+        }
+        Task::batch(loads)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn handle_pack_upload_poll(&mut self) {
+        let Some(bytes) = crate::wasm32_upload::take_uploaded_pack() else {
+            // Nothing to wait for once the picker is closed without a file
+            if crate::wasm32_upload::take_upload_given_up() {
+                self.pack_upload_pending = false;
+            }
+            return;
+        };
+        self.pack_upload_pending = false;
+        
+        self.pack_upload_status = Some(match crate::crisis::pack::install_pack_bytes(&bytes) {
+            Ok(manifest) => format!(
+                "{}: {} v{} ({}, {})",
+                crate::translations::t(crate::translations::TranslationKey::PackLoaded, &self.settings_language),
+                manifest.name, manifest.version, manifest.author, manifest.license
+            ),
+            Err(e) => format!(
+                "{}: {}",
+                crate::translations::t(crate::translations::TranslationKey::PackLoadFailed, &self.settings_language),
+                match e.downcast_ref::<crate::crisis::pack::EngineTooOld>() {
+                    Some(too_old) => too_old.to_translated_string(&self.settings_language),
+                    None => e.to_string(),
+                }
+            ),
+        });
+        self.view_needs_redraw.mark_settings_dirty();
+        self.view_needs_redraw.mark_menu_dirty();
+    }
+
+    pub fn restart_allowed(&self) -> bool {
+        self.current_crisis.as_ref()
+            .map(|crisis| crisis.mechanics.allow_restart)
+            .unwrap_or(false)
+    }
+
+    pub fn rewind_allowed(&self) -> bool {
+        self.current_crisis.as_ref()
+            .map(|crisis| crisis.mechanics.allow_rewind)
+            .unwrap_or(false)
+    }
+
+    /// The decision log shows once the crisis tracks decisions and the player made one
+    pub fn transcript_allowed(&self) -> bool {
+        let tracks_decisions = self.current_crisis.as_ref()
+            .map(|crisis| crisis.mechanics.track_decisions)
+            .unwrap_or(false);
+        tracks_decisions && self.story_state.as_ref().is_some_and(|story_state| !story_state.decisions.is_empty())
+    }
+
+    /// Re-renders the running story in another language; nothing else about the game changes
+    fn handle_game_language_change(&mut self, language: String) -> Task<GameMessage> {
+        if let Some(ref mut story_state) = self.story_state {
+            if story_state.secondary_language.as_ref() == Some(&language) {
+                // Swapping the two languages over keeps both on screen
+                story_state.secondary_language = Some(story_state.language.clone());
+            }
+            story_state.language = language;
+        }
+        self.choice_text_inputs.clear();
+        
+        // The layout mirrors when switching between left-to-right and right-to-left languages
+        if let Some(num_choices) = self.current_scene_choice_count() {
+            self.update_focus_for_game_screen(num_choices);
+        }
+        self.view_needs_redraw.mark_game_dirty();
+        Task::none()
+    }
+
+    /// Languages the running crisis is written in; the in-game language pickers offer these
+    pub fn story_languages(&self) -> Vec<String> {
+        self.current_crisis.as_ref()
+            .map(|crisis| crisis.languages())
+            .unwrap_or_default()
+    }
+
+    /// Escape and the controller's Start button open the pause menu, step back to its
+    /// first page, and close it from there
+    fn handle_pause_menu_toggle(&mut self) -> Task<GameMessage> {
+        if self.story_state.is_none() {
+            return Task::none();
+        }
+        let view = match self.pause_menu {
+            None => Some(PauseMenuView::Main),
+            Some(PauseMenuView::Main) => None,
+            Some(_) => Some(PauseMenuView::Main),
+        };
+        self.show_pause_menu(view);
+        Task::none()
+    }
+
+    /// Scrolls the list holding the focused row so the row stays on screen; rows differ in
+    /// height, so the offset is the row's share of the list
+    fn scroll_focus_into_view(&self) -> Task<GameMessage> {
+        let (scrollable, position, count) = match self.focus_state.current_focus {
+            Some(FocusId("transcript", index)) if index > 0 => {
+                let count = self.story_state.as_ref().map(|story_state| story_state.decisions.len()).unwrap_or(0);
+                (TRANSCRIPT_SCROLLABLE, index - 1, count)
+            }
+            Some(FocusId("crisis_card", index)) => {
+                let rows = self.browse_results().len().div_ceil(crate::gui::views::BROWSER_COLUMNS);
+                (CRISIS_BROWSER_SCROLLABLE, index / crate::gui::views::BROWSER_COLUMNS, rows)
+            }
+            _ => return Task::none(),
+        };
+        let y = if count > 1 { position as f32 / (count - 1) as f32 } else { 0.0 };
+        iced::widget::scrollable::snap_to(
+            iced::widget::scrollable::Id::new(scrollable),
+            iced::widget::scrollable::RelativeOffset { x: 0.0, y: y.min(1.0) },
+        )
+    }
+
+    fn show_pause_menu(&mut self, view: Option<PauseMenuView>) {
+        self.pause_menu = view;
+        self.pause_menu_status = None;
+        if view != Some(PauseMenuView::ConfirmLoad) {
+            self.pause_pending_load = None;
+        }
+        if view.is_some() {
+            self.update_focus_for_pause_menu();
+            self.focus_state.current_focus = self.focus_state.focusable_elements.first().copied();
+        } else if let Some(num_choices) = self.current_scene_choice_count() {
+            self.update_focus_for_game_screen(num_choices);
+        }
+        self.view_needs_redraw.mark_game_dirty();
+    }
+
+    fn handle_pause_load(&mut self, saved_game_name: &str) {
+        if let Err(e) = self.start_saved_game(saved_game_name) {
+            self.pause_menu = Some(PauseMenuView::Load);
+            self.update_focus_for_pause_menu();
+            self.pause_menu_status = Some(e);
+            self.view_needs_redraw.mark_game_dirty();
+        }
+    }
+
+    /// Saves without leaving the game; the result is shown in the pause menu
+    fn handle_pause_save(&mut self) -> Task<GameMessage> {
+        self.bank_play_time();
+        if let Some(ref story_state) = self.story_state {
+            self.pause_menu_status = Some(match crate::crisis::save_current_game(story_state, &self.game_history, &story_state.template_name, None) {
+                Ok(save_name) => {
+                    self.unsaved_changes = false;
+                    let mut vars = std::collections::HashMap::new();
+                    vars.insert("save_name".to_string(), save_name);
+                    crate::translations::t_vars(crate::translations::TranslationKey::GameSaved, &self.settings_language, &vars)
+                }
+                Err(e) => format!(
+                    "{}: {}",
+                    crate::translations::t(crate::translations::TranslationKey::SaveFailed, &self.settings_language),
+                    e
+                ),
+            });
+            self.view_needs_redraw.mark_game_dirty();
+        }
+        Task::none()
+    }
+
+    fn handle_save_and_quit(&mut self) -> Task<GameMessage> {
+        let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
+        
+        self.bank_play_time();
+        if let (Some(story_state), Some(_crisis)) = (&self.story_state, &self.current_crisis) {
+            match crate::crisis::save_current_game(story_state, &self.game_history, &story_state.template_name, None) {
+                Ok(save_name) => {
+                    if *verbosity > 0 {
+                        eprintln!("Game saved as: {}", save_name);
+                    }
+                }
+                Err(e) => {
+                    if *verbosity > 0 {
+                        eprintln!("Failed to save game: {}", e);
+                    }
+                }
+            }
+        }
+        
+        self.clear_game_state();
+        Task::none()
+    }
+
+    fn handle_quit_without_save(&mut self) -> Task<GameMessage> {
+        let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
+        if *verbosity > 0 {
+            eprintln!("Game_QuitWithoutSaveRequested");
+        }
+        
+        self.clear_game_state();
+        Task::none()
+    }
+
+    fn clear_game_state(&mut self) {
+        if let Ok(mut evt_loop_wguard) = self.game_state.active_event_loop.write() {
+            *evt_loop_wguard = crate::game::ActiveEventLoop::WelcomeScreen(crate::game::WelcomeScreenView::Empty);
+        }
+        
         self.current_crisis = None;
         self.story_state = None;
+        self.game_history.clear();
+        self.crisis_reload_error = None;
+        self.pause_menu = None;
+        self.pause_menu_status = None;
+        self.pause_pending_load = None;
+        self.unsaved_changes = false;
+        self.scene_transition = None;
         self.new_game_game_template = None;
         self.new_game_selected_description = None;
+        self.new_game_fork_status = None;
         self.choice_text_inputs.clear();
         self.animation_frame_index = 0;
         self.current_background_audio.clear(); // Stop any playing audio
@@ -667,13 +1477,8 @@ impl GameWindow {
     
     #[cfg(not(target_arch = "wasm32"))]
     fn update_audio_playback(&self) {
-        use std::io::Cursor;
-        use std::sync::{Arc, Mutex};
         use std::thread;
         
-        static DESKTOP_AUDIO_STATE: once_cell::sync::Lazy<Arc<Mutex<DesktopAudioState>>> = 
-            once_cell::sync::Lazy::new(|| Arc::new(Mutex::new(DesktopAudioState::default())));
-        
         let audio_state = DESKTOP_AUDIO_STATE.clone();
         let current_audio = self.current_background_audio.clone();
         
@@ -723,6 +1528,10 @@ impl GameWindow {
     }
     
     fn handle_focus_activation(&mut self) -> Task<GameMessage> {
+        // Enter and the A button first finish appearing text, so a reader cannot pick a choice by accident
+        if self.pause_menu.is_none() && self.skip_text_reveal() {
+            return Task::none();
+        }
         if let Some(current_focus) = self.focus_state.current_focus {
             match (current_focus.0, current_focus.1) {
                 // Menu buttons
@@ -731,9 +1540,19 @@ impl GameWindow {
                 ("menu", 2) => Task::done(GameMessage::Menu_SettingsRequested),
                 ("menu", 3) => Task::done(GameMessage::Menu_LicensesRequested),
                 ("menu", 4) => Task::done(GameMessage::QuitGameRequested),
+                ("menu", 5) => Task::done(GameMessage::Menu_StatsRequested),
+                ("menu", 7) => Task::done(GameMessage::Menu_ProfileSwitchConfirmed),
+                ("menu", 8) => Task::done(GameMessage::Menu_ProfileSwitchCancelled),
                 
                 // New game elements
                 ("newgame_button", 0) => Task::done(GameMessage::Menu_NewGameStartClicked),
+                ("newgame_button", 1) => Task::done(GameMessage::Menu_NewGameForkRequested),
+                ("crisis_card", index) => {
+                    match self.browse_results().get(index) {
+                        Some(entry) => Task::done(GameMessage::Menu_NewGameTemplateChoiceAltered(entry.template_name.clone())),
+                        None => Task::none(),
+                    }
+                }
                 // Note: text inputs and pick lists handle their own focus/activation
                 
                 // Continue game elements
@@ -762,12 +1581,42 @@ impl GameWindow {
                 
                 // Settings elements
                 ("settings_button", 0) => Task::done(GameMessage::Menu_SettingsOpenCrisesFolder),
+                ("settings_button", 1) => Task::done(GameMessage::Menu_SettingsUploadPackRequested),
                 // Note: toggles can be activated with Enter key
                 ("settings_toggle", 0) => Task::done(GameMessage::Menu_SettingsAutosaveToggled(!self.settings_autosave)),
+                ("settings_toggle", 1) => Task::done(GameMessage::Menu_SettingsRequireSignedToggled(!self.settings_require_signed_crises)),
+                ("settings_toggle", 2) => Task::done(GameMessage::Menu_SettingsReduceMotionToggled(!self.settings_reduce_motion)),
                 
                 // Game control buttons
                 ("control", 0) => Task::done(GameMessage::Game_SaveAndQuitRequested),
-                ("control", 1) => Task::done(GameMessage::Game_QuitWithoutSaveRequested),
+                ("control", 1) => Task::done(GameMessage::Game_PauseQuitRequested),
+                ("control", 2) => Task::done(GameMessage::Game_RewindRequested),
+                ("control", 3) => Task::done(GameMessage::Game_RestartCrisisRequested),
+                ("control", 4) => Task::done(GameMessage::Game_PauseMenuToggled),
+                
+                // Pause menu
+                ("pause", 0) => Task::done(GameMessage::Game_PauseMenuToggled),
+                ("pause", 1) => Task::done(GameMessage::Game_PauseSaveRequested),
+                ("pause", 2) => Task::done(GameMessage::Game_PauseMenuViewChanged(PauseMenuView::Load)),
+                ("pause", 3) => Task::done(GameMessage::Game_PauseMenuViewChanged(PauseMenuView::Settings)),
+                ("pause", 4) => Task::done(GameMessage::Game_RestartCrisisRequested),
+                ("pause", 5) => Task::done(GameMessage::Game_PauseQuitRequested),
+                ("pause", 6) => Task::done(GameMessage::Game_PauseMenuViewChanged(PauseMenuView::Main)),
+                ("pause", 7) => Task::done(GameMessage::Game_SaveAndQuitRequested),
+                ("pause", 8) => Task::done(GameMessage::Game_QuitWithoutSaveRequested),
+                ("pause", 9) => Task::done(GameMessage::Game_PauseLoadConfirmed),
+                ("pause_load", index) => {
+                    match crate::crisis::get_saved_games().get_save_names().get(index) {
+                        Some(saved_game_name) => Task::done(GameMessage::Game_PauseLoadRequested(saved_game_name.clone())),
+                        None => Task::none(),
+                    }
+                }
+                
+                // Timeline entries jump back to a past scene
+                ("timeline", index) => Task::done(GameMessage::Game_TimelineJumpRequested(index)),
+                
+                // The decision log toggle; its entries only take focus so they can be read
+                ("transcript", 0) => Task::done(GameMessage::Game_TranscriptToggled),
                 
                 // Game choice buttons
                 ("choice", index) => Task::done(GameMessage::Game_ChoiceSelected(index)),
@@ -779,74 +1628,175 @@ impl GameWindow {
         }
     }
     
+    /// Loads another profile's settings, saves and statistics and returns to the main menu
+    fn switch_profile(&mut self, profile_id: &str) {
+        if profile_id == self.profiles.active {
+            return;
+        }
+        // A crises folder typed on the settings screen belongs to the profile being left
+        self.apply_crises_folder_input();
+        if !crate::profile::switch_profile(profile_id) {
+            return;
+        }
+        self.profiles = crate::profile::get_profiles();
+        self.settings_profile_name = self.profiles.active_profile().map(|profile| profile.name.clone()).unwrap_or_default();
+        self.apply_settings(Self::load_settings());
+        self.player_stats = crate::crisis::get_player_stats();
+        
+        // Choices made on the other profile's screens do not carry over
+        self.continue_game_game_choice = None;
+        self.continue_game_delete_confirmation = None;
+        self.new_game_game_template = None;
+        self.new_game_selected_description = None;
+        self.new_game_fork_status = None;
+        self.crisis_query = crate::crisis::CrisisQuery::default();
+        self.focus_state.pick_list_selection_index.clear();
+        
+        if let Ok(mut evt_loop_wguard) = self.game_state.active_event_loop.write() {
+            *evt_loop_wguard = crate::game::ActiveEventLoop::WelcomeScreen(crate::game::WelcomeScreenView::Empty);
+        }
+        self.update_focus_for_main_menu();
+        self.view_needs_redraw.force_redraw();
+    }
+    
+    fn is_on_settings_screen(&self) -> bool {
+        matches!(
+            self.game_state.active_event_loop.read().map(|event_loop| event_loop.clone()),
+            Ok(crate::game::ActiveEventLoop::WelcomeScreen(crate::game::WelcomeScreenView::Settings))
+        )
+    }
+    
+    /// Puts the typed crises folder into effect: saved, then loaded from and watched on desktop
+    fn apply_crises_folder_input(&mut self) {
+        if self.settings_game_crises_folder_input == self.settings_game_crises_folder {
+            return;
+        }
+        self.settings_game_crises_folder = self.settings_game_crises_folder_input.clone();
+        eprintln!("Settings: Game Crises Folder changed to: {}", self.settings_game_crises_folder);
+        self.save_settings();
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            crate::crisis::PlayableCrises::set_crises_folder(&self.settings_game_crises_folder);
+            self.crisis_watcher = Self::create_crisis_watcher(&self.settings_game_crises_folder);
+        }
+    }
+    
     fn update_focus_for_main_menu(&mut self) {
-        let menu_elements = vec![
-            FocusId::menu_button(0), // Continue Game  
-            FocusId::menu_button(1), // New Game
-            FocusId::menu_button(2), // Settings
-            FocusId::menu_button(3), // Licenses
-            FocusId::menu_button(4), // Quit Game
-        ];
+        let menu_elements = self.menu_panel_focus_elements();
         self.focus_state.set_focusable_elements(menu_elements);
     }
     
-    fn update_focus_for_new_game_screen(&mut self) {
-        let elements = vec![
-            // Left panel
-            FocusId::menu_button(0), // Continue Game  
+    /// The left panel, with Switch and Cancel under the profile switcher while a switch waits for confirmation
+    fn menu_panel_focus_elements(&self) -> Vec<FocusId> {
+        let mut elements = vec![FocusId::menu_button(6)]; // Profile switcher
+        if self.pending_profile_switch.is_some() {
+            elements.push(FocusId::menu_button(7)); // Switch
+            elements.push(FocusId::menu_button(8)); // Cancel
+        }
+        elements.extend([
+            FocusId::menu_button(0), // Continue Game
             FocusId::menu_button(1), // New Game
             FocusId::menu_button(2), // Settings
+            FocusId::menu_button(5), // Statistics
             FocusId::menu_button(3), // Licenses
             FocusId::menu_button(4), // Quit Game
-            
+        ]);
+        elements
+    }
+    
+    /// Adds or drops the profile confirmation's buttons, keeping the right panel's focus stops
+    fn refresh_menu_panel_focus(&mut self) {
+        let mut elements = self.menu_panel_focus_elements();
+        elements.extend(self.focus_state.focusable_elements.iter().copied().filter(|element| element.0 != "menu"));
+        self.focus_state.set_focusable_elements(elements);
+    }
+    
+    fn new_game_focus_elements(&self) -> Vec<FocusId> {
+        let mut elements = self.menu_panel_focus_elements();
+        elements.extend([
             // Right panel - new game elements
             FocusId::new_game_input(0),  // Player name input
-            FocusId::new_game_input(1),  // Game template picker
-            FocusId::new_game_button(0), // Go button
-        ];
+            FocusId::new_game_input(2),  // Pronouns picker
+            FocusId::new_game_input(3),  // Custom pronouns input
+            FocusId::new_game_input(1),  // Crisis search input
+            FocusId::new_game_input(4),  // Language filter
+            FocusId::new_game_input(5),  // Tag filter
+            FocusId::new_game_input(6),  // Sort order
+        ]);
+        
+        // One stop per card, in reading order
+        let card_count = self.browse_results().len();
+        elements.extend((0..card_count).map(FocusId::crisis_card));
+        
+        if self.selected_crisis_can_fork() {
+            elements.push(FocusId::new_game_button(1)); // Fork button
+        }
+        elements.push(FocusId::new_game_button(0)); // Go button
+        elements
+    }
+    
+    /// True when the selected crisis is not already an editable copy in the crises folder
+    pub fn selected_crisis_can_fork(&self) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(ref template_name) = self.new_game_game_template {
+            return matches!(
+                crate::crisis::CrisisCatalog::get().source_of(template_name),
+                Some(source) if source != crate::crisis::CrisisSource::CrisesFolder
+            );
+        }
+        false
+    }
+    
+    fn update_focus_for_new_game_screen(&mut self) {
+        self.refresh_browse_results();
+        let elements = self.new_game_focus_elements();
         self.focus_state.set_focusable_elements(elements);
         
         // Set focus to the first right panel element (player name input) for better UX
         self.focus_state.current_focus = Some(FocusId::new_game_input(0));
-        
-        // Initialize pick list selection index for game template picker
-        let game_template_focus_id = FocusId::new_game_input(1);
-        if !self.focus_state.pick_list_selection_index.contains_key(&game_template_focus_id) {
-            let crisis_names = crate::crisis::get_crisis_names_localized(&self.settings_language);
-            if !crisis_names.is_empty() {
-                // Find current template's index in the crisis_names list, or default to 0
-                let initial_index = if let Some(ref template_name) = self.new_game_game_template {
-                    let mut found_index = 0;
-                    for (index, display_name) in crisis_names.iter().enumerate() {
-                        let found_template = crate::crisis::get_template_name_from_display_name(display_name);
-                        if found_template == *template_name {
-                            found_index = index;
-                            break;
-                        }
-                    }
-                    found_index
-                } else {
-                    0
-                };
-                self.focus_state.pick_list_selection_index.insert(game_template_focus_id, initial_index);
+    }
+    
+    /// Redraws the crisis browser after its search, filters or sort change; the cards to focus change with them
+    fn refresh_crisis_browser(&mut self) {
+        self.refresh_browse_results();
+        let elements = self.new_game_focus_elements();
+        self.focus_state.set_focusable_elements(elements);
+        self.view_needs_redraw.mark_menu_dirty();
+    }
+    
+    /// Reads the browser's cover images once per visit, so redraws reuse the same image handles.
+    /// The images of a crisis still being played are read again with them.
+    fn load_crisis_covers(&mut self) {
+        self.load_crisis_images();
+        for entry in crate::crisis::CrisisCatalog::get().playable_entries() {
+            let Some(ref cover_image) = entry.cover_image else {
+                continue;
+            };
+            if let Some(file) = crate::crisis::PlayableCrises::get(cover_image) {
+                self.cached_views.get_or_load_image(cover_image, &file.data);
             }
         }
     }
     
+    fn update_focus_for_stats_screen(&mut self) {
+        let mut elements = self.menu_panel_focus_elements();
+        elements.extend([
+            // Right panel - the totals, then one panel per crisis, so each can be read out
+            FocusId::stats_row(0),
+        ]);
+        let crisis_count = self.stats_entries(&crate::crisis::CrisisCatalog::get()).len();
+        elements.extend((1..=crisis_count).map(FocusId::stats_row));
+        self.focus_state.set_focusable_elements(elements);
+    }
+    
     fn update_focus_for_continue_game_screen(&mut self) {
-        let mut elements = vec![
-            // Left panel
-            FocusId::menu_button(0), // Continue Game  
-            FocusId::menu_button(1), // New Game
-            FocusId::menu_button(2), // Settings
-            FocusId::menu_button(3), // Licenses
-            FocusId::menu_button(4), // Quit Game
-            
+        let mut elements = self.menu_panel_focus_elements();
+        elements.extend([
             // Right panel - continue game elements
             FocusId::continue_game_input(0),  // Saved games picker
             FocusId::continue_game_button(0), // Play button
             FocusId::continue_game_button(1), // Delete button
-        ];
+        ]);
         
         // Add confirmation dialog buttons if delete confirmation is active
         if self.continue_game_delete_confirmation.is_some() {
@@ -858,43 +1808,131 @@ impl GameWindow {
     }
     
     fn update_focus_for_settings_screen(&mut self) {
-        let mut elements = vec![
-            // Left panel
-            FocusId::menu_button(0), // Continue Game  
-            FocusId::menu_button(1), // New Game
-            FocusId::menu_button(2), // Settings
-            FocusId::menu_button(3), // Licenses
-            FocusId::menu_button(4), // Quit Game
-            
+        let mut elements = self.menu_panel_focus_elements();
+        elements.extend([
             // Right panel - settings elements
+            FocusId::settings_input(2),   // Profile name
             FocusId::settings_input(0),   // Game crises folder
             FocusId::settings_picker(0),  // Difficulty level
             FocusId::settings_toggle(0),  // Autosave toggle
             FocusId::settings_picker(1),  // Language picker
             FocusId::settings_slider(0),  // Font scale slider
-        ];
+            FocusId::settings_slider(1),  // Volume slider
+            FocusId::settings_toggle(2),  // Reduce motion toggle
+            FocusId::settings_picker(2),  // Theme picker
+            FocusId::settings_input(1),   // Trusted signing keys
+            FocusId::settings_toggle(1),  // Require signed crises toggle
+        ]);
+        let folder_input_index = elements.iter().position(|&element| element == FocusId::settings_input(0)).unwrap_or_default();
         
         // Add Open Folder button on non-wasm32 platforms
         #[cfg(not(target_arch = "wasm32"))]
         {
-            elements.insert(6, FocusId::settings_button(0)); // Open folder button after folder input
+            elements.insert(folder_input_index + 1, FocusId::settings_button(0)); // Open folder button after folder input
+        }
+        
+        // Add Load Crisis Pack button on wasm32, where there is no folder to open
+        #[cfg(target_arch = "wasm32")]
+        {
+            elements.insert(folder_input_index + 1, FocusId::settings_button(1)); // Load pack button after folder input
         }
         
         self.focus_state.set_focusable_elements(elements);
+        self.sync_settings_focus_values();
+    }
+    
+    /// Starts keyboard and controller edits of the sliders, language and theme pickers from their current values
+    fn sync_settings_focus_values(&mut self) {
+        self.focus_state.slider_values.insert(FocusId::settings_slider(0), self.settings_font_scale);
+        self.focus_state.slider_values.insert(FocusId::settings_slider(1), self.settings_volume);
+        if let Some(index) = crate::language::get_available_languages().iter().position(|(code, _)| *code == self.settings_language) {
+            self.focus_state.pick_list_selection_index.insert(FocusId::settings_picker(1), index);
+        }
+        if let Some(index) = ThemeSetting::all().iter().position(|theme| *theme == self.settings_theme) {
+            self.focus_state.pick_list_selection_index.insert(FocusId::settings_picker(2), index);
+        }
+    }
+    
+    fn update_focus_for_pause_menu(&mut self) {
+        let elements = match self.pause_menu {
+            Some(PauseMenuView::Main) => {
+                let mut elements = vec![
+                    FocusId::pause_button(0), // Resume
+                    FocusId::pause_button(1), // Save
+                    FocusId::pause_button(2), // Load
+                    FocusId::pause_button(3), // Settings
+                ];
+                if self.restart_allowed() {
+                    elements.push(FocusId::pause_button(4)); // Restart
+                }
+                elements.push(FocusId::pause_button(5)); // Quit
+                elements
+            }
+            Some(PauseMenuView::Settings) => {
+                self.sync_settings_focus_values();
+                vec![
+                    FocusId::settings_slider(0), // Font scale slider
+                    FocusId::settings_slider(1), // Volume slider
+                    FocusId::settings_toggle(2), // Reduce motion toggle
+                    FocusId::settings_picker(2), // Theme picker
+                    FocusId::settings_picker(1), // Language picker
+                    FocusId::pause_button(6),    // Back
+                ]
+            }
+            Some(PauseMenuView::Load) => {
+                let save_count = crate::crisis::get_saved_games().get_save_names().len();
+                let mut elements: Vec<FocusId> = (0..save_count).map(FocusId::pause_load).collect();
+                elements.push(FocusId::pause_button(6)); // Back
+                elements
+            }
+            Some(PauseMenuView::ConfirmQuit) => vec![
+                FocusId::pause_button(7), // Save & Quit
+                FocusId::pause_button(8), // Quit without saving
+                FocusId::pause_button(6), // Back
+            ],
+            Some(PauseMenuView::ConfirmLoad) => vec![
+                FocusId::pause_button(9), // Load without saving
+                FocusId::pause_button(6), // Back
+            ],
+            None => return,
+        };
+        self.focus_state.set_focusable_elements(elements);
     }
     
     fn handle_tab_interaction(&mut self, reverse: bool) -> Task<GameMessage> {
         use crate::gui::types::TabInteractionResult;
         
         match self.focus_state.handle_tab_interact(reverse) {
-            TabInteractionResult::TextInputToggled(_focus_id, _is_focused) => {
-                // Text input focus is handled by the focus state itself
-                Task::none()
+            TabInteractionResult::TextInputToggled(focus_id, is_focused) => {
+                // Text input focus is handled by the focus state itself; leaving a
+                // settings input commits what was typed, like pressing Enter
+                match (focus_id.0, focus_id.1, is_focused) {
+                    ("settings_input", 0, false) => Task::done(GameMessage::Menu_SettingsGameCrisesFolderSubmitted),
+                    ("settings_input", 1, false) => Task::done(GameMessage::Menu_SettingsTrustedKeysSubmitted),
+                    ("settings_input", 2, false) => Task::done(GameMessage::Menu_SettingsProfileNameSubmitted),
+                    _ => Task::none(),
+                }
             }
             
             TabInteractionResult::PickListCycle(focus_id, current_index, is_reverse) => {
                 // Handle pick list cycling based on the specific pick list
                 match (focus_id.0, focus_id.1) {
+                    ("menu", 6) => {
+                        // Profile switcher - cycle through existing profiles; new ones are only made by picking "New profile".
+                        // Cycling only picks the profile, the switch still waits for Switch
+                        let profile_ids: Vec<String> = self.profiles.profiles.iter().map(|profile| profile.id.clone()).collect();
+                        let selected_id = self.pending_profile_switch.as_ref()
+                            .and_then(|choice| choice.profile_id.as_ref())
+                            .unwrap_or(&self.profiles.active);
+                        let current_index = profile_ids.iter().position(|profile_id| profile_id == selected_id).unwrap_or(0);
+                        let next_index = if is_reverse {
+                            if current_index == 0 { profile_ids.len() - 1 } else { current_index - 1 }
+                        } else {
+                            (current_index + 1) % profile_ids.len()
+                        };
+                        self.focus_state.pick_list_selection_index.insert(focus_id, next_index);
+                        Task::done(GameMessage::Menu_ProfileSelected(self.profile_choices()[next_index].clone()))
+                    }
                     ("continue_input", 0) => {
                         // Saved games picker - cycle through saved games
                         let saved_games = crate::crisis::get_saved_crisis_names();
@@ -911,45 +1949,93 @@ impl GameWindow {
                         }
                         Task::none()
                     }
-                    ("newgame_input", 1) => {
-                        // Game template picker - cycle through available templates
-                        let crisis_names = crate::crisis::get_crisis_names_localized(&self.settings_language);
-                        if !crisis_names.is_empty() {
-                            // Initialize current_index if not set - this happens on first user interaction
-                            let current_index = if !self.focus_state.pick_list_selection_index.contains_key(&focus_id) {
-                                // Find current template's index in the crisis_names list, or default to 0
-                                if let Some(ref template_name) = self.new_game_game_template {
-                                    let mut found_index = 0;
-                                    for (index, display_name) in crisis_names.iter().enumerate() {
-                                        let found_template = crate::crisis::get_template_name_from_display_name(display_name);
-                                        if found_template == *template_name {
-                                            found_index = index;
-                                            break;
-                                        }
-                                    }
-                                    found_index
-                                } else {
-                                    // No template selected, start at index 0
-                                    0
-                                }
-                            } else {
-                                // Use existing index, but validate it's still in bounds
-                                let stored_index = current_index;
-                                if stored_index >= crisis_names.len() { 0 } else { stored_index }
-                            };
-                            
-                            let next_index = if is_reverse {
-                                if current_index == 0 { crisis_names.len() - 1 } else { current_index - 1 }
-                            } else {
-                                (current_index + 1) % crisis_names.len()
-                            };
-                            
-                            self.focus_state.pick_list_selection_index.insert(focus_id, next_index);
-                            if let Some(template_name) = crisis_names.get(next_index) {
-                                return Task::done(GameMessage::Menu_NewGameTemplateChoiceAltered(template_name.clone()));
-                            }
+                    ("newgame_input", 2) => {
+                        // Pronouns picker - cycle through player genders
+                        use crate::crisis::PlayerGender;
+                        let genders = &PlayerGender::ALL;
+                        let current_index = genders.iter().position(|gender| *gender == self.new_game_player_gender).unwrap_or(0);
+                        let next_index = if is_reverse {
+                            if current_index == 0 { genders.len() - 1 } else { current_index - 1 }
+                        } else {
+                            (current_index + 1) % genders.len()
+                        };
+                        self.focus_state.pick_list_selection_index.insert(focus_id, next_index);
+                        Task::done(GameMessage::Menu_NewGamePlayerGenderChanged(genders[next_index]))
+                    }
+                    ("newgame_input", 4) => {
+                        // Language filter - "all languages", then each language a crisis is written in
+                        let mut languages: Vec<Option<String>> = vec![None];
+                        languages.extend(crate::crisis::CrisisCatalog::get().languages().into_iter().map(Some));
+                        let current_index = languages.iter().position(|language| *language == self.crisis_query.language).unwrap_or(0);
+                        let next_index = if is_reverse {
+                            if current_index == 0 { languages.len() - 1 } else { current_index - 1 }
+                        } else {
+                            (current_index + 1) % languages.len()
+                        };
+                        self.focus_state.pick_list_selection_index.insert(focus_id, next_index);
+                        Task::done(GameMessage::Menu_NewGameLanguageFilterChanged(languages[next_index].clone()))
+                    }
+                    ("newgame_input", 5) => {
+                        // Tag filter - "all tags", then every tag in use
+                        let mut tags: Vec<Option<String>> = vec![None];
+                        tags.extend(crate::crisis::CrisisCatalog::get().tags().into_iter().map(Some));
+                        let current_index = tags.iter().position(|tag| *tag == self.crisis_query.tag).unwrap_or(0);
+                        let next_index = if is_reverse {
+                            if current_index == 0 { tags.len() - 1 } else { current_index - 1 }
+                        } else {
+                            (current_index + 1) % tags.len()
+                        };
+                        self.focus_state.pick_list_selection_index.insert(focus_id, next_index);
+                        Task::done(GameMessage::Menu_NewGameTagFilterChanged(tags[next_index].clone()))
+                    }
+                    ("newgame_input", 6) => {
+                        // Sort order
+                        use crate::crisis::CrisisSort;
+                        let sorts = &CrisisSort::ALL;
+                        let current_index = sorts.iter().position(|sort| *sort == self.crisis_query.sort).unwrap_or(0);
+                        let next_index = if is_reverse {
+                            if current_index == 0 { sorts.len() - 1 } else { current_index - 1 }
+                        } else {
+                            (current_index + 1) % sorts.len()
+                        };
+                        self.focus_state.pick_list_selection_index.insert(focus_id, next_index);
+                        Task::done(GameMessage::Menu_NewGameSortChanged(sorts[next_index]))
+                    }
+                    ("game_language", 0) => {
+                        // Story language picker - cycle through the crisis' languages
+                        let languages = self.story_languages();
+                        let Some(story_state) = self.story_state.as_ref() else {
+                            return Task::none();
+                        };
+                        if languages.is_empty() {
+                            return Task::none();
                         }
-                        Task::none()
+                        let current_index = languages.iter().position(|language| *language == story_state.language).unwrap_or(0);
+                        let next_index = if is_reverse {
+                            if current_index == 0 { languages.len() - 1 } else { current_index - 1 }
+                        } else {
+                            (current_index + 1) % languages.len()
+                        };
+                        self.focus_state.pick_list_selection_index.insert(focus_id, next_index);
+                        Task::done(GameMessage::Game_LanguageChanged(languages[next_index].clone()))
+                    }
+                    ("game_language", 1) => {
+                        // Side-by-side picker - cycle through one language only, then every other language
+                        let Some(story_state) = self.story_state.as_ref() else {
+                            return Task::none();
+                        };
+                        let mut options: Vec<Option<String>> = vec![None];
+                        options.extend(self.story_languages().into_iter()
+                            .filter(|language| *language != story_state.language)
+                            .map(Some));
+                        let current_index = options.iter().position(|option| *option == story_state.secondary_language).unwrap_or(0);
+                        let next_index = if is_reverse {
+                            if current_index == 0 { options.len() - 1 } else { current_index - 1 }
+                        } else {
+                            (current_index + 1) % options.len()
+                        };
+                        self.focus_state.pick_list_selection_index.insert(focus_id, next_index);
+                        Task::done(GameMessage::Game_SecondaryLanguageChanged(options[next_index].clone()))
                     }
                     ("settings_picker", 0) => {
                         // Difficulty picker - cycle through difficulty levels
@@ -979,6 +2065,17 @@ impl GameWindow {
                         }
                         Task::none()
                     }
+                    ("settings_picker", 2) => {
+                        // Theme picker - cycle through the game's themes, then iced's
+                        let themes = ThemeSetting::all();
+                        let next_index = if is_reverse {
+                            if current_index == 0 { themes.len() - 1 } else { current_index - 1 }
+                        } else {
+                            (current_index + 1) % themes.len()
+                        };
+                        self.focus_state.pick_list_selection_index.insert(focus_id, next_index);
+                        Task::done(GameMessage::Menu_SettingsThemeChanged(themes[next_index].clone()))
+                    }
                     _ => Task::none()
                 }
             }
@@ -990,6 +2087,10 @@ impl GameWindow {
                         // Font scale slider
                         Task::done(GameMessage::Menu_SettingsFontScaleChanged(new_value))
                     }
+                    ("settings_slider", 1) => {
+                        // Volume slider
+                        Task::done(GameMessage::Menu_SettingsVolumeChanged(new_value))
+                    }
                     _ => Task::none()
                 }
             }
@@ -1001,6 +2102,14 @@ impl GameWindow {
                         // Autosave toggle
                         Task::done(GameMessage::Menu_SettingsAutosaveToggled(!self.settings_autosave))
                     }
+                    ("settings_toggle", 1) => {
+                        // Require signed crises toggle
+                        Task::done(GameMessage::Menu_SettingsRequireSignedToggled(!self.settings_require_signed_crises))
+                    }
+                    ("settings_toggle", 2) => {
+                        // Reduce motion toggle
+                        Task::done(GameMessage::Menu_SettingsReduceMotionToggled(!self.settings_reduce_motion))
+                    }
                     _ => Task::none()
                 }
             }
@@ -1015,11 +2124,49 @@ impl GameWindow {
     }
     
     fn update_focus_for_game_screen(&mut self, num_choices: usize) {
+        // The pause menu is modal, so only its own controls take focus while it is open
+        if self.pause_menu.is_some() {
+            self.update_focus_for_pause_menu();
+            return;
+        }
+        
         let mut elements = vec![
+            FocusId::game_control(4), // Pause menu
             FocusId("control", 0), // Save and quit
-            FocusId("control", 1), // Quit without save
+            FocusId("control", 1), // Quit, asking first if there is unsaved progress
         ];
         
+        if self.rewind_allowed() && self.game_history.can_rewind() {
+            elements.push(FocusId::game_control(2)); // Rewind
+        }
+        if self.restart_allowed() {
+            elements.push(FocusId::game_control(3)); // Restart crisis
+        }
+        
+        // Add the language pickers when the crisis comes in more than one language
+        if self.story_languages().len() > 1 {
+            elements.push(FocusId::game_language(0)); // Story language
+            elements.push(FocusId::game_language(1)); // Side-by-side language
+        }
+        
+        // Add timeline entries
+        if self.rewind_allowed() {
+            for i in 0..self.game_history.len() {
+                elements.push(FocusId::game_timeline(i));
+            }
+        }
+        
+        // Add the decision log toggle, and its entries while it is open
+        if self.transcript_allowed() {
+            elements.push(FocusId::game_transcript(0));
+            if self.transcript_expanded {
+                let decision_count = self.story_state.as_ref().map(|story_state| story_state.decisions.len()).unwrap_or(0);
+                for i in 0..decision_count {
+                    elements.push(FocusId::game_transcript(i + 1));
+                }
+            }
+        }
+        
         // Add choice buttons
         for i in 0..num_choices {
             elements.push(FocusId("choice", i));
@@ -1028,6 +2175,11 @@ impl GameWindow {
         self.focus_state.set_focusable_elements(elements);
     }
     
+    fn current_scene_choice_count(&self) -> Option<usize> {
+        let (crisis, story_state) = (self.current_crisis.as_ref()?, self.story_state.as_ref()?);
+        crisis.scenes.get(&story_state.current_scene).map(|scene| scene.choices.len())
+    }
+    
     // Helper methods to break down large functions
     
     fn validate_new_game_inputs(&self) -> bool {
@@ -1036,7 +2188,7 @@ impl GameWindow {
     
     fn get_character_name_for_new_game(&self, crisis: &crate::crisis::CrisisDefinition) -> String {
         if self.new_game_player_name.is_empty() {
-            crate::crisis::get_random_character_name(crisis, None, &self.settings_language)
+            crate::crisis::get_random_character_name(crisis, None, self.new_game_player_gender, &self.settings_language)
         } else {
             self.new_game_player_name.clone()
         }
@@ -1051,12 +2203,17 @@ impl GameWindow {
         );
         story_state.current_scene = crisis.story.starting_scene.clone();
         story_state.character_name = character_name;
+        story_state.variables = crisis.conditions.initial_variables();
+        story_state.player_gender = self.new_game_player_gender;
+        story_state.pronouns = crate::crisis::PronounSet::parse(&self.new_game_custom_pronouns);
+        story_state.pronouns_language = Some(user_language);
         story_state
     }
     
     fn setup_game_session(&mut self, crisis: crate::crisis::CrisisDefinition, story_state: crate::crisis::GameState) -> Task<GameMessage> {
         self.current_crisis = Some(crisis.clone());
         self.story_state = Some(story_state.clone());
+        self.load_crisis_images();
         self.choice_text_inputs.clear();
         self.animation_frame_index = 0;
 
@@ -1097,6 +2254,16 @@ impl GameWindow {
     
     /// Handle frame rate tick for optimized rendering
     fn handle_frame_rate_tick(&mut self) -> Task<GameMessage> {
+        // Scene transitions animate on this tick, and stop asking for frames once they settle
+        if let Some(ref transition) = self.scene_transition {
+            if transition.is_finished() {
+                self.scene_transition = None;
+                self.view_needs_redraw.mark_game_dirty();
+            } else {
+                self.view_needs_redraw.mark_animation_dirty();
+            }
+        }
+        
         // Check if we should render and if anything needs redrawing
         if self.frame_rate_limiter.should_render() && self.view_needs_redraw.is_any_dirty() {
             // Force a redraw by marking it dirty
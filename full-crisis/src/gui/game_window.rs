@@ -4,6 +4,8 @@ use iced::{Element, Task, Theme, widget};
 impl GameWindow {
     pub fn make_app_settings() -> iced::Settings {
         iced::Settings {
+            fonts: crate::fonts::bundled_fonts(),
+            default_font: iced::Font::MONOSPACE,
             ..Default::default()
         }
     }
@@ -19,25 +21,59 @@ impl GameWindow {
     
     pub fn new() -> (Self, Task<GameMessage>) {
         let loaded_settings = Self::load_settings();
+        let profiles = crate::profile::get_profiles();
+        #[cfg(not(target_arch = "wasm32"))]
+        let crisis_watcher = Self::create_crisis_watcher(&loaded_settings.game_crises_folder);
         let mut window = Self {
             os_theme: crate::OS_COLOR_THEME.get().unwrap_or(&crate::game::OSColorTheme::Light).clone(),
             game_state: crate::game::GameState::new(),
+            settings_profile_name: profiles.active_profile().map(|profile| profile.name.clone()).unwrap_or_default(),
+            profiles,
+            pending_profile_switch: None,
             new_game_player_name: loaded_settings.last_username,
+            new_game_player_gender: loaded_settings.last_player_gender,
+            new_game_custom_pronouns: loaded_settings.last_custom_pronouns,
             new_game_game_template: None,
             new_game_selected_description: None,
+            new_game_fork_status: None,
+            crisis_query: crate::crisis::CrisisQuery::default(),
             continue_game_game_choice: None,
             continue_game_delete_confirmation: None,
+            settings_game_crises_folder_input: loaded_settings.game_crises_folder.clone(),
             settings_game_crises_folder: loaded_settings.game_crises_folder,
             settings_difficulty_level: loaded_settings.difficulty_level,
             settings_autosave: loaded_settings.autosave,
             settings_language: loaded_settings.language,
             settings_font_scale: loaded_settings.font_scale,
+            settings_volume: loaded_settings.volume,
+            settings_reduce_motion: loaded_settings.reduce_motion,
+            settings_theme: loaded_settings.theme,
+            settings_trusted_keys: loaded_settings.trusted_keys.join(", "),
+            settings_require_signed_crises: loaded_settings.require_signed_crises,
             current_crisis: None,
             story_state: None,
+            game_history: crate::crisis::GameHistory::new(),
+            transcript_expanded: false,
+            pause_menu: None,
+            pause_menu_status: None,
+            pause_pending_load: None,
+            unsaved_changes: false,
+            scene_transition: None,
+            play_clock: crate::time::now(),
+            player_stats: crate::crisis::get_player_stats(),
             choice_text_inputs: std::collections::HashMap::new(),
             animation_frame_index: 0,
             current_background_audio: Vec::new(),
             focus_state: FocusState::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            crisis_watcher,
+            crisis_reload_error: None,
+            #[cfg(target_arch = "wasm32")]
+            pack_upload_pending: false,
+            #[cfg(target_arch = "wasm32")]
+            pack_upload_status: None,
+            #[cfg(target_arch = "wasm32")]
+            font_fetch_pending: false,
             
             // Initialize performance optimization fields
             frame_rate_limiter: FrameRateLimiter::default(),
@@ -45,22 +81,45 @@ impl GameWindow {
             cached_views: ViewCache::default(),
         };
         
+        window.apply_volume();
+        
         // Initialize focus for main menu
         window.focus_state.set_focusable_elements(vec![
+            FocusId::menu_button(6), // Profile switcher
             FocusId::menu_button(0), // Continue Game  
             FocusId::menu_button(1), // New Game
             FocusId::menu_button(2), // Settings
+            FocusId::menu_button(5), // Statistics
             FocusId::menu_button(3), // Licenses
             FocusId::menu_button(4), // Quit Game
         ]);
         
+        window.publish_accessibility();
+        #[cfg(target_arch = "wasm32")]
+        window.request_shown_language_fonts();
+        
         (
             window,
             Task::batch([
                 widget::focus_next(),
+                Self::attach_screen_reader(),
             ]),
         )
     }
+    
+    /// Connects the window to the platform's screen reader support once it exists
+    #[cfg(not(target_arch = "wasm32"))]
+    fn attach_screen_reader() -> Task<GameMessage> {
+        iced::window::get_latest()
+            .and_then(|id| iced::window::run_with_handle(id, |handle| crate::accessibility::attach_to_window(handle.as_raw())))
+            .discard()
+    }
+    
+    /// Browsers read the ARIA live regions, which need no window
+    #[cfg(target_arch = "wasm32")]
+    fn attach_screen_reader() -> Task<GameMessage> {
+        Task::none()
+    }
 
     pub fn view(&self) -> Element<'_, GameMessage> {
         #[cfg(not(target_arch = "wasm32"))]
@@ -99,9 +158,29 @@ impl GameWindow {
     }
 
     pub fn theme(&self) -> Theme {
-        match self.os_theme {
-            crate::game::OSColorTheme::Light => Theme::Light,
-            crate::game::OSColorTheme::Dark => Theme::Dark,
+        let dark = match self.os_theme {
+            crate::game::OSColorTheme::Light => false,
+            crate::game::OSColorTheme::Dark => true,
+        };
+        let theme = self.settings_theme.resolve(dark);
+        // High contrast keeps its own accents, which were picked to stay readable
+        if self.settings_theme == ThemeSetting::HighContrast {
+            return theme;
+        }
+        match self.current_crisis.as_ref().and_then(|crisis| crisis.palette.as_ref()) {
+            Some(accents) => crate::gui::with_crisis_palette(theme, accents),
+            None => theme,
+        }
+    }
+
+    /// Color of hints, captions and other text that steps back from the main text: dimmed,
+    /// except in the High Contrast theme, which keeps every text at full strength
+    pub fn secondary_text_color(&self) -> iced::Color {
+        let text = self.theme().extended_palette().background.base.text;
+        if self.settings_theme == ThemeSetting::HighContrast {
+            text
+        } else {
+            iced::Color { a: 0.6, ..text }
         }
     }
 
@@ -132,6 +211,9 @@ impl GameWindow {
                         iced::keyboard::Key::Named(iced::keyboard::key::Named::Enter) => {
                             Some(GameMessage::Focus_Activate)
                         }
+                        iced::keyboard::Key::Named(iced::keyboard::key::Named::Escape) => {
+                            Some(GameMessage::Game_PauseMenuToggled)
+                        }
                         iced::keyboard::Key::Named(iced::keyboard::key::Named::Tab) => {
                             eprintln!("DEBUG: Tab pressed, shift: {}", modifiers.shift());
                             if modifiers.shift() {
@@ -162,6 +244,33 @@ impl GameWindow {
             );
         }
         
+        // Crises folder polling for hot-reload
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.crisis_watcher.is_some() {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_millis(500))
+                    .map(|_| GameMessage::Game_CrisisFilesPoll)
+            );
+        }
+        
+        // Browser pack upload polling
+        #[cfg(target_arch = "wasm32")]
+        if self.pack_upload_pending {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_millis(500))
+                    .map(|_| GameMessage::Menu_PackUploadPoll)
+            );
+        }
+        
+        // Lazy web font polling
+        #[cfg(target_arch = "wasm32")]
+        if self.font_fetch_pending {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_millis(500))
+                    .map(|_| GameMessage::Menu_FontFetchPoll)
+            );
+        }
+        
         // Controller input polling - reduced frequency to 250ms
         subscriptions.push(
             iced::time::every(std::time::Duration::from_millis(250))
@@ -171,21 +280,69 @@ impl GameWindow {
         iced::Subscription::batch(subscriptions)
     }
     
+    /// Starts downloading the fonts the web build left out for the languages on screen. The
+    /// settings screen and pause menu list every language in its own script, so they need all.
+    #[cfg(target_arch = "wasm32")]
+    pub fn request_shown_language_fonts(&mut self) {
+        let mut languages = vec![self.settings_language.clone()];
+        if let Some(ref story_state) = self.story_state {
+            languages.push(story_state.language.clone());
+            languages.extend(story_state.secondary_language.clone());
+        }
+        let lists_languages = self.pause_menu.is_some() || matches!(
+            self.game_state.active_event_loop.read().map(|event_loop| event_loop.clone()),
+            Ok(crate::game::ActiveEventLoop::WelcomeScreen(crate::game::WelcomeScreenView::Settings))
+        );
+        if lists_languages {
+            languages.extend(crate::language::get_available_languages().into_iter().map(|(code, _)| code));
+        }
+        for language in languages {
+            if crate::fonts::request_fonts_for(&language) {
+                self.font_fetch_pending = true;
+            }
+        }
+    }
+    
     #[cfg(not(target_arch = "wasm32"))]
     pub fn get_settings_storage_path() -> String {
-        use directories::ProjectDirs;
-        
-        if let Some(proj_dirs) = ProjectDirs::from("com.jmcateer.full-crisis", "Full-Crisis", "Full-Crisis") {
-            if let Some(cache_dir) = proj_dirs.cache_dir().to_str() {
-                cache_dir.to_string()
+        Self::get_storage_path(crate::storage::StorageKind::Config)
+    }
+    
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn get_saves_storage_path() -> String {
+        Self::get_storage_path(crate::storage::StorageKind::Data)
+    }
+    
+    #[cfg(not(target_arch = "wasm32"))]
+    fn get_storage_path(kind: crate::storage::StorageKind) -> String {
+        if let Some(dir) = crate::storage::storage_dir(kind) {
+            if let Some(dir_str) = dir.to_str() {
+                dir_str.to_string()
             } else {
-                "Unable to get cache directory path".to_string()
+                "Unable to get storage directory path".to_string()
             }
         } else {
             "Unable to get project directories".to_string()
         }
     }
     
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn create_crisis_watcher(crises_folder: &str) -> Option<crate::crisis::CrisisWatcher> {
+        if !std::path::Path::new(crises_folder).is_dir() {
+            return None;
+        }
+        match crate::crisis::CrisisWatcher::new(crises_folder) {
+            Ok(watcher) => Some(watcher),
+            Err(e) => {
+                let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
+                if *verbosity > 0 {
+                    eprintln!("Not watching crises folder '{}': {}", crises_folder, e);
+                }
+                None
+            }
+        }
+    }
+    
     // Font scaling helper functions
     pub fn scaled_font_size(&self, base_size: f32) -> f32 {
         let scaled = base_size * self.settings_font_scale;
@@ -205,6 +362,49 @@ impl GameWindow {
         self.scaled_font_size(28.0)
     }
     
+    // Right-to-left and complex script helper functions
+    /// Language the current screen is laid out in: the story language in game, else the settings language
+    pub fn layout_language(&self) -> &str {
+        match self.story_state {
+            Some(ref story_state) => &story_state.language,
+            None => &self.settings_language,
+        }
+    }
+    
+    pub fn is_rtl(&self, language: &str) -> bool {
+        crate::language::get_text_direction(language).is_rtl()
+    }
+    
+    /// Left for left-to-right languages, right for right-to-left ones
+    pub fn text_alignment(&self, language: &str) -> iced::alignment::Horizontal {
+        if self.is_rtl(language) {
+            iced::alignment::Horizontal::Right
+        } else {
+            iced::alignment::Horizontal::Left
+        }
+    }
+    
+    /// Text shaped and aligned for its language; Arabic, Hebrew and CJK need advanced shaping
+    /// for joined letters, bidi runs and line breaks
+    pub fn localized_text<'a>(&self, content: impl widget::text::IntoFragment<'a>, language: &str) -> widget::Text<'a> {
+        let shaping = if crate::language::needs_advanced_shaping(language) {
+            widget::text::Shaping::Advanced
+        } else {
+            widget::text::Shaping::Basic
+        };
+        widget::text(content)
+            .shaping(shaping)
+            .align_x(self.text_alignment(language))
+    }
+    
+    /// Row whose children are given in reading order, mirrored for right-to-left languages
+    pub fn directional_row<'a>(&self, language: &str, mut children: Vec<Element<'a, GameMessage>>) -> widget::Row<'a, GameMessage> {
+        if self.is_rtl(language) {
+            children.reverse();
+        }
+        widget::Row::with_children(children)
+    }
+    
     pub fn ensure_menu_audio_playing(&self) {
         if let Some(audio_manager) = crate::AUDIO_MANAGER.get() {
             if let Ok(mut manager) = audio_manager.lock() {
@@ -231,6 +431,37 @@ impl GameWindow {
         }
     }
     
+    /// Pushes the volume setting to the menu music, sound effects and scene audio
+    pub fn apply_volume(&self) {
+        if let Some(audio_manager) = crate::AUDIO_MANAGER.get() {
+            if let Ok(mut manager) = audio_manager.lock() {
+                manager.set_volume(self.settings_volume);
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        crate::gui::event_handlers::set_scene_audio_volume(self.settings_volume);
+    }
+    
+    /// Reads the current crisis' images once, so redraws of the game screen reuse the same
+    /// image handles. Called whenever a crisis is loaded or reloaded.
+    pub fn load_crisis_images(&mut self) {
+        self.cached_views.clear_image_cache();
+        let (Some(crisis), Some(story_state)) = (&self.current_crisis, &self.story_state) else {
+            return;
+        };
+        let template_name = story_state.template_name.clone();
+        for path in crisis.image_paths() {
+            if let Some(file) = crate::crisis::PlayableCrises::get_for_crisis(&template_name, &path) {
+                self.cached_views.get_or_load_image(&path, &file.data);
+            }
+        }
+    }
+
+    /// The cached handle of one of the current crisis' images; None when the file does not exist
+    pub fn crisis_image(&self, path: &str) -> Option<iced::widget::image::Handle> {
+        self.cached_views.image_handles.get(path).cloned()
+    }
+    
     /// Check if the current scene has character animations that need to be updated
     pub fn has_character_animation(&self) -> bool {
         if let (Some(crisis), Some(story_state)) = (&self.current_crisis, &self.story_state) {
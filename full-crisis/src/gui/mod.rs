@@ -8,10 +8,11 @@ pub mod game_window;
 pub mod views;
 pub mod styles;
 pub mod helpers;
+pub mod accessibility;
 pub mod builders;
 
 use types::*;
 
 // Re-export key types for public use
-pub use types::{GameWindow, GameMessage, DifficultyLevel, GameSettings};
+pub use types::{GameWindow, GameMessage, DifficultyLevel, GameSettings, ProfileChoice, ThemeSetting};
 pub use styles::*;
\ No newline at end of file
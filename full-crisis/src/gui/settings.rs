@@ -8,18 +8,58 @@ impl GameWindow {
             autosave: self.settings_autosave,
             language: self.settings_language.clone(),
             last_username: self.new_game_player_name.clone(),
+            last_player_gender: self.new_game_player_gender,
+            last_custom_pronouns: self.new_game_custom_pronouns.clone(),
             font_scale: self.settings_font_scale,
+            volume: self.settings_volume,
+            reduce_motion: self.settings_reduce_motion,
+            theme: self.settings_theme.clone(),
+            trusted_keys: self.settings_trusted_keys.split(',')
+                .map(|key| key.trim().to_string())
+                .filter(|key| !key.is_empty())
+                .collect(),
+            require_signed_crises: self.settings_require_signed_crises,
         };
         if let Ok(serialized) = serde_json::to_string(&settings) {
-            crate::internal_storage::set_attr("game_settings", &serialized);
+            crate::storage::set_profile_attr("game_settings", &serialized);
         }
     }
 
+    /// Puts settings loaded from storage into effect, as when switching to another profile
+    pub fn apply_settings(&mut self, settings: GameSettings) {
+        self.settings_game_crises_folder_input = settings.game_crises_folder.clone();
+        self.settings_game_crises_folder = settings.game_crises_folder;
+        self.settings_difficulty_level = settings.difficulty_level;
+        self.settings_autosave = settings.autosave;
+        self.settings_language = settings.language;
+        self.new_game_player_name = settings.last_username;
+        self.new_game_player_gender = settings.last_player_gender;
+        self.new_game_custom_pronouns = settings.last_custom_pronouns;
+        self.settings_font_scale = settings.font_scale;
+        self.settings_volume = settings.volume;
+        self.settings_reduce_motion = settings.reduce_motion;
+        self.settings_theme = settings.theme;
+        self.settings_trusted_keys = settings.trusted_keys.join(", ");
+        self.settings_require_signed_crises = settings.require_signed_crises;
+        
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            crate::crisis::PlayableCrises::set_crises_folder(&self.settings_game_crises_folder);
+            self.crisis_watcher = Self::create_crisis_watcher(&self.settings_game_crises_folder);
+        }
+        // Trusted keys and signature requirements decide which crises are listed
+        crate::crisis::CrisisCatalog::invalidate();
+        self.apply_volume();
+    }
+
+    /// The active profile's settings, with anything the admin policy locks put in its place
     pub fn load_settings() -> GameSettings {
-        if let Some(content) = crate::internal_storage::get_attr("game_settings") {
+        let mut settings: GameSettings = if let Some(content) = crate::storage::get_profile_attr("game_settings") {
             serde_json::from_str(&content).unwrap_or_default()
         } else {
             GameSettings::default()
-        }
+        };
+        crate::policy::AdminPolicy::get().apply(&mut settings);
+        settings
     }
 }
\ No newline at end of file
@@ -1,5 +1,88 @@
 use iced::Theme;
 
+// The high contrast themes take their accents from the Okabe-Ito palette, which stays
+// distinguishable with the common forms of color blindness.
+const OKABE_ITO_YELLOW: iced::Color = iced::Color::from_rgb(0.94, 0.89, 0.26);
+const OKABE_ITO_BLUE: iced::Color = iced::Color::from_rgb(0.0, 0.45, 0.70);
+const OKABE_ITO_VERMILLION: iced::Color = iced::Color::from_rgb(0.84, 0.37, 0.0);
+const OKABE_ITO_BLUISH_GREEN: iced::Color = iced::Color::from_rgb(0.0, 0.62, 0.45);
+const OKABE_ITO_ORANGE: iced::Color = iced::Color::from_rgb(0.90, 0.62, 0.0);
+
+// WCAG's minimum contrast for focus indicators and other non-text marks
+const MIN_RING_CONTRAST: f32 = 3.0;
+
+fn relative_luminance(color: iced::Color) -> f32 {
+    let linear = |channel: f32| {
+        if channel <= 0.04045 { channel / 12.92 } else { ((channel + 0.055) / 1.055).powf(2.4) }
+    };
+    0.2126 * linear(color.r) + 0.7152 * linear(color.g) + 0.0722 * linear(color.b)
+}
+
+fn contrast_ratio(a: iced::Color, b: iced::Color) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// The first accent that stands out from the background, or the text color when none does
+fn ring_color(theme: &Theme, accents: &[iced::Color]) -> iced::Color {
+    let background = theme.extended_palette().background.base;
+    accents.iter()
+        .copied()
+        .find(|accent| contrast_ratio(*accent, background.color) >= MIN_RING_CONTRAST)
+        .unwrap_or(background.text)
+}
+
+/// Outline of the control keyboard or controller navigation is on, in the theme's primary
+/// color; the ring also changes width, so focus never relies on color alone
+pub fn focus_ring_color(theme: &Theme) -> iced::Color {
+    let palette = theme.extended_palette();
+    ring_color(theme, &[palette.primary.base.color, palette.primary.strong.color])
+}
+
+/// Outline of a text input being typed into, set apart from `focus_ring_color`
+pub fn editing_ring_color(theme: &Theme) -> iced::Color {
+    let palette = theme.extended_palette();
+    ring_color(theme, &[palette.success.base.color, palette.success.strong.color, palette.danger.base.color])
+}
+
+/// White on black or black on white, with Okabe-Ito accents, for players who need more contrast
+pub fn high_contrast_theme(dark: bool) -> Theme {
+    let mut palette = if dark { iced::theme::Palette::DARK } else { iced::theme::Palette::LIGHT };
+    if dark {
+        palette.background = iced::Color::BLACK;
+        palette.text = iced::Color::WHITE;
+        palette.primary = OKABE_ITO_YELLOW;
+        palette.success = OKABE_ITO_BLUISH_GREEN;
+        palette.danger = OKABE_ITO_ORANGE;
+    } else {
+        palette.background = iced::Color::WHITE;
+        palette.text = iced::Color::BLACK;
+        palette.primary = OKABE_ITO_BLUE;
+        palette.success = OKABE_ITO_BLUISH_GREEN;
+        palette.danger = OKABE_ITO_VERMILLION;
+    }
+    let name = if dark { "High Contrast Dark" } else { "High Contrast Light" };
+    Theme::custom(name.to_string(), palette)
+}
+
+/// `theme` with a crisis' `[palette]` accents in place of its own; colors that do not parse are left alone
+pub fn with_crisis_palette(theme: Theme, accents: &crate::crisis::CrisisPalette) -> Theme {
+    let parse = |hex: &Option<String>| hex.as_deref()
+        .and_then(crate::crisis::parse_hex_color)
+        .map(|[r, g, b]| iced::Color::from_rgb8(r, g, b));
+    let mut palette = theme.palette();
+    if let Some(primary) = parse(&accents.primary) {
+        palette.primary = primary;
+    }
+    if let Some(success) = parse(&accents.success) {
+        palette.success = success;
+    }
+    if let Some(danger) = parse(&accents.danger) {
+        palette.danger = danger;
+    }
+    Theme::custom(theme.to_string(), palette)
+}
+
 pub fn menu_right_box_style(theme: &Theme) -> iced::widget::container::Style {
     let palette = theme.extended_palette();
 
@@ -44,10 +127,11 @@ pub fn focused_button_style(is_focused: bool) -> impl Fn(&Theme, iced::widget::b
             },
         };
 
-        // Add focus outline without affecting layout
+        // Add focus outline without affecting layout. The border is drawn over the button's
+        // fill, which is the primary color, so it takes the label color instead of the ring color.
         if is_focused {
             style.border = iced::border::rounded(4)
-                .color(iced::Color::from_rgb(1.0, 1.0, 1.0)) // Bright white focus outline
+                .color(style.text_color)
                 .width(3);
         }
 
@@ -55,6 +139,55 @@ pub fn focused_button_style(is_focused: bool) -> impl Fn(&Theme, iced::widget::b
     }
 }
 
+/// Card in the crisis browser: the selected crisis is filled with the primary color, the focused one gets the focus ring
+pub fn crisis_card_style(is_selected: bool, is_focused: bool) -> impl Fn(&Theme, iced::widget::button::Status) -> iced::widget::button::Style {
+    move |theme: &Theme, status: iced::widget::button::Status| {
+        let palette = theme.extended_palette();
+        let fill = match status {
+            _ if is_selected => palette.primary.weak,
+            iced::widget::button::Status::Hovered | iced::widget::button::Status::Pressed => palette.background.strong,
+            _ => palette.background.base,
+        };
+        let border = if is_focused {
+            iced::border::rounded(8)
+                .color(focus_ring_color(theme))
+                .width(3)
+        } else {
+            iced::border::rounded(8)
+                .color(if is_selected { palette.primary.base.color } else { palette.background.strong.color })
+                .width(1)
+        };
+        iced::widget::button::Style {
+            background: Some(fill.color.into()),
+            text_color: fill.text,
+            border,
+            ..iced::widget::button::Style::default()
+        }
+    }
+}
+
+/// A Stats screen panel, framed like an unselected crisis card
+pub fn stats_panel_style(is_focused: bool) -> impl Fn(&Theme) -> iced::widget::container::Style {
+    move |theme: &Theme| {
+        let palette = theme.extended_palette();
+        let border = if is_focused {
+            iced::border::rounded(8)
+                .color(focus_ring_color(theme))
+                .width(3)
+        } else {
+            iced::border::rounded(8)
+                .color(palette.background.strong.color)
+                .width(1)
+        };
+        iced::widget::container::Style {
+            background: Some(palette.background.base.color.into()),
+            text_color: Some(palette.background.base.text),
+            border,
+            ..iced::widget::container::Style::default()
+        }
+    }
+}
+
 pub fn focused_text_input_style(is_focused: bool, is_actively_focused: bool) -> impl Fn(&Theme, iced::widget::text_input::Status) -> iced::widget::text_input::Style {
     move |theme: &Theme, status: iced::widget::text_input::Status| {
         let palette = theme.extended_palette();
@@ -104,15 +237,15 @@ pub fn focused_text_input_style(is_focused: bool, is_actively_focused: bool) ->
         // Add focus outline
         if is_focused {
             if is_actively_focused {
-                // Active input focus - bright blue with thicker border
+                // Active input focus - a second color with a thicker border
                 style.border = iced::border::rounded(4)
-                    .color(iced::Color::from_rgb(0.2, 0.6, 1.0))
+                    .color(editing_ring_color(theme))
                     .width(4);
                 style.background = palette.primary.weak.color.into();
             } else {
-                // Navigation focus - white outline
+                // Navigation focus
                 style.border = iced::border::rounded(4)
-                    .color(iced::Color::from_rgb(1.0, 1.0, 1.0))
+                    .color(focus_ring_color(theme))
                     .width(3);
             }
         }
@@ -157,7 +290,7 @@ pub fn focused_pick_list_style(is_focused: bool) -> impl Fn(&Theme, iced::widget
         // Add focus outline
         if is_focused {
             style.border = iced::border::rounded(4)
-                .color(iced::Color::from_rgb(1.0, 1.0, 1.0))
+                .color(focus_ring_color(theme))
                 .width(3);
         }
         
@@ -206,7 +339,7 @@ pub fn focused_toggler_style(is_focused: bool) -> impl Fn(&Theme, iced::widget::
         // Add focus outline
         if is_focused {
             style.background_border_width = 3.0;
-            style.background_border_color = iced::Color::from_rgb(1.0, 1.0, 1.0);
+            style.background_border_color = focus_ring_color(theme);
         }
         
         style
@@ -261,7 +394,7 @@ pub fn focused_slider_style(is_focused: bool) -> impl Fn(&Theme, iced::widget::s
         // Add focus indication by making handle more prominent
         if is_focused {
             style.handle.shape = iced::widget::slider::HandleShape::Circle { radius: 10.0 };
-            style.handle.border_color = iced::Color::from_rgb(1.0, 1.0, 1.0);
+            style.handle.border_color = focus_ring_color(theme);
             style.handle.border_width = 3.0;
         }
         
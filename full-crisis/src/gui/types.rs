@@ -2,10 +2,14 @@ use serde::{Serialize, Deserialize};
 use crate::gui::helpers::TranslationUtils;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum DifficultyLevel {
+    // Crisis metadata writes these in lowercase
+    #[serde(alias = "easy")]
     Easy,
+    #[serde(alias = "medium")]
     Medium,
+    #[serde(alias = "hard")]
     Hard,
 }
 
@@ -34,6 +38,67 @@ impl DifficultyLevel {
     ];
 }
 
+/// An entry of the main menu's profile switcher
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileChoice {
+    pub profile_id: Option<String>, // None creates a new profile
+    pub label: String,
+}
+
+impl std::fmt::Display for ProfileChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+/// Look of the game, chosen on the settings screen
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ThemeSetting {
+    #[default]
+    System, // Light or dark, following the operating system
+    Light,
+    Dark,
+    HighContrast, // Light or dark following the operating system, with stronger colors
+    Builtin(String), // One of iced's other themes by name, e.g. "Dracula"
+}
+
+impl ThemeSetting {
+    /// Every choice, with iced's themes after the game's own
+    pub fn all() -> Vec<ThemeSetting> {
+        let mut settings = vec![ThemeSetting::System, ThemeSetting::Light, ThemeSetting::Dark, ThemeSetting::HighContrast];
+        settings.extend(iced::Theme::ALL.iter()
+            .filter(|theme| !matches!(theme, iced::Theme::Light | iced::Theme::Dark))
+            .map(|theme| ThemeSetting::Builtin(theme.to_string())));
+        settings
+    }
+
+    pub fn to_translated_string(&self, language: &str) -> String {
+        match self {
+            ThemeSetting::System => TranslationUtils::translate(crate::translations::TranslationKey::ThemeSystem, language),
+            ThemeSetting::Light => TranslationUtils::translate(crate::translations::TranslationKey::ThemeLight, language),
+            ThemeSetting::Dark => TranslationUtils::translate(crate::translations::TranslationKey::ThemeDark, language),
+            ThemeSetting::HighContrast => TranslationUtils::translate(crate::translations::TranslationKey::ThemeHighContrast, language),
+            ThemeSetting::Builtin(name) => name.clone(),
+        }
+    }
+
+    /// The iced theme to draw with; `dark` is the operating system's preference
+    pub fn resolve(&self, dark: bool) -> iced::Theme {
+        match self {
+            ThemeSetting::System if dark => iced::Theme::Dark,
+            ThemeSetting::System => iced::Theme::Light,
+            ThemeSetting::Light => iced::Theme::Light,
+            ThemeSetting::Dark => iced::Theme::Dark,
+            ThemeSetting::HighContrast => crate::gui::high_contrast_theme(dark),
+            ThemeSetting::Builtin(name) => iced::Theme::ALL.iter()
+                .find(|theme| theme.to_string() == *name)
+                .cloned()
+                // A theme from a newer iced that this build does not have
+                .unwrap_or_else(|| ThemeSetting::System.resolve(dark)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameSettings {
     pub game_crises_folder: String,
@@ -41,7 +106,21 @@ pub struct GameSettings {
     pub autosave: bool,
     pub language: String,
     pub last_username: String,
+    #[serde(default)]
+    pub last_player_gender: crate::crisis::PlayerGender,
+    #[serde(default)]
+    pub last_custom_pronouns: String, // As typed, e.g. "xe/xem/xyr/xyrs/xemself"; empty for the gender's usual set
     pub font_scale: f32,
+    #[serde(default = "default_volume")]
+    pub volume: f32, // 0.0 - 1.0, scales music and sound effects
+    #[serde(default)]
+    pub reduce_motion: bool, // No typewriter text or scene fades
+    #[serde(default)]
+    pub theme: ThemeSetting,
+    #[serde(default)]
+    pub trusted_keys: Vec<String>, // Hex ed25519 public keys whose crisis signatures count as verified
+    #[serde(default)]
+    pub require_signed_crises: bool, // Refuse crises that are neither built in nor signed by a trusted key
 }
 
 impl Default for GameSettings {
@@ -52,30 +131,135 @@ impl Default for GameSettings {
             autosave: true,
             language: crate::language::detect_system_language(),
             last_username: String::new(),
+            last_player_gender: crate::crisis::PlayerGender::default(),
+            last_custom_pronouns: String::new(),
             font_scale: 1.0,
+            volume: default_volume(),
+            reduce_motion: false,
+            theme: ThemeSetting::default(),
+            trusted_keys: Vec::new(),
+            require_signed_crises: false,
+        }
+    }
+}
+
+fn default_volume() -> f32 {
+    1.0
+}
+
+/// Page of the in-game pause menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseMenuView {
+    Main,
+    Settings,
+    Load,
+    ConfirmQuit, // Shown when quitting would lose progress
+    ConfirmLoad, // Shown when loading `pause_pending_load` would lose progress
+}
+
+/// Progress of the current scene's `SceneTransition`, advanced by `FrameRate_Tick`
+#[derive(Debug, Clone)]
+pub struct SceneTransitionState {
+    pub started: crate::time::PlatformInstant,
+    pub transition: crate::crisis::SceneTransition,
+    pub previous_background: Option<String>, // Background of the scene being left; None fades in from black
+    pub text_length: usize, // Characters in the longest scene text on screen
+    pub text_skipped: bool,
+}
+
+impl SceneTransitionState {
+    pub fn new(transition: crate::crisis::SceneTransition, previous_background: Option<String>, text_length: usize) -> Self {
+        Self {
+            started: crate::time::now(),
+            transition,
+            previous_background,
+            text_length,
+            text_skipped: false,
+        }
+    }
+    
+    /// 0.0 when the scene change starts, 1.0 once the new background is fully shown
+    pub fn background_progress(&self) -> f32 {
+        if self.transition.background == crate::crisis::BackgroundTransition::Cut || self.transition.duration_ms == 0 {
+            return 1.0;
         }
+        (self.started.elapsed().as_millis() as f32 / self.transition.duration_ms as f32).min(1.0)
+    }
+    
+    /// How many characters of the scene text to show; None once all of it is shown
+    pub fn visible_characters(&self) -> Option<usize> {
+        if self.text_skipped || self.transition.text == crate::crisis::TextReveal::Instant {
+            return None;
+        }
+        // Text starts once the background has settled, so a crossfade is not read through
+        let background_ms = match self.transition.background {
+            crate::crisis::BackgroundTransition::Cut => 0,
+            _ => self.transition.duration_ms,
+        };
+        let elapsed_ms = (self.started.elapsed().as_millis() as u64).saturating_sub(background_ms);
+        let visible = (elapsed_ms as f32 / 1000.0 * self.transition.characters_per_second.max(1.0)) as usize;
+        if visible >= self.text_length { None } else { Some(visible) }
+    }
+    
+    pub fn is_text_revealing(&self) -> bool {
+        self.visible_characters().is_some()
+    }
+    
+    pub fn is_finished(&self) -> bool {
+        self.background_progress() >= 1.0 && !self.is_text_revealing()
     }
 }
 
 pub struct GameWindow {
     pub os_theme: crate::game::OSColorTheme,
     pub game_state: crate::game::GameState,
+    pub profiles: crate::profile::ProfileList, // Every local profile; settings, saves and stats belong to the active one
+    pub pending_profile_switch: Option<ProfileChoice>, // Picked in the switcher, waiting for Switch or Cancel
+    pub settings_profile_name: String, // Active profile's name as typed into settings
     pub new_game_player_name: String,
+    pub new_game_player_gender: crate::crisis::PlayerGender,
+    pub new_game_custom_pronouns: String,
     pub new_game_game_template: Option<String>,
     pub new_game_selected_description: Option<String>,
+    pub new_game_fork_status: Option<String>, // Result of the last "fork to crises folder"
+    pub crisis_query: crate::crisis::CrisisQuery, // Search, filters and sort of the crisis browser
     pub continue_game_game_choice: Option<String>,
     pub continue_game_delete_confirmation: Option<String>,
-    pub settings_game_crises_folder: String,
+    pub settings_game_crises_folder: String, // Folder in effect, loaded from, watched and saved
+    pub settings_game_crises_folder_input: String, // As typed; applied on Enter or when leaving settings
     pub settings_difficulty_level: DifficultyLevel,
     pub settings_autosave: bool,
     pub settings_language: String,
     pub settings_font_scale: f32,
+    pub settings_volume: f32,
+    pub settings_reduce_motion: bool,
+    pub settings_theme: ThemeSetting,
+    pub settings_trusted_keys: String, // Comma separated, as typed into settings
+    pub settings_require_signed_crises: bool,
     pub current_crisis: Option<crate::crisis::CrisisDefinition>,
     pub story_state: Option<crate::crisis::GameState>,
+    pub game_history: crate::crisis::GameHistory, // Snapshots taken before each decision, for rewind
+    pub transcript_expanded: bool, // Decision log panel on the game screen is open
+    pub pause_menu: Option<PauseMenuView>, // None while the game is running
+    pub pause_menu_status: Option<String>, // Result of the last save or load made from the pause menu
+    pub pause_pending_load: Option<String>, // Saved game waiting on the player to confirm discarding progress
+    pub unsaved_changes: bool, // A decision was made since the game was started, loaded or saved
+    pub scene_transition: Option<SceneTransitionState>, // The scene on screen is still fading in or revealing its text
+    pub play_clock: crate::time::PlatformInstant, // Play time since it was last added to the story state
+    pub player_stats: crate::crisis::PlayerStats, // Runs, endings and discovered scenes per crisis
     pub choice_text_inputs: std::collections::HashMap<usize, String>, // Track text input values by choice index
     pub animation_frame_index: usize, // Current frame index for character animation
     pub current_background_audio: Vec<u8>, // Current background audio data to play
     pub focus_state: FocusState, // Focus tracking state
+    #[cfg(not(target_arch = "wasm32"))]
+    pub crisis_watcher: Option<crate::crisis::CrisisWatcher>, // Watches the crises folder for edits
+    pub crisis_reload_error: Option<String>, // Shown over the game when a hot-reload fails
+    #[cfg(target_arch = "wasm32")]
+    pub pack_upload_pending: bool, // Waiting on the browser to read an uploaded .fcpack
+    #[cfg(target_arch = "wasm32")]
+    pub pack_upload_status: Option<String>, // Result of the last pack upload, shown in settings
+    #[cfg(target_arch = "wasm32")]
+    pub font_fetch_pending: bool, // Waiting on the browser to download fonts the wasm file left out
     
     // Performance optimization fields
     pub frame_rate_limiter: FrameRateLimiter,
@@ -90,8 +274,15 @@ pub enum GameMessage {
     // Menu actions
     Menu_NewGameRequested,
     Menu_NewGamePlayerNameAltered(String),
-    Menu_NewGameTemplateChoiceAltered(String),
+    Menu_NewGamePlayerGenderChanged(crate::crisis::PlayerGender),
+    Menu_NewGameCustomPronounsAltered(String),
+    Menu_NewGameTemplateChoiceAltered(String), // Template name of the crisis picked in the browser
+    Menu_NewGameSearchChanged(String),
+    Menu_NewGameLanguageFilterChanged(Option<String>),
+    Menu_NewGameTagFilterChanged(Option<String>),
+    Menu_NewGameSortChanged(crate::crisis::CrisisSort),
     Menu_NewGameStartClicked,
+    Menu_NewGameForkRequested, // Copy the selected crisis into the crises folder for editing
     
     Menu_ContinueGameRequested,
     Menu_ContinueGameChoiceAltered(String),
@@ -101,23 +292,56 @@ pub enum GameMessage {
     
     Menu_SettingsRequested,
     Menu_SettingsGameCrisesFolderChanged(String),
+    Menu_SettingsGameCrisesFolderSubmitted, // Enter pressed in, or focus moved off, the crises folder input
     Menu_SettingsOpenCrisesFolder,
     Menu_SettingsDifficultyLevelChanged(DifficultyLevel),
     Menu_SettingsAutosaveToggled(bool),
     Menu_SettingsLanguageChanged(String),
     Menu_SettingsFontScaleChanged(f32),
-    
+    Menu_SettingsVolumeChanged(f32),
+    Menu_SettingsReduceMotionToggled(bool),
+    Menu_SettingsThemeChanged(ThemeSetting),
+    Menu_SettingsTrustedKeysChanged(String),
+    Menu_SettingsTrustedKeysSubmitted, // Enter pressed in, or focus moved off, the trusted keys input
+    Menu_SettingsRequireSignedToggled(bool),
+    Menu_SettingsUploadPackRequested,
+    Menu_PackUploadPoll, // Timer message to check for a finished pack upload
+    Menu_FontFetchPoll, // Timer message to check for a downloaded lazy font
+    Menu_FontLoaded(Result<(), iced::font::Error>),
+    
+    Menu_StatsRequested,
+    Menu_ProfileSelected(ProfileChoice),
+    Menu_ProfileSwitchConfirmed,
+    Menu_ProfileSwitchCancelled,
+    Menu_SettingsProfileNameChanged(String),
+    Menu_SettingsProfileNameSubmitted, // Enter pressed in, or focus moved off, the profile name input
     Menu_LicensesRequested,
     QuitGameRequested,
+    StartButtonPressed, // Controller Start: pauses a running game, else opens settings
     
     // Game actions
     Game_ChoiceSelected(usize),
     Game_TextInputChanged(usize, String), // (choice_index, input_value)
     Game_TextInputSubmitted(usize, String), // (choice_index, input_value)
     Game_RestartRequested,
+    Game_RestartCrisisRequested, // Start the current crisis over from its starting scene
+    Game_RewindRequested,
+    Game_TimelineJumpRequested(usize), // index into the game history
     Game_SaveAndQuitRequested,
     Game_QuitWithoutSaveRequested,
     Game_AnimationTick, // Timer message for character animation
+    Game_CrisisFilesPoll, // Timer message to check the crises folder for edits
+    Game_ReloadErrorDismissed,
+    Game_TranscriptToggled, // Open or close the decision log
+    Game_PauseMenuToggled, // Escape: opens the pause menu, or steps back out of it
+    Game_PauseMenuViewChanged(PauseMenuView),
+    Game_PauseSaveRequested,
+    Game_PauseLoadRequested(String), // display name of the saved game
+    Game_PauseLoadConfirmed, // Load `pause_pending_load`, dropping unsaved progress
+    Game_PauseQuitRequested, // Quits, asking first if there is unsaved progress
+    Game_LanguageChanged(String), // Switch the running story to another language
+    Game_SecondaryLanguageChanged(Option<String>), // Language shown side by side with the story's, None for one language
+    Game_TextRevealSkipped, // Show the rest of the scene text at once
     
     // Focus system messages
     Focus_NavigateUp,
@@ -135,12 +359,16 @@ pub enum GameMessage {
     FrameRate_Tick, // Timer message for frame rate limiting
 }
 
+/// Scrollable ids, so focus changes can scroll the focused row into view
+pub const TRANSCRIPT_SCROLLABLE: &str = "transcript";
+pub const CRISIS_BROWSER_SCROLLABLE: &str = "crisis_browser";
+
 // Focus system types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct FocusId(pub &'static str, pub usize); // (category, index)
 
 impl FocusId {
-    // Left panel - menu buttons
+    // Left panel - menu buttons, the profile switcher at index 6 and its Switch/Cancel confirmation at 7 and 8
     pub const fn menu_button(index: usize) -> Self {
         Self("menu", index)
     }
@@ -154,6 +382,16 @@ impl FocusId {
         Self("newgame_button", index)
     }
     
+    // Cards of the crisis browser, by position in the filtered list
+    pub const fn crisis_card(index: usize) -> Self {
+        Self("crisis_card", index)
+    }
+    
+    // Panels of the Stats screen: 0 the totals, then one per crisis
+    pub const fn stats_row(index: usize) -> Self {
+        Self("stats_row", index)
+    }
+    
     // Right panel - continue game elements  
     pub const fn continue_game_input(index: usize) -> Self {
         Self("continue_input", index)
@@ -197,6 +435,31 @@ impl FocusId {
     pub const fn game_control(index: usize) -> Self {
         Self("control", index)
     }
+    
+    pub const fn game_timeline(index: usize) -> Self {
+        Self("timeline", index)
+    }
+    
+    // Index 0 opens and closes the decision log, 1.. are its entries, newest first
+    pub const fn game_transcript(index: usize) -> Self {
+        Self("transcript", index)
+    }
+    
+    // Story language picker (0) and the side-by-side language picker (1)
+    pub const fn game_language(index: usize) -> Self {
+        Self("game_language", index)
+    }
+    
+    // Pause menu buttons: 0 resume, 1 save, 2 load, 3 settings, 4 restart, 5 quit,
+    // 6 back, 7 save & quit and 8 quit without saving
+    pub const fn pause_button(index: usize) -> Self {
+        Self("pause", index)
+    }
+    
+    // Saved games listed on the pause menu's load page
+    pub const fn pause_load(index: usize) -> Self {
+        Self("pause_load", index)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -359,34 +622,40 @@ impl FocusState {
     pub fn handle_tab_interact(&mut self, reverse: bool) -> TabInteractionResult {
         if let Some(current) = self.current_focus {
             match current.0 {
-                // New game template picker (only index 1 is the pick_list) - handle this FIRST
-                "newgame_input" if current.1 == 1 => {
+                // New game pronoun picker and the crisis browser's filter and sort pickers - handle this FIRST
+                "newgame_input" if current.1 == 2 || current.1 >= 4 => {
                     let current_index = self.pick_list_selection_index.get(&current).copied().unwrap_or(0);
                     TabInteractionResult::PickListCycle(current, current_index, reverse)
                 }
                 
-                // Text inputs - toggle focus state (including newgame_input index 0)
+                // Text inputs - toggle focus state (newgame_input 0, 1 and 3)
                 "newgame_input" | "settings_input" => {
                     let currently_focused = self.text_input_focused.get(&current).copied().unwrap_or(false);
                     self.text_input_focused.insert(current, !currently_focused);
                     TabInteractionResult::TextInputToggled(current, !currently_focused)
                 }
                 
-                // Pick lists - cycle through options
-                "continue_input" | "settings_picker" => {
+                // Pick lists - cycle through options (the profile switcher sits among the menu buttons)
+                "menu" if current.1 == 6 => {
+                    let current_index = self.pick_list_selection_index.get(&current).copied().unwrap_or(0);
+                    TabInteractionResult::PickListCycle(current, current_index, reverse)
+                }
+                "continue_input" | "settings_picker" | "game_language" => {
                     let current_index = self.pick_list_selection_index.get(&current).copied().unwrap_or(0);
                     TabInteractionResult::PickListCycle(current, current_index, reverse)
                 }
                 
-                // Sliders - increment/decrement by 10%
+                // Sliders - increment/decrement by 0.1
                 "settings_slider" => {
+                    // Font scale (index 0) runs 0.1 - 2.0, volume (index 1) 0.0 - 1.0
+                    let (min, max) = if current.1 == 1 { (0.0, 1.0) } else { (0.1, 2.0) };
                     let current_val = self.slider_values.get(&current).copied().unwrap_or(0.5);
                     let new_val = if reverse {
-                        // Shift+Tab: decrement by 10%, rollover from minimum (0.1) to maximum (2.0)
-                        if current_val <= 0.15 { 2.0 } else { (current_val - 0.1).max(0.1) }
+                        // Shift+Tab: decrement, rollover from minimum to maximum
+                        if current_val <= min + 0.05 { max } else { (current_val - 0.1).max(min) }
                     } else {
-                        // Tab: increment by 10%, rollover from maximum (2.0) to minimum (0.1)
-                        if current_val >= 1.95 { 0.1 } else { (current_val + 0.1).min(2.0) }
+                        // Tab: increment, rollover from maximum to minimum
+                        if current_val >= max - 0.05 { min } else { (current_val + 0.1).min(max) }
                     };
                     self.slider_values.insert(current, new_val);
                     TabInteractionResult::SliderChanged(current, new_val)
@@ -532,7 +801,25 @@ pub struct ViewCache {
     pub last_menu_state: Option<String>, // Simplified state hash for menu
     pub last_game_state: Option<String>, // Simplified state hash for game
     pub last_settings_state: Option<String>, // Simplified state hash for settings
-    pub image_handles: std::collections::HashMap<String, iced::widget::image::Handle>,
+    pub image_handles: std::collections::HashMap<String, iced::widget::image::Handle>, // Covers on the New Game screen, the current crisis' images in a game
+    pub crisis_browser: Option<BrowseResults>, // Matches of the crisis browser's query
+    pub timeline_labels: Option<TimelineLabels>, // Button labels of the game's timeline
+}
+
+/// The crisis browser's matches, kept until the query, the UI language or the catalog changes
+pub struct BrowseResults {
+    pub catalog: std::sync::Arc<crate::crisis::CrisisCatalog>, // The catalog they came from; a rebuilt one is a different Arc
+    pub query: crate::crisis::CrisisQuery,
+    pub language: String,
+    pub entries: Vec<crate::crisis::CrisisCatalogEntry>,
+}
+
+/// Timeline button labels, one per history entry they were made from; kept while the
+/// entries and the story language stay the same
+pub struct TimelineLabels {
+    pub language: String,
+    pub entries: Vec<crate::crisis::HistoryEntry>,
+    pub labels: Vec<String>,
 }
 
 impl ViewCache {
@@ -542,6 +829,8 @@ impl ViewCache {
             last_game_state: None,
             last_settings_state: None,
             image_handles: std::collections::HashMap::new(),
+            crisis_browser: None,
+            timeline_labels: None,
         }
     }
     
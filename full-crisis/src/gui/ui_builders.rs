@@ -1,9 +1,9 @@
 use crate::gui::types::*;
 use crate::gui::helpers::TranslationUtils;
 use iced::widget::{
-    Button, Container, Text, button, center_x, column, pick_list, row, text, text_input, toggler, slider, scrollable,
+    Button, Container, Text, button, center_x, column, pick_list, text, text_input, toggler, slider, scrollable,
 };
-use iced::{Center, Element, Length, Left, Theme};
+use iced::{Center, Element, Length, Theme};
 
 pub const MAIN_MENU_RIGHT_WIDTH_PX: f32 = 880.0;
 pub const MAIN_MENU_HEIGHT_PX: f32 = 640.0;
@@ -22,6 +22,9 @@ impl GameWindow {
                     crate::game::WelcomeScreenView::Settings => {
                         self.build_settings_ui()
                     }
+                    crate::game::WelcomeScreenView::Stats => {
+                        self.build_stats_ui()
+                    }
                     crate::game::WelcomeScreenView::Licenses => {
                         self.build_licenses_ui()
                     }
@@ -59,21 +62,21 @@ impl GameWindow {
         .width(Length::Fill)
         .style(crate::gui::focused_pick_list_style(self.focus_state.is_focused(FocusId::continue_game_input(0))));
 
-        let game_type_row = row![
-            Text::new(TranslationUtils::translate(crate::translations::TranslationKey::SavedGame, user_language)).size(self.font_size_base()),
-            game_type_picker,
-        ]
+        let game_type_row = self.directional_row(user_language, vec![
+            self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::SavedGame, user_language), user_language).size(self.font_size_base()).into(),
+            game_type_picker.into(),
+        ])
             .spacing(10)
             .align_y(Center);
 
-        let go_button = button(Text::new(crate::translations::t(crate::translations::TranslationKey::Play, user_language)).size(self.font_size_base()))
+        let go_button = button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::Play, user_language), user_language).size(self.font_size_base()))
             .on_press(GameMessage::Menu_ContinueGameStartClicked)
             .padding(10)
             .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::continue_game_button(0))));
 
         let delete_button = if self.continue_game_game_choice.is_some() {
             let is_focused = self.focus_state.is_focused(FocusId::continue_game_button(1));
-            button(Text::new(crate::translations::t(crate::translations::TranslationKey::Delete, user_language)).size(self.font_size_base()))
+            button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::Delete, user_language), user_language).size(self.font_size_base()))
                 .on_press(GameMessage::Menu_ContinueGameDeleteRequested(
                     self.continue_game_game_choice.clone().unwrap_or_default()
                 ))
@@ -98,7 +101,7 @@ impl GameWindow {
                     style
                 })
         } else {
-            button(Text::new(crate::translations::t(crate::translations::TranslationKey::Delete, user_language)).size(self.font_size_base()))
+            button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::Delete, user_language), user_language).size(self.font_size_base()))
                 .padding(10)
                 .style(move |theme: &Theme, _status| {
                     let palette = theme.extended_palette();
@@ -113,7 +116,7 @@ impl GameWindow {
                 })
         };
 
-        let button_row = row![delete_button, iced::widget::horizontal_space(), go_button]
+        let button_row = self.directional_row(user_language, vec![delete_button.into(), iced::widget::horizontal_space().into(), go_button.into()])
             .spacing(10)
             .align_y(Center);
 
@@ -124,13 +127,13 @@ impl GameWindow {
 
         // Add confirmation dialog if delete is requested
         if let Some(ref game_name) = self.continue_game_delete_confirmation {
-            let confirmation_text = Text::new(crate::translations::t(crate::translations::TranslationKey::DeleteGame, user_language)).size(self.font_size_base());
+            let confirmation_text = self.localized_text(crate::translations::t(crate::translations::TranslationKey::DeleteGame, user_language), user_language).size(self.font_size_base());
             let game_info_text = Text::new(format!("\"{}\"", game_name))
                 .size(self.font_size_base())
-                .color(iced::Color::from_rgb(0.6, 0.6, 0.6));
+                .color(self.secondary_text_color());
             
             let is_confirm_focused = self.focus_state.is_focused(FocusId::continue_game_confirm(0));
-            let confirm_button = button(Text::new(crate::translations::t(crate::translations::TranslationKey::ConfirmDelete, user_language)).size(self.font_size_base()))
+            let confirm_button = button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::ConfirmDelete, user_language), user_language).size(self.font_size_base()))
                 .on_press(GameMessage::Menu_ContinueGameDeleteConfirmed(game_name.clone()))
                 .padding(10)
                 .style(move |theme: &Theme, status| {
@@ -146,22 +149,22 @@ impl GameWindow {
                         ..iced::widget::button::Style::default()
                     };
                     
-                    // Add focus outline
+                    // Add focus outline, in the label color so it shows on the danger fill
                     if is_confirm_focused {
                         style.border = iced::border::rounded(4)
-                            .color(iced::Color::from_rgb(1.0, 1.0, 1.0))
+                            .color(style.text_color)
                             .width(3);
                     }
                     
                     style
                 });
             
-            let cancel_button = button(Text::new(crate::translations::t(crate::translations::TranslationKey::Cancel, user_language)).size(self.font_size_base()))
+            let cancel_button = button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::Cancel, user_language), user_language).size(self.font_size_base()))
                 .on_press(GameMessage::Menu_ContinueGameDeleteRequested("".to_string())) // Cancel by clearing
                 .padding(10)
                 .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::continue_game_confirm(1))));
             
-            let confirmation_buttons = row![confirm_button, cancel_button]
+            let confirmation_buttons = self.directional_row(user_language, vec![confirm_button.into(), cancel_button.into()])
                 .spacing(10)
                 .align_y(Center);
                 
@@ -194,7 +197,7 @@ impl GameWindow {
 
         layout = layout.push(button_row)
             .height(Length::Fill)
-            .align_x(Left);
+            .align_x(self.text_alignment(user_language));
 
         let self_theme = self.theme();
         Container::<GameMessage, Theme, iced::Renderer>::new(layout)
@@ -219,59 +222,71 @@ impl GameWindow {
                 self.focus_state.is_text_input_focused(FocusId::new_game_input(0))
             ));
 
-        let name_row = row![
-                Text::new(crate::translations::t(crate::translations::TranslationKey::PlayerName, user_language)).size(self.font_size_base()),
-                name_input
-            ]
+        let name_row = self.directional_row(user_language, vec![
+                self.localized_text(crate::translations::t(crate::translations::TranslationKey::PlayerName, user_language), user_language).size(self.font_size_base()).into(),
+                name_input.into()
+            ])
             .spacing(10)
             .align_y(Center);
 
-        let crisis_names = crate::crisis::get_crisis_names_localized(user_language);
-        let selected_display_name = self.new_game_game_template.as_ref()
-            .and_then(|template_name| {
-                // Find the display name that matches this template
-                for display_name in &crisis_names {
-                    let found_template = crate::crisis::get_template_name_from_display_name(display_name);
-                    if found_template == *template_name {
-                        return Some(display_name.clone());
-                    }
-                }
-                None
-            });
-        let game_type_picker = pick_list(
-            crisis_names,
-            selected_display_name,
-            GameMessage::Menu_NewGameTemplateChoiceAltered,
+        // Picks gendered character names and the pronouns scene text uses for the player
+        let gender_options: Vec<String> = crate::crisis::PlayerGender::ALL.iter()
+            .map(|gender| gender.to_translated_string(user_language))
+            .collect();
+        let gender_picker = pick_list(
+            gender_options,
+            Some(self.new_game_player_gender.to_translated_string(user_language)),
+            |selected| {
+                let gender = crate::crisis::PlayerGender::ALL.into_iter()
+                    .find(|gender| gender.to_translated_string(user_language) == selected)
+                    .unwrap_or_default();
+                GameMessage::Menu_NewGamePlayerGenderChanged(gender)
+            },
         )
-        .placeholder(crate::translations::t(crate::translations::TranslationKey::SelectGameType, user_language))
         .padding(10)
         .width(Length::Fill)
-        .style(crate::gui::focused_pick_list_style(self.focus_state.is_focused(FocusId::new_game_input(1))));
+        .style(crate::gui::focused_pick_list_style(self.focus_state.is_focused(FocusId::new_game_input(2))));
+
+        // Overrides the picked set in scene text; names still follow the picker
+        let custom_pronouns_input = text_input(
+            &crate::translations::t(crate::translations::TranslationKey::CustomPronouns, user_language),
+            &self.new_game_custom_pronouns
+        )
+            .on_input(GameMessage::Menu_NewGameCustomPronounsAltered)
+            .padding(10)
+            .width(Length::Fill)
+            .style(crate::gui::focused_text_input_style(
+                self.focus_state.is_focused(FocusId::new_game_input(3)),
+                self.focus_state.is_text_input_focused(FocusId::new_game_input(3))
+            ));
 
-        let game_type_row = row![
-            Text::new(crate::translations::t(crate::translations::TranslationKey::GameType, user_language)).size(self.font_size_base()),
-            game_type_picker,
-        ]
+        let gender_row = self.directional_row(user_language, vec![
+            self.localized_text(crate::translations::t(crate::translations::TranslationKey::Pronouns, user_language), user_language).size(self.font_size_base()).into(),
+            gender_picker.into(),
+            custom_pronouns_input.into(),
+        ])
             .spacing(10)
             .align_y(Center);
 
-        let go_button = button(Text::new(crate::translations::t(crate::translations::TranslationKey::Go, user_language)).size(self.font_size_base()))
+        let go_button = button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::Go, user_language), user_language).size(self.font_size_base()))
             .on_press(GameMessage::Menu_NewGameStartClicked)
             .padding(10)
             .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::new_game_button(0))));
 
         let mut layout = iced::widget::Column::new()
-            .spacing(20)
+            .spacing(12)
             .padding(20)
             .push(name_row)
-            .push(game_type_row);
+            .push(gender_row)
+            .push(self.build_crisis_browser());
 
         // Add description area if a crisis is selected
         if let Some(ref description) = self.new_game_selected_description {
-            let description_text = Text::new(description)
+            let description_text = self.localized_text(description, user_language)
+                .width(Length::Fill)
                 .size(self.font_size_base())
                 .wrapping(iced::widget::text::Wrapping::Word)
-                .color(iced::Color::from_rgb(0.6, 0.6, 0.6));
+                .color(self.secondary_text_color());
             
             let description_container = Container::new(description_text)
                 .width(Length::Fill)
@@ -290,13 +305,93 @@ impl GameWindow {
             layout = layout.push(description_container);
         }
 
+        // Where the selected crisis comes from, and what it overrides
+        if let Some(entry) = self.new_game_game_template.as_ref()
+            .and_then(|template_name| crate::crisis::CrisisCatalog::get().get_entry(template_name).cloned())
+        {
+            let mut source_text = format!(
+                "{}: {}",
+                crate::translations::t(crate::translations::TranslationKey::CrisisSource, user_language),
+                entry.source.label(user_language)
+            );
+            if !entry.shadowed_sources.is_empty() {
+                let shadowed: Vec<String> = entry.shadowed_sources.iter()
+                    .map(|source| source.label(user_language))
+                    .collect();
+                source_text = format!(
+                    "{} ({} {})",
+                    source_text,
+                    crate::translations::t(crate::translations::TranslationKey::Overrides, user_language),
+                    shadowed.join(", ")
+                );
+            }
+
+            let badge_color = match entry.signature {
+                crate::crisis::SignatureStatus::BuiltIn | crate::crisis::SignatureStatus::Verified(_) => iced::Color::from_rgb(0.2, 0.7, 0.3),
+                crate::crisis::SignatureStatus::Invalid(_) => iced::Color::from_rgb(0.8, 0.2, 0.2),
+                _ => self.secondary_text_color(),
+            };
+            let signature_badge = Container::new(
+                self.localized_text(entry.signature.label(user_language), user_language)
+                    .size(self.font_size_small())
+                    .color(badge_color)
+            )
+                .padding([2, 8])
+                .style(move |_theme: &Theme| {
+                    iced::widget::container::Style {
+                        border: iced::border::rounded(10)
+                            .color(badge_color)
+                            .width(1),
+                        ..iced::widget::container::Style::default()
+                    }
+                });
+
+            let mut source_items: Vec<Element<'_, GameMessage>> = vec![
+                self.localized_text(source_text, user_language)
+                    .size(self.font_size_small())
+                    .color(self.secondary_text_color())
+                    .width(Length::Fill)
+                    .into(),
+                signature_badge.into(),
+            ];
+
+            if self.selected_crisis_can_fork() {
+                source_items.push(
+                    button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::ForkCrisis, user_language), user_language).size(self.font_size_small()))
+                        .on_press(GameMessage::Menu_NewGameForkRequested)
+                        .padding([8, 12])
+                        .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::new_game_button(1))))
+                        .into()
+                );
+            }
+            layout = layout.push(
+                self.directional_row(user_language, source_items)
+                    .spacing(10)
+                    .align_y(Center)
+            );
+
+            if let Some(ref fork_status) = self.new_game_fork_status {
+                layout = layout.push(
+                    self.localized_text(fork_status.clone(), user_language)
+                        .size(self.font_size_small())
+                        .color(self.secondary_text_color())
+                );
+            }
+        }
+
+        // Go sits at the reading end of the panel
+        let go_alignment = if self.is_rtl(user_language) {
+            iced::alignment::Horizontal::Left
+        } else {
+            iced::alignment::Horizontal::Right
+        };
         layout = layout.push(
             Container::new(go_button)
-                .align_x(iced::alignment::Horizontal::Right)
+                .align_x(go_alignment)
                 .width(Length::Fill),
         )
         .height(Length::Fill)
-        .align_x(Left);
+        .align_x(self.text_alignment(user_language));
 
         let self_theme = self.theme();
         Container::<GameMessage, Theme, iced::Renderer>::new(layout)
@@ -316,14 +411,37 @@ impl GameWindow {
         let difficulty_label = crate::translations::t(crate::translations::TranslationKey::DifficultyLevel, user_language);
         let difficulty_placeholder = crate::translations::t(crate::translations::TranslationKey::SelectDifficulty, user_language);
         let autosave_label = crate::translations::t(crate::translations::TranslationKey::Autosave, user_language);
-        let language_label = crate::translations::t(crate::translations::TranslationKey::Language, user_language);
-        let language_placeholder = crate::translations::t(crate::translations::TranslationKey::SelectLanguage, user_language);
         
+        let profile_name_input = text_input(
+            &crate::profile::numbered_profile_name(1, user_language),
+            &self.settings_profile_name
+        )
+            .on_input(GameMessage::Menu_SettingsProfileNameChanged)
+            .on_submit(GameMessage::Menu_SettingsProfileNameSubmitted)
+            .padding(10)
+            .width(Length::Fill)
+            .style(crate::gui::focused_text_input_style(
+                self.focus_state.is_focused(FocusId::settings_input(2)),
+                self.focus_state.is_text_input_focused(FocusId::settings_input(2))
+            ));
+
+        let profile_row = self.directional_row(user_language, vec![
+            self.localized_text(crate::translations::t(crate::translations::TranslationKey::ProfileName, user_language), user_language).size(self.font_size_base()).into(),
+            profile_name_input.into(),
+        ])
+        .spacing(10)
+        .align_y(Center);
+
+        let profile_explanation = self.localized_text(crate::translations::t(crate::translations::TranslationKey::ProfileExplanation, user_language), user_language)
+            .size(self.font_size_small())
+            .color(self.secondary_text_color());
+
         let save_folder_input = text_input(
             &save_folder_placeholder, 
-            &self.settings_game_crises_folder
+            &self.settings_game_crises_folder_input
         )
             .on_input(GameMessage::Menu_SettingsGameCrisesFolderChanged)
+            .on_submit(GameMessage::Menu_SettingsGameCrisesFolderSubmitted)
             .padding(10)
             .width(Length::Fill)
             .style(crate::gui::focused_text_input_style(
@@ -334,31 +452,48 @@ impl GameWindow {
         // Create the save folder row with optional Open button
         #[cfg(not(target_arch = "wasm32"))]
         let save_folder_row = {
-            let open_button = button(Text::new(crate::translations::t(crate::translations::TranslationKey::OpenFolder, user_language)).size(self.font_size_base()))
+            let open_button = button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::OpenFolder, user_language), user_language).size(self.font_size_base()))
                 .on_press(GameMessage::Menu_SettingsOpenCrisesFolder)
                 .padding([10, 15])
                 .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::settings_button(0))));
 
-            row![
-                Text::new(save_folder_label).size(self.font_size_base()),
-                save_folder_input,
-                open_button
-            ]
+            self.directional_row(user_language, vec![
+                self.localized_text(save_folder_label, user_language).size(self.font_size_base()).into(),
+                save_folder_input.into(),
+                open_button.into()
+            ])
             .spacing(10)
             .align_y(Center)
         };
 
         #[cfg(target_arch = "wasm32")]
-        let save_folder_row = row![
-            Text::new(save_folder_label), 
-            save_folder_input
-        ]
+        let save_folder_row = self.directional_row(user_language, vec![
+            self.localized_text(save_folder_label, user_language).into(), 
+            save_folder_input.into()
+        ])
         .spacing(10)
         .align_y(Center);
 
-        let crises_folder_explanation = Text::new(crate::translations::t(crate::translations::TranslationKey::CrisesFolderExplanation, user_language))
+        // Browsers cannot read a crises folder, so crisis packs are uploaded instead
+        #[cfg(target_arch = "wasm32")]
+        let upload_pack_row = {
+            let upload_button = button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::LoadCrisisPack, user_language), user_language).size(self.font_size_base()))
+                .on_press(GameMessage::Menu_SettingsUploadPackRequested)
+                .padding([10, 15])
+                .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::settings_button(1))));
+
+            let upload_status = Text::new(self.pack_upload_status.clone().unwrap_or_default())
+                .size(self.font_size_small())
+                .color(self.secondary_text_color());
+
+            self.directional_row(user_language, vec![upload_button.into(), upload_status.into()])
+                .spacing(10)
+                .align_y(Center)
+        };
+
+        let crises_folder_explanation = self.localized_text(crate::translations::t(crate::translations::TranslationKey::CrisesFolderExplanation, user_language), user_language)
             .size(self.font_size_small())
-            .color(iced::Color::from_rgb(0.6, 0.6, 0.6));
+            .color(self.secondary_text_color());
 
         // Create difficulty options with translations
         let difficulty_options: Vec<String> = DifficultyLevel::ALL.iter()
@@ -384,10 +519,10 @@ impl GameWindow {
         .width(Length::Fill)
         .style(crate::gui::focused_pick_list_style(self.focus_state.is_focused(FocusId::settings_picker(0))));
 
-        let difficulty_row = row![
-            Text::new(difficulty_label).size(self.font_size_base()),
-            difficulty_picker,
-        ]
+        let difficulty_row = self.directional_row(user_language, vec![
+            self.localized_text(difficulty_label, user_language).size(self.font_size_base()).into(),
+            difficulty_picker.into(),
+        ])
         .spacing(10)
         .align_y(Center);
 
@@ -396,120 +531,139 @@ impl GameWindow {
             .width(Length::Shrink)
             .style(crate::gui::focused_toggler_style(self.focus_state.is_focused(FocusId::settings_toggle(0))));
 
-        let autosave_row = row![
-            Text::new(autosave_label).size(self.font_size_base()),
-            autosave_toggle,
-        ]
+        let autosave_row = self.directional_row(user_language, vec![
+            self.localized_text(autosave_label, user_language).size(self.font_size_base()).into(),
+            autosave_toggle.into(),
+        ])
         .spacing(10)
         .align_y(Center);
 
-        let available_languages = crate::language::get_available_languages();
-        let language_options: Vec<String> = available_languages.iter().map(|(code, name)| format!("{} ({})", name, code)).collect();
-        let current_language_display = available_languages.iter()
-            .find(|(code, _)| code == &self.settings_language)
-            .map(|(code, name)| format!("{} ({})", name, code))
-            .unwrap_or_else(|| format!("Unknown ({})", self.settings_language));
+        let language_row = self.build_language_row(user_language);
 
-        let language_picker = pick_list(
-            language_options,
-            Some(current_language_display),
-            |selected| {
-                let lang_code = selected.split(" (").last().unwrap_or("eng").trim_end_matches(')').to_string();
-                GameMessage::Menu_SettingsLanguageChanged(lang_code)
-            },
+        let font_scale_row = self.build_font_scale_row(user_language);
+
+        let font_scale_explanation = self.localized_text(crate::translations::t(crate::translations::TranslationKey::FontScaleExplanation, user_language), user_language)
+            .size(self.font_size_small())
+            .color(self.secondary_text_color());
+
+        let volume_row = self.build_volume_row(user_language);
+
+        let reduce_motion_row = self.build_reduce_motion_row(user_language);
+
+        let reduce_motion_explanation = self.localized_text(crate::translations::t(crate::translations::TranslationKey::ReduceMotionExplanation, user_language), user_language)
+            .size(self.font_size_small())
+            .color(self.secondary_text_color());
+
+        let theme_row = self.build_theme_row(user_language);
+
+        // Signing settings locked by an admin policy are shown but cannot be edited
+        let policy = crate::policy::AdminPolicy::get();
+        let trusted_keys_input = text_input(
+            &crate::translations::t(crate::translations::TranslationKey::EnterTrustedKeys, user_language),
+            &self.settings_trusted_keys
         )
-        .placeholder(&language_placeholder)
-        .padding(10)
-        .width(Length::Fill)
-        .style(crate::gui::focused_pick_list_style(self.focus_state.is_focused(FocusId::settings_picker(1))));
+            .on_input_maybe(policy.trusted_keys.is_none().then_some(GameMessage::Menu_SettingsTrustedKeysChanged))
+            .on_submit(GameMessage::Menu_SettingsTrustedKeysSubmitted)
+            .padding(10)
+            .width(Length::Fill)
+            .style(crate::gui::focused_text_input_style(
+                self.focus_state.is_focused(FocusId::settings_input(1)),
+                self.focus_state.is_text_input_focused(FocusId::settings_input(1))
+            ));
 
-        let language_row = row![
-            Text::new(language_label).size(self.font_size_base()),
-            language_picker,
-        ]
+        let trusted_keys_row = self.directional_row(user_language, vec![
+            self.localized_text(crate::translations::t(crate::translations::TranslationKey::TrustedKeys, user_language), user_language).size(self.font_size_base()).into(),
+            trusted_keys_input.into(),
+        ])
         .spacing(10)
         .align_y(Center);
 
-        // Font scale slider
-        let font_scale_label = crate::translations::t(crate::translations::TranslationKey::FontScale, user_language);
-        let font_scale_slider = slider(0.1..=2.0, self.settings_font_scale, GameMessage::Menu_SettingsFontScaleChanged)
-            .step(0.1)
-            .width(Length::Fill)
-            .style(crate::gui::focused_slider_style(self.focus_state.is_focused(FocusId::settings_slider(0))));
-
-        let font_scale_value_text = Text::new(format!("{:.1}x", self.settings_font_scale)).size(self.font_size_base());
+        let require_signed_toggle = toggler(self.settings_require_signed_crises)
+            .on_toggle_maybe(policy.require_signed_crises.is_none().then_some(GameMessage::Menu_SettingsRequireSignedToggled))
+            .width(Length::Shrink)
+            .style(crate::gui::focused_toggler_style(self.focus_state.is_focused(FocusId::settings_toggle(1))));
 
-        let font_scale_row = row![
-            Text::new(font_scale_label).size(self.font_size_base()),
-            font_scale_slider,
-            font_scale_value_text
-        ]
+        let require_signed_row = self.directional_row(user_language, vec![
+            self.localized_text(crate::translations::t(crate::translations::TranslationKey::RequireSignedCrises, user_language), user_language).size(self.font_size_base()).into(),
+            require_signed_toggle.into(),
+        ])
         .spacing(10)
         .align_y(Center);
 
-        let font_scale_explanation = Text::new(crate::translations::t(crate::translations::TranslationKey::FontScaleExplanation, user_language))
+        let signing_explanation_key = if policy.locks_signing() {
+            crate::translations::TranslationKey::SigningLockedByPolicy
+        } else {
+            crate::translations::TranslationKey::SigningExplanation
+        };
+        let signing_explanation = self.localized_text(crate::translations::t(signing_explanation_key, user_language), user_language)
             .size(self.font_size_small())
-            .color(iced::Color::from_rgb(0.6, 0.6, 0.6));
+            .color(self.secondary_text_color());
 
         // Create settings storage path components (non-wasm32 only)
         #[cfg(not(target_arch = "wasm32"))]
-        let (settings_storage_row, settings_storage_explanation) = {
-            let storage_path = Self::get_settings_storage_path();
+        let (settings_storage_row, saves_storage_row, settings_storage_explanation) = {
             let storage_path_label = crate::translations::t(crate::translations::TranslationKey::SettingsStoragePath, user_language);
+            let saves_path_label = crate::translations::t(crate::translations::TranslationKey::SavesStoragePath, user_language);
             
-            let storage_path_input = text_input("", &storage_path)
-                .width(Length::Fill)
-                .padding(10)
-                .style(|theme: &Theme, _status| {
-                    // Make it look read-only
-                    let palette = theme.extended_palette();
-                    iced::widget::text_input::Style {
-                        background: palette.background.weak.color.into(),
-                        border: iced::border::rounded(4)
-                            .color(palette.background.strong.color)
-                            .width(1),
-                        icon: palette.background.strong.text,
-                        placeholder: palette.background.weak.text,
-                        value: palette.background.base.text,
-                        selection: palette.primary.weak.color,
-                    }
-                });
-                
-            let storage_row = row![
-                Text::new(storage_path_label).size(self.font_size_base()),
-                storage_path_input,
-            ]
+            let storage_row = self.directional_row(user_language, vec![
+                self.localized_text(storage_path_label, user_language).size(self.font_size_base()).into(),
+                Self::read_only_path_input(Self::get_settings_storage_path()).into(),
+            ])
+            .spacing(10)
+            .align_y(Center);
+            
+            let saves_row = self.directional_row(user_language, vec![
+                self.localized_text(saves_path_label, user_language).size(self.font_size_base()).into(),
+                Self::read_only_path_input(Self::get_saves_storage_path()).into(),
+            ])
             .spacing(10)
             .align_y(Center);
             
-            let storage_explanation = Text::new(crate::translations::t(crate::translations::TranslationKey::SettingsStorageExplanation, user_language))
+            let storage_explanation = self.localized_text(crate::translations::t(crate::translations::TranslationKey::SettingsStorageExplanation, user_language), user_language)
                 .size(self.font_size_small())
-                .color(iced::Color::from_rgb(0.6, 0.6, 0.6));
+                .color(self.secondary_text_color());
                 
-            (storage_row, storage_explanation)
+            (storage_row, saves_row, storage_explanation)
         };
 
         let mut layout = iced::widget::Column::new()
             .spacing(20)
             .padding(20)
+            .push(profile_row)
+            .push(profile_explanation)
             .push(save_folder_row)
-            .push(crises_folder_explanation)
+            .push(crises_folder_explanation);
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            layout = layout.push(upload_pack_row);
+        }
+
+        layout = layout
             .push(difficulty_row)
             .push(autosave_row)
             .push(language_row)
             .push(font_scale_row)
-            .push(font_scale_explanation);
+            .push(font_scale_explanation)
+            .push(volume_row)
+            .push(reduce_motion_row)
+            .push(reduce_motion_explanation)
+            .push(theme_row)
+            .push(trusted_keys_row)
+            .push(require_signed_row)
+            .push(signing_explanation);
             
         // Add settings storage path on non-wasm32 platforms
         #[cfg(not(target_arch = "wasm32"))]
         {
             layout = layout
                 .push(settings_storage_row)
+                .push(saves_storage_row)
                 .push(settings_storage_explanation);
         }
         
         let layout = layout
-            .align_x(Left);
+            .align_x(self.text_alignment(user_language));
 
         // Wrap the layout in a scrollable container
         let scrollable_content = iced::widget::Scrollable::new(layout)
@@ -524,6 +678,140 @@ impl GameWindow {
             .padding(10)
     }
 
+    /// Language picker row, shared by the settings screen and the in-game pause menu
+    pub fn build_language_row(&self, language: &str) -> iced::widget::Row<'_, GameMessage> {
+        let available_languages = crate::language::get_available_languages();
+        let language_options: Vec<String> = available_languages.iter().map(|(code, name)| format!("{} ({})", name, code)).collect();
+        let current_language_display = available_languages.iter()
+            .find(|(code, _)| code == &self.settings_language)
+            .map(|(code, name)| format!("{} ({})", name, code))
+            .unwrap_or_else(|| format!("Unknown ({})", self.settings_language));
+
+        let language_picker = pick_list(
+            language_options,
+            Some(current_language_display),
+            |selected| {
+                let lang_code = selected.split(" (").last().unwrap_or("eng").trim_end_matches(')').to_string();
+                GameMessage::Menu_SettingsLanguageChanged(lang_code)
+            },
+        )
+        .placeholder(crate::translations::t(crate::translations::TranslationKey::SelectLanguage, language))
+        // Every option is the language's own name, so Arabic, Hebrew and CJK names need full shaping
+        .text_shaping(iced::widget::text::Shaping::Advanced)
+        .padding(10)
+        .width(Length::Fill)
+        .style(crate::gui::focused_pick_list_style(self.focus_state.is_focused(FocusId::settings_picker(1))));
+
+        self.directional_row(language, vec![
+            self.localized_text(crate::translations::t(crate::translations::TranslationKey::Language, language), language).size(self.font_size_base()).into(),
+            language_picker.into(),
+        ])
+        .spacing(10)
+        .align_y(Center)
+    }
+
+    /// Font scale slider row, shared by the settings screen and the in-game pause menu
+    pub fn build_font_scale_row(&self, language: &str) -> iced::widget::Row<'_, GameMessage> {
+        let font_scale_slider = slider(0.1..=2.0, self.settings_font_scale, GameMessage::Menu_SettingsFontScaleChanged)
+            .step(0.1)
+            .width(Length::Fill)
+            .style(crate::gui::focused_slider_style(self.focus_state.is_focused(FocusId::settings_slider(0))));
+
+        let font_scale_value_text = Text::new(format!("{:.1}x", self.settings_font_scale)).size(self.font_size_base());
+
+        self.directional_row(language, vec![
+            self.localized_text(crate::translations::t(crate::translations::TranslationKey::FontScale, language), language).size(self.font_size_base()).into(),
+            font_scale_slider.into(),
+            font_scale_value_text.into()
+        ])
+        .spacing(10)
+        .align_y(Center)
+    }
+
+    /// Volume slider row, shared by the settings screen and the in-game pause menu
+    pub fn build_volume_row(&self, language: &str) -> iced::widget::Row<'_, GameMessage> {
+        let volume_slider = slider(0.0..=1.0, self.settings_volume, GameMessage::Menu_SettingsVolumeChanged)
+            .step(0.05)
+            .width(Length::Fill)
+            .style(crate::gui::focused_slider_style(self.focus_state.is_focused(FocusId::settings_slider(1))));
+
+        let volume_value_text = Text::new(format!("{:.0}%", self.settings_volume * 100.0)).size(self.font_size_base());
+
+        self.directional_row(language, vec![
+            self.localized_text(crate::translations::t(crate::translations::TranslationKey::Volume, language), language).size(self.font_size_base()).into(),
+            volume_slider.into(),
+            volume_value_text.into()
+        ])
+        .spacing(10)
+        .align_y(Center)
+    }
+
+    /// Reduce motion toggle row, shared by the settings screen and the in-game pause menu
+    pub fn build_reduce_motion_row(&self, language: &str) -> iced::widget::Row<'_, GameMessage> {
+        let reduce_motion_toggle = toggler(self.settings_reduce_motion)
+            .on_toggle(GameMessage::Menu_SettingsReduceMotionToggled)
+            .width(Length::Shrink)
+            .style(crate::gui::focused_toggler_style(self.focus_state.is_focused(FocusId::settings_toggle(2))));
+
+        self.directional_row(language, vec![
+            self.localized_text(crate::translations::t(crate::translations::TranslationKey::ReduceMotion, language), language).size(self.font_size_base()).into(),
+            reduce_motion_toggle.into(),
+        ])
+        .spacing(10)
+        .align_y(Center)
+    }
+
+    /// Theme picker row, shared by the settings screen and the in-game pause menu
+    pub fn build_theme_row(&self, language: &str) -> iced::widget::Row<'_, GameMessage> {
+        let themes = ThemeSetting::all();
+        let theme_options: Vec<String> = themes.iter().map(|theme| theme.to_translated_string(language)).collect();
+        let picker_language = language.to_string();
+
+        let theme_picker = pick_list(
+            theme_options,
+            Some(self.settings_theme.to_translated_string(language)),
+            move |selected| {
+                // Map back from the shown name to the setting
+                let theme = themes.iter()
+                    .find(|theme| theme.to_translated_string(&picker_language) == selected)
+                    .cloned()
+                    .unwrap_or_default();
+                GameMessage::Menu_SettingsThemeChanged(theme)
+            },
+        )
+        .padding(10)
+        .width(Length::Fill)
+        .style(crate::gui::focused_pick_list_style(self.focus_state.is_focused(FocusId::settings_picker(2))));
+
+        self.directional_row(language, vec![
+            self.localized_text(crate::translations::t(crate::translations::TranslationKey::Theme, language), language).size(self.font_size_base()).into(),
+            theme_picker.into(),
+        ])
+        .spacing(10)
+        .align_y(Center)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn read_only_path_input<'a>(path: String) -> iced::widget::TextInput<'a, GameMessage> {
+        text_input("", &path)
+            .width(Length::Fill)
+            .padding(10)
+            .style(|theme: &Theme, _status| {
+                // Make it look read-only
+                let palette = theme.extended_palette();
+                iced::widget::text_input::Style {
+                    background: palette.background.weak.color.into(),
+                    border: iced::border::rounded(4)
+                        .color(palette.background.strong.color)
+                        .width(1),
+                    icon: palette.background.strong.text,
+                    placeholder: palette.background.weak.text,
+                    value: palette.background.base.text,
+                    selection: palette.primary.weak.color,
+                }
+            })
+    }
+
     pub fn build_licenses_ui(&self) -> Container<'_, GameMessage> {
         
         let license_content = text(Self::get_license_content())
@@ -553,7 +841,7 @@ impl GameWindow {
     }
 
     fn get_license_content() -> String {
-        r#"THIRD-PARTY SOFTWARE LICENSES AND ATTRIBUTIONS
+        concat!(r#"THIRD-PARTY SOFTWARE LICENSES AND ATTRIBUTIONS
 
 This software includes the following third-party libraries:
 
@@ -584,6 +872,18 @@ License: MIT
 Copyright (c) 2018 pyros2097
 Used for: Asset embedding in binary
 
+=== FONTS ===
+
+Noto Sans, Noto Sans Arabic, Noto Sans Hebrew
+License: SIL Open Font License 1.1
+Copyright 2022 The Noto Project Authors
+Used for: User interface and story text
+
+Noto Sans CJK SC
+License: SIL Open Font License 1.1
+Copyright 2014-2021 Adobe, with Reserved Font Name 'Source'
+Used for: Chinese, Japanese and Korean text
+
 === IMAGE ATTRIBUTIONS ===
 
 Background Images and Character Assets:
@@ -627,6 +927,10 @@ Unless required by applicable law or agreed to in writing, software
 distributed under the License is distributed on an "AS IS" BASIS,
 WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 See the License for the specific language governing permissions and
-limitations under the License."#.to_string()
+limitations under the License.
+
+SIL Open Font License 1.1 (Noto fonts):
+
+"#, include_str!("../../../fonts/OFL.txt")).to_string()
     }
 }
@@ -0,0 +1,225 @@
+use crate::gui::types::*;
+use crate::translations::{t, t_count, t_vars, TranslationKey};
+use iced::widget::{
+    Container, Image, Scrollable, Space, button, column, pick_list, text_input
+};
+use iced::{Center, Element, Length, Theme};
+
+pub const BROWSER_COLUMNS: usize = 3;
+const COVER_HEIGHT_PX: f32 = 96.0;
+
+impl GameWindow {
+    /// Searches the catalog again when the query, the UI language or the catalog itself changed
+    /// since the last search. Returns true when the matches were recomputed.
+    pub fn refresh_browse_results(&mut self) -> bool {
+        let catalog = crate::crisis::CrisisCatalog::get();
+        let up_to_date = self.cached_views.crisis_browser.as_ref().is_some_and(|cached| {
+            std::sync::Arc::ptr_eq(&cached.catalog, &catalog)
+                && cached.query == self.crisis_query
+                && cached.language == self.settings_language
+        });
+        if up_to_date {
+            return false;
+        }
+        let entries = catalog.browse(&self.crisis_query, &self.settings_language).into_iter().cloned().collect();
+        self.cached_views.crisis_browser = Some(BrowseResults {
+            catalog,
+            query: self.crisis_query.clone(),
+            language: self.settings_language.clone(),
+            entries,
+        });
+        true
+    }
+
+    /// The crises the browser lists, as of the last `refresh_browse_results`
+    pub fn browse_results(&self) -> &[crate::crisis::CrisisCatalogEntry] {
+        self.cached_views.crisis_browser.as_ref().map(|cached| cached.entries.as_slice()).unwrap_or(&[])
+    }
+
+    /// Search box, filters, sort order and the grid of crisis cards on the New Game screen
+    pub fn build_crisis_browser(&self) -> Element<'_, GameMessage> {
+        let language = &self.settings_language;
+        let catalog = crate::crisis::CrisisCatalog::get();
+        let query = &self.crisis_query;
+
+        let search_input = text_input(&t(TranslationKey::SearchCrises, language), &query.search)
+            .on_input(GameMessage::Menu_NewGameSearchChanged)
+            .padding(8)
+            .width(Length::FillPortion(2))
+            .style(crate::gui::focused_text_input_style(
+                self.focus_state.is_focused(FocusId::new_game_input(1)),
+                self.focus_state.is_text_input_focused(FocusId::new_game_input(1))
+            ));
+
+        let language_choices: Vec<(Option<String>, String)> = std::iter::once((None, t(TranslationKey::AllLanguages, language)))
+            .chain(catalog.languages().into_iter().map(|code| {
+                let name = crate::language::get_language_display_name(&code);
+                (Some(code), name)
+            }))
+            .collect();
+        let selected_language = language_choices.iter()
+            .find(|(code, _)| *code == query.language)
+            .map(|(_, name)| name.clone());
+        let language_filter = pick_list(
+            language_choices.iter().map(|(_, name)| name.clone()).collect::<Vec<_>>(),
+            selected_language,
+            move |selected| GameMessage::Menu_NewGameLanguageFilterChanged(
+                language_choices.iter()
+                    .find(|(_, name)| *name == selected)
+                    .and_then(|(code, _)| code.clone())
+            ),
+        )
+        .padding(8)
+        .width(Length::FillPortion(1))
+        .style(crate::gui::focused_pick_list_style(self.focus_state.is_focused(FocusId::new_game_input(4))));
+
+        let all_tags = t(TranslationKey::AllTags, language);
+        let mut tag_options = vec![all_tags.clone()];
+        tag_options.extend(catalog.tags());
+        let tag_filter = pick_list(
+            tag_options,
+            Some(query.tag.clone().unwrap_or_else(|| all_tags.clone())),
+            move |selected| GameMessage::Menu_NewGameTagFilterChanged(if selected == all_tags { None } else { Some(selected) }),
+        )
+        .padding(8)
+        .width(Length::FillPortion(1))
+        .style(crate::gui::focused_pick_list_style(self.focus_state.is_focused(FocusId::new_game_input(5))));
+
+        let sort_language = language.to_string();
+        let sort_picker = pick_list(
+            crate::crisis::CrisisSort::ALL.iter().map(|sort| sort.label(language)).collect::<Vec<_>>(),
+            Some(query.sort.label(language)),
+            move |selected| GameMessage::Menu_NewGameSortChanged(
+                crate::crisis::CrisisSort::ALL.into_iter()
+                    .find(|sort| sort.label(&sort_language) == selected)
+                    .unwrap_or_default()
+            ),
+        )
+        .padding(8)
+        .width(Length::FillPortion(1))
+        .style(crate::gui::focused_pick_list_style(self.focus_state.is_focused(FocusId::new_game_input(6))));
+
+        let toolbar = self.directional_row(language, vec![
+            search_input.into(),
+            language_filter.into(),
+            tag_filter.into(),
+            sort_picker.into(),
+        ])
+        .spacing(8)
+        .align_y(Center);
+
+        let entries = self.browse_results();
+        let grid: Element<'_, GameMessage> = if entries.is_empty() {
+            self.localized_text(t(TranslationKey::NoMatchingCrises, language), language)
+                .size(self.font_size_small())
+                .color(self.secondary_text_color())
+                .into()
+        } else {
+            let mut grid = iced::widget::Column::new().spacing(10);
+            for (row_index, row_entries) in entries.chunks(BROWSER_COLUMNS).enumerate() {
+                let mut cards: Vec<Element<'_, GameMessage>> = row_entries.iter()
+                    .enumerate()
+                    .map(|(column_index, entry)| self.build_crisis_card(entry, row_index * BROWSER_COLUMNS + column_index))
+                    .collect();
+                // Keeps the cards of a short last row as wide as the rest
+                while cards.len() < BROWSER_COLUMNS {
+                    cards.push(Space::with_width(Length::FillPortion(1)).into());
+                }
+                grid = grid.push(self.directional_row(language, cards).spacing(10));
+            }
+            Scrollable::new(grid)
+                .id(iced::widget::scrollable::Id::new(CRISIS_BROWSER_SCROLLABLE))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .into()
+        };
+
+        column![toolbar, grid]
+            .spacing(10)
+            .height(Length::Fill)
+            .into()
+    }
+
+    fn build_crisis_card(&self, entry: &crate::crisis::CrisisCatalogEntry, index: usize) -> Element<'_, GameMessage> {
+        let language = &self.settings_language;
+        let is_selected = self.new_game_game_template.as_deref() == Some(entry.template_name.as_str());
+        let is_focused = self.focus_state.is_focused(FocusId::crisis_card(index));
+
+        // Handles are made by `load_crisis_covers`; crises without a cover get a plain panel
+        let cover: Element<'_, GameMessage> = match entry.cover_image.as_ref().and_then(|path| self.cached_views.image_handles.get(path)) {
+            Some(handle) => Image::<iced::widget::image::Handle>::new(handle.clone())
+                .width(Length::Fill)
+                .height(Length::Fixed(COVER_HEIGHT_PX))
+                .content_fit(iced::ContentFit::Cover)
+                .into(),
+            None => Container::<GameMessage, Theme, iced::Renderer>::new(Space::with_width(Length::Fill))
+                .width(Length::Fill)
+                .height(Length::Fixed(COVER_HEIGHT_PX))
+                .style(|theme: &Theme| iced::widget::container::Style {
+                    background: Some(theme.extended_palette().background.weak.color.into()),
+                    border: iced::border::rounded(6),
+                    ..iced::widget::container::Style::default()
+                })
+                .into(),
+        };
+
+        let mut details = column![
+            cover,
+            self.localized_text(entry.localized_name(language), language)
+                .size(self.font_size_base())
+                .wrapping(iced::widget::text::Wrapping::Word),
+        ]
+        .spacing(4)
+        .width(Length::Fill)
+        .align_x(self.text_alignment(language));
+
+        for line in self.crisis_card_lines(entry, language) {
+            details = details.push(
+                self.localized_text(line, language)
+                    .size(self.font_size_small())
+                    .wrapping(iced::widget::text::Wrapping::Word)
+            );
+        }
+
+        button(details)
+            .on_press(GameMessage::Menu_NewGameTemplateChoiceAltered(entry.template_name.clone()))
+            .padding(8)
+            .width(Length::FillPortion(1))
+            .style(crate::gui::crisis_card_style(is_selected, is_focused))
+            .into()
+    }
+
+    /// Author and version, length and difficulty, languages, tags, and the player's progress;
+    /// lines with nothing to say are left out
+    pub fn crisis_card_lines(&self, entry: &crate::crisis::CrisisCatalogEntry, language: &str) -> Vec<String> {
+        let mut lines = vec![];
+
+        if let Some(ref metadata) = entry.metadata {
+            let mut vars = std::collections::HashMap::new();
+            vars.insert("author".to_string(), metadata.author.clone());
+            lines.push(format!("{} · v{}", t_vars(TranslationKey::CrisisAuthor, language, &vars), metadata.version));
+        }
+
+        let facts: Vec<String> = [
+            entry.time_limit_minutes.map(|minutes| t_count(TranslationKey::CrisisMinutes, language, minutes as i64)),
+            entry.difficulty().map(|difficulty| difficulty.to_translated_string(language)),
+        ].into_iter().flatten().collect();
+        if !facts.is_empty() {
+            lines.push(facts.join(" · "));
+        }
+
+        if !entry.languages.is_empty() {
+            lines.push(entry.languages.iter().map(|code| code.to_uppercase()).collect::<Vec<_>>().join(" "));
+        }
+
+        if !entry.tags().is_empty() {
+            lines.push(entry.tags().iter().map(|tag| format!("#{}", tag)).collect::<Vec<_>>().join(" "));
+        }
+
+        if let Some(stats) = self.player_stats.get(&entry.template_name).filter(|stats| stats.runs > 0) {
+            lines.push(Self::crisis_progress_line(stats, language));
+        }
+
+        lines
+    }
+}
@@ -8,12 +8,27 @@ use iced::{Center, Element, Length, Theme};
 impl GameWindow {
     pub fn view_game_screen(&self) -> Element<'_, GameMessage> {
         if let (Some(crisis), Some(story_state)) = (&self.current_crisis, &self.story_state) {
-            self.render_story_scene(crisis, story_state)
+            let mut layers = vec![self.render_story_scene(crisis, story_state)];
+            if let Some(ref reload_error) = self.crisis_reload_error {
+                layers.push(self.create_reload_error_overlay(reload_error).into());
+            }
+            if let Some(pause_view) = self.pause_menu {
+                layers.push(self.create_pause_menu_overlay(pause_view));
+            }
+            
+            if layers.len() == 1 {
+                layers.remove(0)
+            } else {
+                iced::widget::Stack::with_children(layers)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .into()
+            }
         } else {
             container(
                 column![
-                    text(TranslationUtils::translate(crate::translations::TranslationKey::LoadingCrisis, &self.settings_language)).size(self.font_size_large()),
-                    button(text(TranslationUtils::translate(crate::translations::TranslationKey::ReturnToMenu, &self.settings_language)).size(self.font_size_base()))
+                    self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::LoadingCrisis, &self.settings_language), &self.settings_language).size(self.font_size_large()),
+                    button(self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::ReturnToMenu, &self.settings_language), &self.settings_language).size(self.font_size_base()))
                         .on_press(GameMessage::Game_RestartRequested)
                         .padding(10)
                 ]
@@ -65,12 +80,8 @@ impl GameWindow {
         error_messages: &mut Vec<String>
     ) -> Option<iced::widget::Stack<'_, GameMessage, Theme, iced::Renderer>> {
         if let Some(ref bg_path) = current_scene.background_image {
-            if let Some(bg_file) = crate::crisis::PlayableCrises::get(bg_path) {
-                let bg_handle = iced::widget::image::Handle::from_bytes(bg_file.data.as_ref().to_vec());
-                let bg_img = Image::<iced::widget::image::Handle>::new(bg_handle)
-                    .width(Length::Fill)
-                    .height(Length::Fill)
-                    .content_fit(iced::ContentFit::Cover);
+            if let Some(bg_img) = self.create_background_image(bg_path) {
+                let background = self.create_transitioning_background(bg_path, bg_img);
                 
                 let overlay = Container::<GameMessage, Theme, iced::Renderer>::new(
                     iced::widget::Space::with_width(Length::Fill)
@@ -85,9 +96,7 @@ impl GameWindow {
                 });
                 
                 Some(iced::widget::stack![
-                    Container::<GameMessage, Theme, iced::Renderer>::new(bg_img)
-                        .width(Length::Fill)
-                        .height(Length::Fill),
+                    background,
                     overlay
                 ]
                 .width(Length::Fill)
@@ -102,6 +111,63 @@ impl GameWindow {
         }
     }
 
+    fn create_background_image(&self, bg_path: &str) -> Option<Image<iced::widget::image::Handle>> {
+        let bg_handle = self.crisis_image(bg_path)?;
+        Some(Image::<iced::widget::image::Handle>::new(bg_handle)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .content_fit(iced::ContentFit::Cover))
+    }
+
+    /// The scene's background, faded in from black or from the previous scene's background while a transition runs
+    fn create_transitioning_background(&self, bg_path: &str, bg_img: Image<iced::widget::image::Handle>) -> Element<'_, GameMessage> {
+        let Some(transition) = self.scene_transition.as_ref().filter(|transition| transition.background_progress() < 1.0) else {
+            return Container::<GameMessage, Theme, iced::Renderer>::new(bg_img)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .into();
+        };
+        // Scenes sharing a background do not fade it
+        if transition.previous_background.as_deref() == Some(bg_path) {
+            return bg_img.into();
+        }
+        
+        let progress = transition.background_progress();
+        let previous_img = transition.previous_background.as_ref()
+            .and_then(|previous_path| self.create_background_image(previous_path));
+        let black = Container::<GameMessage, Theme, iced::Renderer>::new(Space::with_width(Length::Fill))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_theme: &Theme| iced::widget::container::Style {
+                background: Some(iced::Color::BLACK.into()),
+                ..iced::widget::container::Style::default()
+            });
+        
+        let layers: Vec<Element<'_, GameMessage>> = match (transition.transition.background, previous_img) {
+            (crate::crisis::BackgroundTransition::Crossfade, Some(previous_img)) => vec![
+                previous_img.into(),
+                bg_img.opacity(progress).into(),
+            ],
+            // A fade spends the first half darkening the old background and the second brightening the new one
+            (crate::crisis::BackgroundTransition::Fade, Some(previous_img)) if progress < 0.5 => vec![
+                black.into(),
+                previous_img.opacity(1.0 - progress * 2.0).into(),
+            ],
+            (crate::crisis::BackgroundTransition::Fade, Some(_)) => vec![
+                black.into(),
+                bg_img.opacity(progress * 2.0 - 1.0).into(),
+            ],
+            _ => vec![
+                black.into(),
+                bg_img.opacity(progress).into(),
+            ],
+        };
+        iced::widget::Stack::with_children(layers)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+
     fn create_main_game_layout(
         &self,
         crisis: &crate::crisis::CrisisDefinition,
@@ -112,64 +178,421 @@ impl GameWindow {
         let top_data = self.create_top_data_section(crisis, story_state);
         let bottom_row = self.create_bottom_game_section(crisis, current_scene, story_state, error_messages);
         
+        let mut layout = column![top_data]
+            .width(Length::Fill)
+            .height(Length::Fill);
+        
+        if let Some(timeline) = self.create_timeline_section(story_state) {
+            layout = layout.push(timeline);
+        }
+        
+        if let Some(transcript) = self.create_transcript_section(crisis, story_state) {
+            layout = layout.push(transcript);
+        }
+        
+        layout = layout.push(bottom_row);
+        
         if !error_messages.is_empty() {
-            let error_display = self.create_error_display(error_messages);
-            column![top_data, bottom_row, error_display]
-                .width(Length::Fill)
-                .height(Length::Fill)
+            layout = layout.push(self.create_error_display(error_messages));
+        }
+        
+        layout
+    }
+
+    /// Row of past scenes the player can jump back to; hidden when the crisis disables rewinding
+    fn create_timeline_section(&self, story_state: &crate::crisis::GameState) -> Option<Container<'_, GameMessage, Theme, iced::Renderer>> {
+        if !self.rewind_allowed() || self.game_history.is_empty() {
+            return None;
+        }
+        
+        let mut timeline_items: Vec<Element<'_, GameMessage>> = vec![
+            self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::Timeline, &story_state.language), &story_state.language)
+                .size(self.font_size_small())
+                .into(),
+            self.localized_text(crate::translations::t_count(crate::translations::TranslationKey::DecisionCount, &story_state.language, self.game_history.len() as i64), &story_state.language)
+                .size(self.font_size_small())
+                .into(),
+        ];
+        
+        for (index, label) in self.timeline_labels().iter().enumerate() {
+            let label = format!("{}. {}", index + 1, label);
+            timeline_items.push(
+                button(self.localized_text(label, &story_state.language).size(self.font_size_small()))
+                    .on_press(GameMessage::Game_TimelineJumpRequested(index))
+                    .padding(6)
+                    .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::game_timeline(index))))
+                    .into()
+            );
+        }
+        
+        // Mirrored for right-to-left languages, so the first decision sits at the reading start
+        let timeline_row = self.directional_row(&story_state.language, timeline_items)
+            .spacing(8)
+            .align_y(iced::alignment::Vertical::Center);
+        
+        let scrollable_timeline = iced::widget::Scrollable::new(timeline_row)
+            .direction(iced::widget::scrollable::Direction::Horizontal(
+                iced::widget::scrollable::Scrollbar::default()
+            ))
+            .width(Length::Fill);
+        
+        Some(container(scrollable_timeline).width(Length::Fill).padding([0, 20]))
+    }
+
+    /// Labels the timeline entries added since the last call, keeping the ones already made.
+    /// Every label is made again when the story language changes or the crisis is reloaded.
+    pub fn refresh_timeline_labels(&mut self) {
+        let Some(language) = self.story_state.as_ref().map(|story_state| story_state.language.clone()) else {
+            self.cached_views.timeline_labels = None;
+            return;
+        };
+        let mut cached = match self.cached_views.timeline_labels.take() {
+            Some(cached) if cached.language == language => cached,
+            _ => TimelineLabels { language, entries: Vec::new(), labels: Vec::new() },
+        };
+        let entries = self.game_history.entries();
+        let unchanged = cached.entries.iter().zip(entries).take_while(|(cached_entry, entry)| cached_entry == entry).count();
+        cached.entries.truncate(unchanged);
+        cached.labels.truncate(unchanged);
+        for entry in &entries[unchanged..] {
+            cached.labels.push(self.timeline_label(entry));
+            cached.entries.push(entry.clone());
+        }
+        self.cached_views.timeline_labels = Some(cached);
+    }
+
+    /// Timeline button labels, oldest first, as of the last `refresh_timeline_labels`
+    pub fn timeline_labels(&self) -> &[String] {
+        self.cached_views.timeline_labels.as_ref().map(|cached| cached.labels.as_slice()).unwrap_or(&[])
+    }
+
+    /// The opening words of a past scene as the player read it, for its timeline button
+    fn timeline_label(&self, entry: &crate::crisis::HistoryEntry) -> String {
+        const MAX_LABEL_CHARS: usize = 32;
+        let scene_text = self.current_crisis.as_ref().zip(self.story_state.as_ref()).and_then(|(crisis, story_state)| {
+            let scene = crisis.scenes.get(&entry.scene)?;
+            let past_state = entry.restore(story_state);
+            Some(crate::crisis::get_scene_text_with_substitutions(scene, &story_state.language, crisis, &past_state))
+        });
+        let Some(scene_text) = scene_text else {
+            return entry.scene.replace('_', " ");
+        };
+        let first_line = scene_text.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or_default();
+        if first_line.chars().count() > MAX_LABEL_CHARS {
+            format!("{}…", first_line.chars().take(MAX_LABEL_CHARS).collect::<String>().trim_end())
         } else {
-            column![top_data, bottom_row]
-                .width(Length::Fill)
-                .height(Length::Fill)
+            first_line.to_string()
         }
     }
 
+    /// Collapsible log of earlier scenes and the choices made in them, newest first
+    fn create_transcript_section(&self, crisis: &crate::crisis::CrisisDefinition, story_state: &crate::crisis::GameState) -> Option<Container<'_, GameMessage, Theme, iced::Renderer>> {
+        if !self.transcript_allowed() {
+            return None;
+        }
+        let language = &story_state.language;
+        
+        let toggle_key = if self.transcript_expanded {
+            crate::translations::TranslationKey::HideDecisionLog
+        } else {
+            crate::translations::TranslationKey::ShowDecisionLog
+        };
+        let toggle_button = button(self.localized_text(TranslationUtils::translate(toggle_key, language), language).size(self.font_size_small()))
+            .on_press(GameMessage::Game_TranscriptToggled)
+            .padding(6)
+            .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::game_transcript(0))));
+        let header = self.directional_row(language, vec![
+            toggle_button.into(),
+            self.localized_text(crate::translations::t_count(crate::translations::TranslationKey::DecisionCount, language, story_state.decisions.len() as i64), language)
+                .size(self.font_size_small())
+                .into(),
+        ])
+            .spacing(10)
+            .align_y(Center);
+        
+        let mut section = column![header].spacing(8);
+        if self.transcript_expanded {
+            // Keyboard and controller focus walks the entries, newest first; `scroll_focus_into_view` follows it
+            let mut entries = column![].spacing(10);
+            for (position, decision) in story_state.decisions.iter().rev().enumerate() {
+                let number = story_state.decisions.len() - position;
+                let focused = self.focus_state.is_focused(FocusId::game_transcript(position + 1));
+                entries = entries.push(self.create_transcript_entry(crisis, story_state, decision, number, focused));
+            }
+            section = section.push(
+                iced::widget::Scrollable::new(entries)
+                    .id(iced::widget::scrollable::Id::new(TRANSCRIPT_SCROLLABLE))
+                    .width(Length::Fill)
+                    .height(Length::Fixed(200.0))
+            );
+        }
+        
+        Some(container(section).width(Length::Fill).padding([0, 20]))
+    }
+
+    fn create_transcript_entry(
+        &self,
+        crisis: &crate::crisis::CrisisDefinition,
+        story_state: &crate::crisis::GameState,
+        decision: &crate::crisis::DecisionRecord,
+        number: usize,
+        focused: bool,
+    ) -> Element<'_, GameMessage> {
+        let language = &story_state.language;
+        let scene = crisis.scenes.get(&decision.scene);
+        
+        // Saves from before the transcript kept its text are rendered again, and scenes
+        // removed by a hot reload still show up by name
+        let scene_text = decision.scene_text.clone()
+            .or_else(|| scene.map(|scene| crate::crisis::get_scene_text_with_substitutions(scene, language, crisis, story_state)))
+            .unwrap_or_else(|| decision.scene.replace('_', " "));
+        let choice_text = decision.choice_text.clone()
+            .or_else(|| scene
+                .and_then(|scene| scene.choices.get(decision.choice_index))
+                .map(|choice| crisis.localized_text(&choice.text, language)))
+            .unwrap_or_default();
+        let arrow = if self.is_rtl(language) { "←" } else { "→" };
+        
+        let mut lines = column![
+            self.localized_text(format!("{}. {}", number, scene_text), language)
+                .size(self.font_size_small())
+                .color(self.secondary_text_color())
+                .wrapping(iced::widget::text::Wrapping::Word)
+                .width(Length::Fill),
+            self.localized_text(format!("{} {}", arrow, choice_text), language)
+                .size(self.font_size_base())
+                .wrapping(iced::widget::text::Wrapping::Word)
+                .width(Length::Fill),
+        ]
+        .spacing(4);
+        
+        if let Some(ref value) = decision.text_input {
+            let mut vars = std::collections::HashMap::new();
+            vars.insert("value".to_string(), value.clone());
+            lines = lines.push(
+                self.localized_text(crate::translations::t_vars(crate::translations::TranslationKey::DecisionInput, language, &vars), language)
+                    .size(self.font_size_small())
+                    .width(Length::Fill)
+            );
+        }
+        
+        // Hidden variables stay hidden here too
+        let specs = crisis.conditions.variable_specs();
+        let changes: Vec<String> = decision.variable_changes.iter()
+            .filter_map(|(name, change)| {
+                let spec = specs.iter().find(|spec| spec.name == *name);
+                if spec.is_some_and(|spec| spec.style == crate::crisis::VariableStyle::Hidden) {
+                    return None;
+                }
+                let label = spec.map(|spec| spec.label(language)).unwrap_or_else(|| name.replace('_', " "));
+                Some(format!("{} {:+}", label, change))
+            })
+            .collect();
+        if !changes.is_empty() {
+            lines = lines.push(
+                self.localized_text(changes.join(", "), language)
+                    .size(self.font_size_small())
+                    .color(self.secondary_text_color())
+                    .width(Length::Fill)
+            );
+        }
+        
+        container(lines.align_x(self.text_alignment(language)))
+            .width(Length::Fill)
+            .padding(8)
+            .style(move |theme: &Theme| {
+                let palette = theme.extended_palette();
+                iced::widget::container::Style {
+                    background: Some(palette.background.weak.color.into()),
+                    border: iced::border::rounded(6)
+                        .color(if focused { crate::gui::focus_ring_color(theme) } else { palette.background.strong.color })
+                        .width(if focused { 3 } else { 1 }),
+                    ..iced::widget::container::Style::default()
+                }
+            })
+            .into()
+    }
+
     fn create_top_data_section(&self, crisis: &crate::crisis::CrisisDefinition, story_state: &crate::crisis::GameState) -> Container<'_, GameMessage, Theme, iced::Renderer> {
-        let title = crate::crisis::get_localized_text(&crisis.name, &story_state.language);
+        let title = crisis.localized_text(&crisis.name, &story_state.language);
         let mut vars = std::collections::HashMap::new();
         vars.insert("character_name".to_string(), story_state.character_name.clone());
-        let character_info = text(
-            crate::translations::t_vars(crate::translations::TranslationKey::PlayingAs, &story_state.language, &vars)
+        let character_info = self.localized_text(
+            crate::translations::t_vars(crate::translations::TranslationKey::PlayingAs, &story_state.language, &vars),
+            &story_state.language
         )
             .size(self.font_size_base())
-            .color(iced::Color::from_rgb(0.6, 0.6, 0.6));
-        
-        let variables_text = if !story_state.variables.is_empty() {
-            format!("Variables: {:?}", story_state.variables)
-        } else {
-            String::new()
-        };
+            .color(self.secondary_text_color());
         
         let control_buttons = self.create_control_buttons(story_state);
         
-        let top_row = row![
-            container(control_buttons).align_x(iced::alignment::Horizontal::Left),
-            container(
-                column![
-                    text(title.clone()).size(self.font_size_large()).align_x(Center),
-                    character_info.align_x(Center),
-                    if !variables_text.is_empty() {
-                        text(variables_text.clone()).size(self.font_size_small()).color(iced::Color::from_rgb(0.5, 0.5, 0.5))
-                    } else {
-                        text("").size(self.font_size_base())
-                    }
-                ]
-                .spacing(5)
-                .align_x(Center)
-            )
-            .width(Length::Fill)
-            .align_x(Center),
-            container(Space::with_width(Length::Fixed(190.0)))
+        let mut title_column = column![
+            self.localized_text(title.clone(), &story_state.language).size(self.font_size_large()).align_x(Center),
+            character_info.align_x(Center),
         ]
+        .spacing(5)
+        .align_x(Center);
+        if let Some(variable_hud) = self.create_variable_hud(crisis, story_state) {
+            title_column = title_column.push(variable_hud);
+        }
+        
+        let top_row = self.directional_row(&story_state.language, vec![
+            container(control_buttons).into(),
+            container(title_column)
+            .width(Length::Fill)
+            .align_x(Center)
+            .into(),
+            container(self.create_language_pickers(crisis, story_state))
+                .width(Length::Fixed(190.0))
+                .into()
+        ])
         .width(Length::Fill)
         .align_y(iced::alignment::Vertical::Top);
         
         container(top_row).width(Length::Fill).padding(20)
     }
 
+    /// Story language and side-by-side language pickers; empty for crises written in one language
+    fn create_language_pickers(&self, crisis: &crate::crisis::CrisisDefinition, story_state: &crate::crisis::GameState) -> Element<'_, GameMessage> {
+        let languages = crisis.languages();
+        if languages.len() < 2 {
+            return Space::with_width(Length::Fill).into();
+        }
+        let language = &story_state.language;
+        
+        // Pickers show language names; this maps a name back to its code
+        let named_languages: Vec<(String, String)> = languages.iter()
+            .map(|code| (crate::language::get_language_display_name(code), code.clone()))
+            .collect();
+        let code_for_name = move |name: &str| named_languages.iter()
+            .find(|(display_name, _)| display_name == name)
+            .map(|(_, code)| code.clone());
+        let secondary_code_for_name = code_for_name.clone();
+        
+        let story_language_picker = iced::widget::pick_list(
+            languages.iter().map(|code| crate::language::get_language_display_name(code)).collect::<Vec<String>>(),
+            Some(crate::language::get_language_display_name(language)),
+            move |selected| match code_for_name(&selected) {
+                Some(code) => GameMessage::Game_LanguageChanged(code),
+                None => GameMessage::Nop,
+            },
+        )
+        .padding(6)
+        .width(Length::Fill)
+        .style(crate::gui::focused_pick_list_style(self.focus_state.is_focused(FocusId::game_language(0))));
+        
+        let one_language = TranslationUtils::translate(crate::translations::TranslationKey::NoSecondLanguage, language);
+        let mut secondary_options = vec![one_language.clone()];
+        secondary_options.extend(languages.iter()
+            .filter(|code| *code != language)
+            .map(|code| crate::language::get_language_display_name(code)));
+        let secondary_selected = story_state.secondary_language.as_ref()
+            .map(|code| crate::language::get_language_display_name(code))
+            .unwrap_or_else(|| one_language.clone());
+        let secondary_language_picker = iced::widget::pick_list(
+            secondary_options,
+            Some(secondary_selected),
+            move |selected| GameMessage::Game_SecondaryLanguageChanged(secondary_code_for_name(&selected)),
+        )
+        .padding(6)
+        .width(Length::Fill)
+        .style(crate::gui::focused_pick_list_style(self.focus_state.is_focused(FocusId::game_language(1))));
+        
+        column![
+            self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::Language, language), language)
+                .size(self.font_size_small()),
+            story_language_picker,
+            self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::SecondLanguage, language), language)
+                .size(self.font_size_small()),
+            secondary_language_picker,
+        ]
+        .spacing(4)
+        .width(Length::Fill)
+        .into()
+    }
+
+    /// Meters and counters for the crisis variables, in the order `conditions.variables` declares them
+    fn create_variable_hud(&self, crisis: &crate::crisis::CrisisDefinition, story_state: &crate::crisis::GameState) -> Option<Element<'_, GameMessage>> {
+        let items: Vec<Element<'_, GameMessage>> = crisis.conditions.hud_variables(&story_state.variables).iter()
+            .map(|(spec, value)| self.create_variable_hud_item(spec, *value, &story_state.language))
+            .collect();
+        if items.is_empty() {
+            return None;
+        }
+        
+        Some(self.directional_row(&story_state.language, items)
+            .spacing(20)
+            .align_y(Center)
+            .into())
+    }
+
+    fn create_variable_hud_item(&self, spec: &crate::crisis::VariableSpec, value: i32, language: &str) -> Element<'_, GameMessage> {
+        let color = if spec.is_warning(value) {
+            self.theme().extended_palette().danger.base.color
+        } else {
+            self.secondary_text_color()
+        };
+        
+        let mut parts: Vec<Element<'_, GameMessage>> = vec![];
+        if let Some(ref icon) = spec.icon {
+            match self.crisis_image(icon) {
+                Some(icon_handle) => parts.push(
+                    Image::<iced::widget::image::Handle>::new(icon_handle)
+                        .width(Length::Fixed(self.font_size_base()))
+                        .height(Length::Fixed(self.font_size_base()))
+                        .into()
+                ),
+                None => parts.push(text(icon.clone()).size(self.font_size_small()).color(color).into()),
+            }
+        }
+        parts.push(self.localized_text(spec.label(language), language).size(self.font_size_small()).color(color).into());
+        
+        if spec.style == crate::crisis::VariableStyle::Bar {
+            if let Some(fraction) = spec.fraction(value) {
+                parts.push(self.create_variable_meter(fraction, spec.is_warning(value), language));
+            }
+        }
+        parts.push(text(value.to_string()).size(self.font_size_small()).color(color).into());
+        
+        self.directional_row(language, parts)
+            .spacing(6)
+            .align_y(Center)
+            .into()
+    }
+
+    /// A bar filled to `fraction`, growing from the reading start
+    fn create_variable_meter(&self, fraction: f32, warning: bool, language: &str) -> Element<'_, GameMessage> {
+        const METER_WIDTH: f32 = 80.0;
+        let filled = container(Space::with_width(Length::Fill))
+            .width(Length::Fixed(METER_WIDTH * fraction))
+            .height(Length::Fill)
+            .style(move |theme: &Theme| {
+                let palette = theme.extended_palette();
+                iced::widget::container::Style {
+                    background: Some(if warning { palette.danger.base.color } else { palette.primary.base.color }.into()),
+                    border: iced::border::rounded(4),
+                    ..iced::widget::container::Style::default()
+                }
+            });
+        
+        container(filled)
+            .width(Length::Fixed(METER_WIDTH))
+            .height(Length::Fixed(8.0))
+            .align_x(self.text_alignment(language))
+            .style(|theme: &Theme| {
+                let palette = theme.extended_palette();
+                iced::widget::container::Style {
+                    background: Some(palette.background.strong.color.into()),
+                    border: iced::border::rounded(4),
+                    ..iced::widget::container::Style::default()
+                }
+            })
+            .into()
+    }
+
     fn create_control_buttons(&self, story_state: &crate::crisis::GameState) -> iced::widget::Row<'_, GameMessage, Theme, iced::Renderer> {
         let save_button = button(
-                text(TranslationUtils::translate(crate::translations::TranslationKey::SaveAndQuit, &story_state.language)).size(self.font_size_base())
+                self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::SaveAndQuit, &story_state.language), &story_state.language).size(self.font_size_base())
                     .align_x(Center)
             )
             .on_press(GameMessage::Game_SaveAndQuitRequested)
@@ -178,40 +601,120 @@ impl GameWindow {
             .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId("control", 0))));
             
         let quit_button = button(
-                text(TranslationUtils::translate(crate::translations::TranslationKey::Quit, &story_state.language)).size(self.font_size_base())
+                self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::Quit, &story_state.language), &story_state.language).size(self.font_size_base())
                     .align_x(Center)
             )
-            .on_press(GameMessage::Game_QuitWithoutSaveRequested)
+            .on_press(GameMessage::Game_PauseQuitRequested)
             .padding(8)
             .width(Length::Fixed(60.0))
             .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId("control", 1))));
             
-        row![save_button, quit_button].spacing(10)
+        let pause_button = button(
+                self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::PauseMenu, &story_state.language), &story_state.language).size(self.font_size_base())
+                    .align_x(Center)
+            )
+            .on_press(GameMessage::Game_PauseMenuToggled)
+            .padding(8)
+            .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::game_control(4))));
+            
+        let mut controls: Vec<Element<'_, GameMessage>> = vec![pause_button.into(), save_button.into(), quit_button.into()];
+        
+        if self.rewind_allowed() && self.game_history.can_rewind() {
+            controls.push(
+                button(
+                    self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::Rewind, &story_state.language), &story_state.language).size(self.font_size_base())
+                        .align_x(Center)
+                )
+                .on_press(GameMessage::Game_RewindRequested)
+                .padding(8)
+                .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::game_control(2))))
+                .into()
+            );
+        }
+        
+        if self.restart_allowed() {
+            controls.push(
+                button(
+                    self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::RestartCrisis, &story_state.language), &story_state.language).size(self.font_size_base())
+                        .align_x(Center)
+                )
+                .on_press(GameMessage::Game_RestartCrisisRequested)
+                .padding(8)
+                .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::game_control(3))))
+                .into()
+            );
+        }
+        
+        self.directional_row(&story_state.language, controls).spacing(10)
     }
 
     fn create_bottom_game_section(
         &self,
-        _crisis: &crate::crisis::CrisisDefinition,
+        crisis: &crate::crisis::CrisisDefinition,
         current_scene: &crate::crisis::CrisisScene,
         story_state: &crate::crisis::GameState,
         error_messages: &mut Vec<String>
     ) -> iced::widget::Row<'_, GameMessage, Theme, iced::Renderer> {
-        let left_column = self.create_story_choices_column(current_scene, story_state);
+        let left_column = self.create_story_choices_column(crisis, current_scene, story_state);
         let right_column = self.create_character_column(current_scene, story_state, error_messages);
         
-        row![left_column, right_column]
+        // The story reads from the same side as the text, the character faces it from the other
+        self.directional_row(&story_state.language, vec![left_column.into(), right_column.into()])
             .width(Length::Fill)
             .height(Length::Fill)
     }
 
-    fn create_story_choices_column(&self, current_scene: &crate::crisis::CrisisScene, story_state: &crate::crisis::GameState) -> iced::widget::Column<'_, GameMessage, Theme, iced::Renderer> {
-        let scene_text = crate::crisis::get_scene_text_with_substitutions(current_scene, &story_state.language, &story_state.character_name, &story_state.text_inputs);
+    fn create_story_choices_column(&self, crisis: &crate::crisis::CrisisDefinition, current_scene: &crate::crisis::CrisisScene, story_state: &crate::crisis::GameState) -> iced::widget::Column<'_, GameMessage, Theme, iced::Renderer> {
+        // A typewriter reveal shows the first characters of each text, the same count in both languages
+        let visible_characters = self.scene_transition.as_ref().and_then(|transition| transition.visible_characters());
+        let reveal = |scene_text: String| match visible_characters {
+            Some(count) => scene_text.chars().take(count).collect(),
+            None => scene_text,
+        };
+        
+        let scene_text = reveal(crate::crisis::get_scene_text_with_substitutions(current_scene, &story_state.language, crisis, story_state));
+        let primary_text = self.localized_text(scene_text, &story_state.language)
+            .size(self.font_size_base())
+            .wrapping(iced::widget::text::Wrapping::Word)
+            .width(Length::Fill);
+        
+        // Dual-language mode puts the same scene in the second language beside the first
+        let scene_text_element: Element<'_, GameMessage> = match story_state.secondary_language {
+            Some(ref secondary_language) => {
+                let secondary_text = reveal(crate::crisis::get_scene_text_with_substitutions(current_scene, secondary_language, crisis, story_state));
+                self.directional_row(&story_state.language, vec![
+                    primary_text.into(),
+                    container(Space::with_width(Length::Fixed(1.0)))
+                        .height(Length::Fill)
+                        .style(|theme: &Theme| iced::widget::container::Style {
+                            background: Some(theme.extended_palette().background.strong.color.into()),
+                            ..iced::widget::container::Style::default()
+                        })
+                        .into(),
+                    self.localized_text(secondary_text, secondary_language)
+                        .size(self.font_size_base())
+                        .wrapping(iced::widget::text::Wrapping::Word)
+                        .width(Length::Fill)
+                        .into(),
+                ])
+                .spacing(16)
+                .height(Length::Shrink)
+                .into()
+            }
+            None => primary_text.into(),
+        };
+        
+        // Clicking the text while it appears shows the rest, like Enter
+        let scene_text_element: Element<'_, GameMessage> = if visible_characters.is_some() {
+            iced::widget::mouse_area(scene_text_element)
+                .on_press(GameMessage::Game_TextRevealSkipped)
+                .into()
+        } else {
+            scene_text_element
+        };
+        
         let story_text_display = container(
-            container(
-                text(scene_text.clone())
-                    .size(self.font_size_base())
-                    .wrapping(iced::widget::text::Wrapping::Word)
-            )
+            container(scene_text_element)
             .padding(20)
             .style(move |theme: &Theme| {
                 let palette = theme.extended_palette();
@@ -227,7 +730,7 @@ impl GameWindow {
         .width(Length::Fill)
         .padding(10);
 
-        let choices_column = self.create_choices_section(current_scene, story_state);
+        let choices_column = self.create_choices_section(crisis, current_scene, story_state);
 
         column![
             Space::with_height(Length::Fill),
@@ -239,14 +742,14 @@ impl GameWindow {
         .height(Length::Fill)
     }
 
-    fn create_choices_section(&self, current_scene: &crate::crisis::CrisisScene, story_state: &crate::crisis::GameState) -> iced::widget::Column<'_, GameMessage, Theme, iced::Renderer> {
+    fn create_choices_section(&self, crisis: &crate::crisis::CrisisDefinition, current_scene: &crate::crisis::CrisisScene, story_state: &crate::crisis::GameState) -> iced::widget::Column<'_, GameMessage, Theme, iced::Renderer> {
         let mut choices_column = column![].spacing(10);
         
         if current_scene.choices.is_empty() {
             choices_column = choices_column.push(
                 column![
-                    text(TranslationUtils::translate(crate::translations::TranslationKey::End, &story_state.language)).size(self.font_size_large()),
-                    button(text(TranslationUtils::translate(crate::translations::TranslationKey::ReturnToMenu, &story_state.language)).size(self.font_size_base()))
+                    self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::End, &story_state.language), &story_state.language).size(self.font_size_large()),
+                    button(self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::ReturnToMenu, &story_state.language), &story_state.language).size(self.font_size_base()))
                         .on_press(GameMessage::Game_RestartRequested)
                         .padding(10)
                         .width(Length::Fill)
@@ -255,12 +758,12 @@ impl GameWindow {
             );
         } else {
             choices_column = choices_column.push(
-                text(TranslationUtils::translate(crate::translations::TranslationKey::WhatDoYouChoose, &story_state.language))
+                self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::WhatDoYouChoose, &story_state.language), &story_state.language)
                     .size(self.font_size_base())
             );
             
             for (index, choice) in current_scene.choices.iter().enumerate() {
-                let choice_element = self.create_choice_element(choice, index, story_state);
+                let choice_element = self.create_choice_element(crisis, choice, index, story_state);
                 choices_column = choices_column.push(choice_element);
             }
         }
@@ -268,14 +771,17 @@ impl GameWindow {
         choices_column
     }
 
-    fn create_choice_element(&self, choice: &crate::crisis::CrisisChoice, index: usize, story_state: &crate::crisis::GameState) -> iced::Element<'_, GameMessage> {
-        let choice_text = crate::crisis::get_localized_text(&choice.text, &story_state.language);
+    fn create_choice_element(&self, crisis: &crate::crisis::CrisisDefinition, choice: &crate::crisis::CrisisChoice, index: usize, story_state: &crate::crisis::GameState) -> iced::Element<'_, GameMessage> {
+        let choice_text = crisis.localized_text(&choice.text, &story_state.language);
         let available = self.is_choice_available(choice, story_state);
         
         if let Some(ref text_input) = choice.text_input {
-            self.create_text_input_choice(text_input, choice_text, index, available, story_state)
+            self.create_text_input_choice(crisis, text_input, choice_text, index, available, story_state)
         } else {
-            self.create_regular_choice_button(choice_text, index, available, story_state)
+            // In dual-language mode the second language goes under the first
+            let secondary_text = story_state.secondary_language.as_ref()
+                .map(|secondary_language| (crisis.localized_text(&choice.text, secondary_language), secondary_language.as_str()));
+            self.create_regular_choice_button(choice_text, secondary_text, index, available, story_state)
         }
     }
 
@@ -294,9 +800,9 @@ impl GameWindow {
         true
     }
 
-    fn create_text_input_choice(&self, text_input: &crate::crisis::CrisisTextInput, choice_text: String, index: usize, available: bool, story_state: &crate::crisis::GameState) -> iced::Element<'_, GameMessage> {
+    fn create_text_input_choice(&self, crisis: &crate::crisis::CrisisDefinition, text_input: &crate::crisis::CrisisTextInput, choice_text: String, index: usize, available: bool, story_state: &crate::crisis::GameState) -> iced::Element<'_, GameMessage> {
         let placeholder_text = if let Some(ref placeholder) = text_input.placeholder {
-            crate::crisis::get_localized_text(placeholder, &story_state.language)
+            crisis.localized_text(placeholder, &story_state.language)
         } else {
             match text_input.input_type {
                 crate::crisis::TextInputType::Text => "Enter text...".to_string(),
@@ -310,37 +816,49 @@ impl GameWindow {
             .on_input(move |value| GameMessage::Game_TextInputChanged(index, value))
             .on_submit(GameMessage::Game_TextInputSubmitted(index, input_value.clone()))
             .padding(8)
-            .width(Length::Fill);
+            .width(Length::Fill)
+            .align_x(self.text_alignment(&story_state.language));
         
         let submit_button = if available && !input_value.is_empty() {
-            button(text(choice_text.clone()).size(self.font_size_base()))
+            button(self.localized_text(choice_text.clone(), &story_state.language).size(self.font_size_base()))
                 .on_press(GameMessage::Game_TextInputSubmitted(index, input_value.clone()))
                 .padding(10)
                 .width(Length::Fixed(120.0))
         } else {
-            button(text(choice_text.clone()).size(self.font_size_base()))
+            button(self.localized_text(choice_text.clone(), &story_state.language).size(self.font_size_base()))
                 .padding(10)
                 .width(Length::Fixed(120.0))
                 .style(self.create_disabled_button_style())
         };
         
-        row![text_input_widget, submit_button]
+        self.directional_row(&story_state.language, vec![text_input_widget.into(), submit_button.into()])
             .spacing(10)
             .align_y(iced::alignment::Vertical::Center)
             .into()
     }
 
-    fn create_regular_choice_button(&self, choice_text: String, index: usize, available: bool, story_state: &crate::crisis::GameState) -> iced::Element<'_, GameMessage> {
+    fn create_regular_choice_button(&self, choice_text: String, secondary_text: Option<(String, &str)>, index: usize, available: bool, story_state: &crate::crisis::GameState) -> iced::Element<'_, GameMessage> {
         if available {
-            button(text(choice_text.clone()).size(self.font_size_base()))
+            let mut label = column![
+                self.localized_text(choice_text.clone(), &story_state.language).size(self.font_size_base()).width(Length::Fill)
+            ];
+            if let Some((secondary_text, secondary_language)) = secondary_text {
+                label = label.push(
+                    self.localized_text(secondary_text, secondary_language)
+                        .size(self.font_size_small())
+                        .width(Length::Fill)
+                        .color(self.secondary_text_color())
+                );
+            }
+            button(label.spacing(2))
                 .on_press(GameMessage::Game_ChoiceSelected(index))
                 .padding(10)
                 .width(Length::Fill)
                 .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId("choice", index))))
                 .into()
         } else {
-            button(text(format!("{} {}", choice_text, 
-                TranslationUtils::translate(crate::translations::TranslationKey::RequirementsNotMet, &story_state.language))))
+            button(self.localized_text(format!("{} {}", choice_text, 
+                TranslationUtils::translate(crate::translations::TranslationKey::RequirementsNotMet, &story_state.language)), &story_state.language).width(Length::Fill))
                 .padding(10)
                 .width(Length::Fill)
                 .style(self.create_disabled_button_style())
@@ -366,8 +884,7 @@ impl GameWindow {
         let character_display = if let Some(ref char_image) = current_scene.speaking_character_image {
             match char_image {
                 crate::crisis::SpeakingCharacterImage::Single(char_path) => {
-                    if let Some(char_file) = crate::crisis::PlayableCrises::get(char_path) {
-                        let char_handle = iced::widget::image::Handle::from_bytes(char_file.data.as_ref().to_vec());
+                    if let Some(char_handle) = self.crisis_image(char_path) {
                         let char_img = Image::<iced::widget::image::Handle>::new(char_handle)
                             .width(Length::Fill);
                         container(char_img)
@@ -384,8 +901,7 @@ impl GameWindow {
                         let current_frame_index = self.animation_frame_index % image_paths.len();
                         let char_path = &image_paths[current_frame_index];
                         
-                        if let Some(char_file) = crate::crisis::PlayableCrises::get(char_path) {
-                            let char_handle = iced::widget::image::Handle::from_bytes(char_file.data.as_ref().to_vec());
+                        if let Some(char_handle) = self.crisis_image(char_path) {
                             let char_img = Image::<iced::widget::image::Handle>::new(char_handle)
                                 .width(Length::Fill);
                             container(char_img)
@@ -436,6 +952,181 @@ impl GameWindow {
         })
     }
 
+    fn create_reload_error_overlay(&self, reload_error: &str) -> Container<'_, GameMessage, Theme, iced::Renderer> {
+        container(
+            container(
+                row![
+                    text(reload_error.to_string())
+                        .size(self.font_size_small())
+                        .color(iced::Color::from_rgb(0.8, 0.2, 0.2))
+                        .wrapping(iced::widget::text::Wrapping::Word)
+                        .width(Length::Fill),
+                    button(self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::Dismiss, &self.settings_language), &self.settings_language).size(self.font_size_small()))
+                        .on_press(GameMessage::Game_ReloadErrorDismissed)
+                        .padding(8)
+                ]
+                .spacing(10)
+                .align_y(Center)
+            )
+            .width(Length::Fill)
+            .padding(10)
+            .style(move |_theme: &Theme| {
+                iced::widget::container::Style {
+                    background: Some(iced::Color::from_rgba(0.1, 0.0, 0.0, 0.9).into()),
+                    border: iced::border::rounded(4)
+                        .color(iced::Color::from_rgb(0.8, 0.2, 0.2))
+                        .width(1),
+                    ..iced::widget::container::Style::default()
+                }
+            })
+        )
+        .width(Length::Fill)
+        .padding(20)
+    }
+
+    /// Modal pause menu over the scene; the dimmed backdrop keeps clicks from reaching the game underneath.
+    /// It follows the interface language, so a language picked in its settings takes effect right away.
+    fn create_pause_menu_overlay(&self, view: PauseMenuView) -> Element<'_, GameMessage> {
+        use crate::translations::TranslationKey;
+        let language = &self.settings_language;
+        
+        let title_key = match view {
+            PauseMenuView::Main => TranslationKey::Paused,
+            PauseMenuView::Settings => TranslationKey::Settings,
+            PauseMenuView::Load => TranslationKey::LoadGame,
+            PauseMenuView::ConfirmQuit => TranslationKey::Quit,
+            PauseMenuView::ConfirmLoad => TranslationKey::LoadGame,
+        };
+        let mut content = column![
+            self.localized_text(TranslationUtils::translate(title_key, language), language).size(self.font_size_large())
+        ]
+        .spacing(12)
+        .align_x(Center)
+        .width(Length::Fill);
+        
+        match view {
+            PauseMenuView::Main => {
+                content = content
+                    .push(self.create_pause_menu_button(TranslationKey::Resume, GameMessage::Game_PauseMenuToggled, 0))
+                    .push(self.create_pause_menu_button(TranslationKey::SaveGame, GameMessage::Game_PauseSaveRequested, 1))
+                    .push(self.create_pause_menu_button(TranslationKey::LoadGame, GameMessage::Game_PauseMenuViewChanged(PauseMenuView::Load), 2))
+                    .push(self.create_pause_menu_button(TranslationKey::Settings, GameMessage::Game_PauseMenuViewChanged(PauseMenuView::Settings), 3));
+                if self.restart_allowed() {
+                    content = content.push(self.create_pause_menu_button(TranslationKey::RestartCrisis, GameMessage::Game_RestartCrisisRequested, 4));
+                }
+                content = content.push(self.create_pause_menu_button(TranslationKey::Quit, GameMessage::Game_PauseQuitRequested, 5));
+            }
+            PauseMenuView::Settings => {
+                content = content
+                    .push(self.build_font_scale_row(language))
+                    .push(self.build_volume_row(language))
+                    .push(self.build_reduce_motion_row(language))
+                    .push(self.build_theme_row(language))
+                    .push(self.build_language_row(language))
+                    .push(self.create_pause_menu_button(TranslationKey::Back, GameMessage::Game_PauseMenuViewChanged(PauseMenuView::Main), 6));
+            }
+            PauseMenuView::Load => {
+                let save_names = crate::crisis::get_saved_games().get_save_names();
+                if save_names.is_empty() {
+                    content = content.push(
+                        self.localized_text(TranslationUtils::translate(TranslationKey::NoSavedGames, language), language)
+                            .size(self.font_size_base())
+                            .color(self.secondary_text_color())
+                    );
+                } else {
+                    let mut saves = column![].spacing(8).width(Length::Fill);
+                    for (index, save_name) in save_names.into_iter().enumerate() {
+                        saves = saves.push(
+                            button(text(save_name.clone()).size(self.font_size_small()))
+                                .on_press(GameMessage::Game_PauseLoadRequested(save_name))
+                                .padding(8)
+                                .width(Length::Fill)
+                                .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::pause_load(index))))
+                        );
+                    }
+                    content = content.push(
+                        iced::widget::Scrollable::new(saves)
+                            .width(Length::Fill)
+                            .height(Length::Fixed(300.0))
+                    );
+                }
+                content = content.push(self.create_pause_menu_button(TranslationKey::Back, GameMessage::Game_PauseMenuViewChanged(PauseMenuView::Main), 6));
+            }
+            PauseMenuView::ConfirmQuit => {
+                content = content
+                    .push(
+                        self.localized_text(TranslationUtils::translate(TranslationKey::UnsavedProgress, language), language)
+                            .size(self.font_size_base())
+                            .wrapping(iced::widget::text::Wrapping::Word)
+                    )
+                    .push(self.create_pause_menu_button(TranslationKey::SaveAndQuit, GameMessage::Game_SaveAndQuitRequested, 7))
+                    .push(self.create_pause_menu_button(TranslationKey::QuitWithoutSaving, GameMessage::Game_QuitWithoutSaveRequested, 8))
+                    .push(self.create_pause_menu_button(TranslationKey::Cancel, GameMessage::Game_PauseMenuViewChanged(PauseMenuView::Main), 6));
+            }
+            PauseMenuView::ConfirmLoad => {
+                content = content
+                    .push(
+                        self.localized_text(TranslationUtils::translate(TranslationKey::UnsavedProgressLoad, language), language)
+                            .size(self.font_size_base())
+                            .wrapping(iced::widget::text::Wrapping::Word)
+                    )
+                    .push(self.create_pause_menu_button(TranslationKey::LoadWithoutSaving, GameMessage::Game_PauseLoadConfirmed, 9))
+                    .push(self.create_pause_menu_button(TranslationKey::Cancel, GameMessage::Game_PauseMenuViewChanged(PauseMenuView::Main), 6));
+            }
+        }
+        
+        // Save and load results
+        if let Some(ref status) = self.pause_menu_status {
+            content = content.push(
+                self.localized_text(status.clone(), language)
+                    .size(self.font_size_small())
+                    .color(self.secondary_text_color())
+                    .wrapping(iced::widget::text::Wrapping::Word)
+            );
+        }
+        
+        let panel = container(content)
+            .width(Length::Fixed(520.0))
+            .padding(24)
+            .style(|theme: &Theme| {
+                let palette = theme.extended_palette();
+                iced::widget::container::Style {
+                    background: Some(palette.background.base.color.into()),
+                    border: iced::border::rounded(8)
+                        .color(palette.background.strong.color)
+                        .width(1),
+                    ..iced::widget::container::Style::default()
+                }
+            });
+        
+        iced::widget::opaque(
+            container(panel)
+                .center_x(Length::Fill)
+                .center_y(Length::Fill)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .style(|_theme: &Theme| {
+                    iced::widget::container::Style {
+                        background: Some(iced::Color::from_rgba(0.0, 0.0, 0.0, 0.6).into()),
+                        ..iced::widget::container::Style::default()
+                    }
+                })
+        )
+    }
+
+    fn create_pause_menu_button(&self, label: crate::translations::TranslationKey, message: GameMessage, index: usize) -> Element<'_, GameMessage> {
+        button(
+            self.localized_text(TranslationUtils::translate(label, &self.settings_language), &self.settings_language)
+                .size(self.font_size_base())
+                .align_x(Center)
+        )
+        .on_press(message)
+        .padding(10)
+        .width(Length::Fixed(300.0))
+        .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::pause_button(index))))
+        .into()
+    }
+
     fn add_scene_error(&self, error_messages: &mut Vec<String>, scene_name: &str, message: &str) {
         error_messages.push(format!("Scene '{}': {}", scene_name, message));
     }
@@ -458,7 +1149,7 @@ impl GameWindow {
                     story_state.current_scene,
                     "!"
                 )).size(self.font_size_large()),
-                button(text(TranslationUtils::translate(crate::translations::TranslationKey::ReturnToMenu, &story_state.language)))
+                button(self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::ReturnToMenu, &story_state.language), &story_state.language))
                     .on_press(GameMessage::Game_RestartRequested)
                     .padding(10)
             ]
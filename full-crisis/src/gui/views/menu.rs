@@ -1,7 +1,7 @@
 use crate::gui::types::*;
 use crate::gui::helpers::TranslationUtils;
 use iced::widget::{
-    Container, Image, button, column, container, row, text, Space, Column, Row
+    Container, Image, button, column, container, pick_list, text, Space, Column, Row
 };
 use iced::{Center, Element, Length, Theme};
 
@@ -25,31 +25,71 @@ impl GameWindow {
         
         // Setup focus elements for the menu (not used directly here since we need immutable access)
         let _menu_elements = vec![
+            FocusId::menu_button(6), // Profile switcher
             FocusId::menu_button(0), // Continue Game  
             FocusId::menu_button(1), // New Game
             FocusId::menu_button(2), // Settings
+            FocusId::menu_button(5), // Statistics
             FocusId::menu_button(3), // Licenses
             FocusId::menu_button(4), // Quit Game
         ];
         
+        let profile_switcher = pick_list(
+            self.profile_choices(),
+            self.shown_profile_choice(),
+            GameMessage::Menu_ProfileSelected,
+        )
+        .width(Length::Fill)
+        .style(crate::gui::focused_pick_list_style(self.focus_state.is_focused(FocusId::menu_button(6))));
+        
+        // A picked profile is only switched to once confirmed
+        let profile_confirmation: Element<'_, GameMessage> = if self.pending_profile_switch.is_some() {
+            column![
+                self.localized_text(crate::translations::t(crate::translations::TranslationKey::SwitchProfilePrompt, user_language), user_language)
+                    .size(self.font_size_small())
+                    .width(Length::Fill),
+                button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::SwitchProfile, user_language), user_language).size(self.font_size_base()))
+                    .on_press(GameMessage::Menu_ProfileSwitchConfirmed)
+                    .width(Length::Fill)
+                    .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::menu_button(7)))),
+                button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::Cancel, user_language), user_language).size(self.font_size_base()))
+                    .on_press(GameMessage::Menu_ProfileSwitchCancelled)
+                    .width(Length::Fill)
+                    .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::menu_button(8)))),
+            ]
+            .spacing(10)
+            .into()
+        } else {
+            Space::with_height(0).into()
+        };
+        
         let buttons = column![
-            button(text(TranslationUtils::translate(crate::translations::TranslationKey::ContinueGame, user_language)).size(self.font_size_base()))
+            self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::Profile, user_language), user_language)
+                .size(self.font_size_small())
+                .width(Length::Fill),
+            profile_switcher,
+            profile_confirmation,
+            button(self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::ContinueGame, user_language), user_language).size(self.font_size_base()))
                 .on_press(GameMessage::Menu_ContinueGameRequested)
                 .width(Length::Fill)
                 .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::menu_button(0)))),
-            button(text(TranslationUtils::translate(crate::translations::TranslationKey::NewGame, user_language)).size(self.font_size_base()))
+            button(self.localized_text(TranslationUtils::translate(crate::translations::TranslationKey::NewGame, user_language), user_language).size(self.font_size_base()))
                 .on_press(GameMessage::Menu_NewGameRequested)
                 .width(Length::Fill)
                 .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::menu_button(1)))),
-            button(text(crate::translations::t(crate::translations::TranslationKey::Settings, user_language)).size(self.font_size_base()))
+            button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::Settings, user_language), user_language).size(self.font_size_base()))
                 .on_press(GameMessage::Menu_SettingsRequested)
                 .width(Length::Fill)
                 .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::menu_button(2)))),
-            button(text(crate::translations::t(crate::translations::TranslationKey::Licenses, user_language)).size(self.font_size_base()))
+            button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::Statistics, user_language), user_language).size(self.font_size_base()))
+                .on_press(GameMessage::Menu_StatsRequested)
+                .width(Length::Fill)
+                .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::menu_button(5)))),
+            button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::Licenses, user_language), user_language).size(self.font_size_base()))
                 .on_press(GameMessage::Menu_LicensesRequested)
                 .width(Length::Fill)
                 .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::menu_button(3)))),
-            button(text(crate::translations::t(crate::translations::TranslationKey::QuitGame, user_language)).size(self.font_size_base()))
+            button(self.localized_text(crate::translations::t(crate::translations::TranslationKey::QuitGame, user_language), user_language).size(self.font_size_base()))
                 .on_press(GameMessage::QuitGameRequested)
                 .width(Length::Fill)
                 .style(crate::gui::focused_button_style(self.focus_state.is_focused(FocusId::menu_button(4)))),
@@ -67,7 +107,8 @@ impl GameWindow {
             .align_x(Center)
             .center_y(iced::Length::Shrink);
 
-        let foreground_content = row![buttons, right_panel]
+        // Menu buttons sit on the reading-start side, left or right
+        let foreground_content = self.directional_row(user_language, vec![buttons.into(), right_panel.into()])
             .height(Length::Fill)
             .width(Length::Shrink);
 
@@ -99,4 +140,39 @@ impl GameWindow {
         .height(Length::Fill)
         .into()
     }
+
+    /// Every profile, then an entry that creates a new one
+    pub fn profile_choices(&self) -> Vec<ProfileChoice> {
+        let mut choices: Vec<ProfileChoice> = self.profiles.profiles.iter()
+            .enumerate()
+            .map(|(index, profile)| self.profile_choice(index, profile))
+            .collect();
+        choices.push(ProfileChoice {
+            profile_id: None,
+            label: crate::translations::t(crate::translations::TranslationKey::NewProfile, &self.settings_language),
+        });
+        choices
+    }
+
+    pub fn active_profile_choice(&self) -> Option<ProfileChoice> {
+        self.profiles.profiles.iter()
+            .enumerate()
+            .find(|(_, profile)| profile.id == self.profiles.active)
+            .map(|(index, profile)| self.profile_choice(index, profile))
+    }
+
+    /// The profile waiting for confirmation, else the active one
+    pub fn shown_profile_choice(&self) -> Option<ProfileChoice> {
+        self.pending_profile_switch.clone().or_else(|| self.active_profile_choice())
+    }
+
+    /// A profile whose name was cleared is listed by its number
+    fn profile_choice(&self, index: usize, profile: &crate::profile::Profile) -> ProfileChoice {
+        let label = if profile.name.trim().is_empty() {
+            crate::profile::numbered_profile_name(index + 1, &self.settings_language)
+        } else {
+            profile.name.clone()
+        };
+        ProfileChoice { profile_id: Some(profile.id.clone()), label }
+    }
 }
\ No newline at end of file
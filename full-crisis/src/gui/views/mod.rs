@@ -1,5 +1,9 @@
 pub mod menu;
 pub mod game;
+pub mod crisis_browser;
+pub mod stats;
 
 pub use menu::*;
-pub use game::*;
\ No newline at end of file
+pub use game::*;
+pub use crisis_browser::*;
+pub use stats::*;
\ No newline at end of file
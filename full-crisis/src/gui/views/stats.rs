@@ -0,0 +1,149 @@
+use crate::gui::types::*;
+use crate::translations::{t, t_vars, TranslationKey};
+use iced::widget::{Container, Scrollable, column, container};
+use iced::{Element, Length, Theme};
+use std::collections::HashMap;
+
+impl GameWindow {
+    /// Totals across every crisis, then one panel per installed crisis with what the player has done in it
+    pub fn build_stats_ui(&self) -> Container<'_, GameMessage> {
+        let language = &self.settings_language;
+
+        let mut panels = column![
+            self.build_stats_panel(0, t(TranslationKey::AllCrisesTotals, language), self.stats_total_lines(language))
+        ]
+        .spacing(10)
+        .padding(10);
+
+        let catalog = crate::crisis::CrisisCatalog::get();
+        for (index, entry) in self.stats_entries(&catalog).into_iter().enumerate() {
+            panels = panels.push(self.build_stats_panel(
+                index + 1,
+                entry.localized_name(language),
+                self.crisis_stats_lines(&entry.template_name, language),
+            ));
+        }
+
+        let layout = column![Scrollable::new(panels).width(Length::Fill).height(Length::Fill)]
+            .spacing(10)
+            .padding(10)
+            .height(Length::Fill)
+            .width(Length::Fill);
+
+        let self_theme = self.theme();
+        Container::<GameMessage, Theme, iced::Renderer>::new(layout)
+            .width(Length::Fixed(crate::gui::ui_builders::MAIN_MENU_RIGHT_WIDTH_PX))
+            .height(Length::Fixed(crate::gui::ui_builders::MAIN_MENU_HEIGHT_PX))
+            .style(move |_theme| crate::gui::menu_right_box_style(&self_theme))
+            .padding(10)
+    }
+
+    fn build_stats_panel(&self, index: usize, title: String, lines: Vec<String>) -> Element<'_, GameMessage> {
+        let language = &self.settings_language;
+        let is_focused = self.focus_state.is_focused(FocusId::stats_row(index));
+
+        let mut details = column![self.localized_text(title, language).size(self.font_size_base())]
+            .spacing(4)
+            .width(Length::Fill)
+            .align_x(self.text_alignment(language));
+        for line in lines {
+            details = details.push(
+                self.localized_text(line, language)
+                    .size(self.font_size_small())
+                    .wrapping(iced::widget::text::Wrapping::Word)
+            );
+        }
+
+        container(details)
+            .padding(8)
+            .width(Length::Fill)
+            .style(crate::gui::stats_panel_style(is_focused))
+            .into()
+    }
+
+    /// Installed crises in the order the Stats screen lists them, by name
+    pub fn stats_entries<'a>(&self, catalog: &'a crate::crisis::CrisisCatalog) -> Vec<&'a crate::crisis::CrisisCatalogEntry> {
+        catalog.browse(&crate::crisis::CrisisQuery::default(), &self.settings_language)
+    }
+
+    /// Crises played, runs, completions and endings reached, across every installed crisis
+    pub fn stats_total_lines(&self, language: &str) -> Vec<String> {
+        let catalog = crate::crisis::CrisisCatalog::get();
+        let entries = self.stats_entries(&catalog);
+        let played: Vec<&crate::crisis::CrisisStats> = entries.iter()
+            .filter_map(|entry| self.player_stats.get(&entry.template_name))
+            .filter(|stats| stats.runs > 0)
+            .collect();
+
+        let mut vars = HashMap::new();
+        vars.insert("played".to_string(), played.len().to_string());
+        vars.insert("total".to_string(), entries.len().to_string());
+        let crises_played = t_vars(TranslationKey::CrisesPlayed, language, &vars);
+
+        let mut vars = HashMap::new();
+        vars.insert("reached".to_string(), played.iter().map(|stats| stats.endings_reached.len()).sum::<usize>().to_string());
+        vars.insert("total".to_string(), played.iter().map(|stats| stats.ending_count).sum::<usize>().to_string());
+        let endings = t_vars(TranslationKey::EndingsReached, language, &vars);
+
+        vec![
+            crises_played,
+            format!(
+                "{} · {}",
+                count_line(TranslationKey::RunCount, language, played.iter().map(|stats| stats.runs).sum::<u32>()),
+                count_line(TranslationKey::CompletionCount, language, played.iter().map(|stats| stats.completions).sum::<u32>()),
+            ),
+            endings,
+        ]
+    }
+
+    /// Everything recorded for one crisis, or a single line saying it has not been played
+    pub fn crisis_stats_lines(&self, template_name: &str, language: &str) -> Vec<String> {
+        let Some(stats) = self.player_stats.get(template_name).filter(|stats| stats.runs > 0) else {
+            return vec![t(TranslationKey::NotPlayedYet, language)];
+        };
+
+        let mut lines = vec![
+            Self::crisis_progress_line(stats, language),
+            format!(
+                "{} · {}",
+                count_line(TranslationKey::RunCount, language, stats.runs),
+                count_line(TranslationKey::CompletionCount, language, stats.completions),
+            ),
+        ];
+
+        let bests: Vec<String> = [
+            stats.best_time_ms.map(|time| var_line(TranslationKey::BestTime, language, "time", crate::crisis::stats::format_play_time(time))),
+            stats.best_score.map(|score| var_line(TranslationKey::BestScore, language, "score", score.to_string())),
+        ].into_iter().flatten().collect();
+        if !bests.is_empty() {
+            lines.push(bests.join(" · "));
+        }
+
+        if let Some(last_played) = stats.last_played {
+            lines.push(var_line(TranslationKey::LastPlayed, language, "date", crate::crisis::stats::format_date(last_played)));
+        }
+        lines
+    }
+
+    /// Endings reached and share of scenes discovered, e.g. "Endings: 2/4 · Explored: 60%"
+    pub fn crisis_progress_line(stats: &crate::crisis::CrisisStats, language: &str) -> String {
+        let mut vars = HashMap::new();
+        vars.insert("reached".to_string(), stats.endings_reached.len().to_string());
+        vars.insert("total".to_string(), stats.ending_count.to_string());
+        format!(
+            "{} · {}",
+            t_vars(TranslationKey::EndingsReached, language, &vars),
+            var_line(TranslationKey::ScenesExplored, language, "percent", stats.discovered_percent().to_string()),
+        )
+    }
+}
+
+fn count_line(key: TranslationKey, language: &str, count: u32) -> String {
+    var_line(key, language, "count", count.to_string())
+}
+
+fn var_line(key: TranslationKey, language: &str, name: &str, value: String) -> String {
+    let mut vars = HashMap::new();
+    vars.insert(name.to_string(), value);
+    t_vars(key, language, &vars)
+}
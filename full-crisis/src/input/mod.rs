@@ -113,7 +113,7 @@ pub fn controller_input_to_game_message(input: ControllerInput) -> GameMessage {
         ControllerInput::ActionSecondary => GameMessage::QuitGameRequested, // Back/escape functionality
         ControllerInput::LeftShoulder => GameMessage::Focus_ShiftTabInteract, // Shift+Tab equivalent (L1/LB)
         ControllerInput::RightShoulder => GameMessage::Focus_TabInteract,     // Tab equivalent (R1/RB)
-        ControllerInput::Start => GameMessage::Menu_SettingsRequested, // Menu access
+        ControllerInput::Start => GameMessage::StartButtonPressed, // Pause menu in game, settings elsewhere
         _ => GameMessage::Nop, // Other inputs not mapped yet
     }
 }
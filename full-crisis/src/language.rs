@@ -1,7 +1,22 @@
 use isolang::Language;
 
-/// Available languages in the game with their display names
+/// Available languages in the game with their display names, plus any language
+/// added by a translation override file in the crises folder
 pub fn get_available_languages() -> Vec<(String, String)> {
+    let mut languages = get_builtin_languages();
+    for code in crate::translations::get_translation_manager().languages() {
+        if !languages.iter().any(|(lang_code, _)| *lang_code == code) {
+            let display_name = Language::from_639_3(&code)
+                .map(|lang| lang.to_name().to_string())
+                .unwrap_or_else(|| code.clone());
+            languages.push((code, display_name));
+        }
+    }
+    languages
+}
+
+/// The languages the game ships UI translations for
+fn get_builtin_languages() -> Vec<(String, String)> {
     vec![
         ("eng".to_string(), "English".to_string()),
         ("spa".to_string(), "Español".to_string()),
@@ -13,9 +28,43 @@ pub fn get_available_languages() -> Vec<(String, String)> {
         ("jpn".to_string(), "日本語".to_string()),
         ("kor".to_string(), "한국어".to_string()),
         ("zho".to_string(), "中文".to_string()),
+        ("ara".to_string(), "العربية".to_string()),
+        ("heb".to_string(), "עברית".to_string()),
     ]
 }
 
+/// Reading direction of a language; the GUI mirrors its layout for right-to-left languages.
+/// Written `meta.direction = "rtl"` in a translation catalog.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+pub enum TextDirection {
+    #[default]
+    #[serde(rename = "ltr")]
+    LeftToRight,
+    #[serde(rename = "rtl")]
+    RightToLeft,
+}
+
+impl TextDirection {
+    pub fn is_rtl(self) -> bool {
+        self == TextDirection::RightToLeft
+    }
+}
+
+/// Languages whose text needs full shaping (joining, bidi or CJK line breaking) to render correctly
+const COMPLEX_SCRIPT_LANGUAGES: &[&str] = &[
+    "jpn", "kor", "zho", "hin", "tha",
+];
+
+/// Get the reading direction of a language code, as declared by its translation catalog
+pub fn get_text_direction(code: &str) -> TextDirection {
+    crate::translations::get_translation_manager().text_direction(code)
+}
+
+/// Whether text in this language must be laid out with advanced shaping
+pub fn needs_advanced_shaping(code: &str) -> bool {
+    get_text_direction(code).is_rtl() || COMPLEX_SCRIPT_LANGUAGES.contains(&code)
+}
+
 /// Convert ISO 639-1 (2-letter) to ISO 639-3 (3-letter) language code
 pub fn convert_language_code(input: &str) -> String {
     if input.len() == 2 {
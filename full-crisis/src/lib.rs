@@ -31,8 +31,14 @@ pub mod crisis;
 pub mod language;
 /// Central translation system for GUI elements
 pub mod translations;
+/// Local player profiles, each with its own settings, saved games and statistics
+pub mod profile;
+/// Settings an instructor or administrator locks for every player
+pub mod policy;
 /// Audio management and embedded audio assets
 pub mod main_audio;
+/// Embedded UI fonts covering Latin, Cyrillic, Arabic, Hebrew and CJK text
+pub mod fonts;
 /// Game controller input abstraction for cross-platform support
 pub mod input;
 
@@ -42,6 +48,14 @@ mod wasm32_storage;
 #[cfg(not(target_arch = "wasm32"))]
 mod native_storage;
 
+// Browser file picker for crisis packs
+#[cfg(target_arch = "wasm32")]
+mod wasm32_upload;
+
+// Browser download of the fonts the web build does not embed
+#[cfg(target_arch = "wasm32")]
+mod wasm32_fonts;
+
 #[cfg(target_arch = "wasm32")]
 use wasm32_storage as internal_storage;
 #[cfg(not(target_arch = "wasm32"))]
@@ -58,6 +72,28 @@ use wasm32_time as internal_time;
 #[cfg(not(target_arch = "wasm32"))]
 use native_time as internal_time;
 
+// Platform-specific screen reader bridges
+#[cfg(target_arch = "wasm32")]
+mod wasm32_accessibility;
+#[cfg(not(target_arch = "wasm32"))]
+mod native_accessibility;
+
+#[cfg(target_arch = "wasm32")]
+use wasm32_accessibility as internal_accessibility;
+#[cfg(not(target_arch = "wasm32"))]
+use native_accessibility as internal_accessibility;
+
+// Platform-specific admin policy sources
+#[cfg(target_arch = "wasm32")]
+mod wasm32_policy;
+#[cfg(not(target_arch = "wasm32"))]
+mod native_policy;
+
+#[cfg(target_arch = "wasm32")]
+use wasm32_policy as internal_policy;
+#[cfg(not(target_arch = "wasm32"))]
+use native_policy as internal_policy;
+
 
 
 pub static GAME: OnceCell<game::GameState> = OnceCell::new();
@@ -65,10 +101,12 @@ pub static OS_COLOR_THEME: OnceCell<game::OSColorTheme> = OnceCell::new();
 pub static VERBOSITY: OnceCell<u8> = OnceCell::new();
 pub static AUDIO_MANAGER: OnceCell<std::sync::Mutex<main_audio::AudioManager>> = OnceCell::new();
 pub static CONTROLLER_MANAGER: OnceCell<std::sync::Mutex<Box<dyn input::ControllerManager + Send>>> = OnceCell::new();
+/// Set by `--data-dir` to keep settings, saves and caches under one folder (portable installs)
+pub static DATA_DIR_OVERRIDE: OnceCell<std::path::PathBuf> = OnceCell::new();
 
-// Re-export WASM audio callback setter
+// Re-export WASM audio callback setters
 #[cfg(target_arch = "wasm32")]
-pub use gui::event_handlers::set_audio_callbacks;
+pub use gui::event_handlers::{set_audio_callbacks, set_volume_callback};
 
 pub fn init_global_vars() {
 
@@ -103,6 +141,69 @@ pub mod storage {
     pub fn set_attr(name: &str, value: &str) {
         super::internal_storage::set_attr(name, value)
     }
+
+    /// Like `get_attr`, for attributes each player profile keeps its own copy of
+    pub fn get_profile_attr(name: &str) -> Option<String> {
+        super::internal_storage::get_attr(&crate::profile::attr_name(name))
+    }
+
+    pub fn set_profile_attr(name: &str, value: &str) {
+        super::internal_storage::set_attr(&crate::profile::attr_name(name), value)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub use super::internal_storage::{StorageKind, storage_dir};
+}
+
+/// Screen reader output: the scene being read and the control that has focus, published
+/// through AccessKit on native platforms and ARIA live regions in the browser
+pub mod accessibility {
+    use once_cell::sync::Lazy;
+    use std::sync::Mutex;
+
+    /// How a screen reader should present the focused control
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ControlKind {
+        Button,
+        TextInput,
+        PickList,
+        Toggle(bool), // on or off
+        Slider,
+    }
+
+    /// The focused control, described in the player's language
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AccessibleControl {
+        pub kind: ControlKind,
+        pub label: String,
+        pub value: Option<String>, // Current selection, typed text or slider value
+    }
+
+    // Last published content, so screen readers do not repeat unchanged text every frame
+    static PUBLISHED: Lazy<Mutex<(String, Option<AccessibleControl>)>> = Lazy::new(|| Mutex::new((String::new(), None)));
+
+    /// Sets the text read out as the current scene; empty outside a game
+    pub fn set_scene_text(text: &str) {
+        if let Ok(mut published) = PUBLISHED.lock() {
+            if published.0 != text {
+                published.0 = text.to_string();
+                super::internal_accessibility::set_scene_text(text);
+            }
+        }
+    }
+
+    /// Announces the control that keyboard or controller focus moved to
+    pub fn set_focused_control(control: Option<AccessibleControl>) {
+        if let Ok(mut published) = PUBLISHED.lock() {
+            if published.1 != control {
+                published.1 = control.clone();
+                super::internal_accessibility::set_focused_control(control);
+            }
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub use super::internal_accessibility::attach_to_window;
 }
 
 /// Public time functions for cross-platform time management
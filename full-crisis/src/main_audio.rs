@@ -8,6 +8,12 @@ use rodio::{Decoder, OutputStreamBuilder, Sink};
 #[folder = "$AUDIO_BUILD_DIR"]
 pub struct AudioAssets;
 
+// Levels at full volume; the volume setting scales both
+#[cfg(not(target_arch = "wasm32"))]
+const BACKGROUND_MUSIC_LEVEL: f32 = 0.2; // Quieter for background
+#[cfg(not(target_arch = "wasm32"))]
+const SOUND_EFFECT_LEVEL: f32 = 0.5;
+
 pub struct AudioManager {
     #[cfg(not(target_arch = "wasm32"))]
     _stream: rodio::OutputStream,
@@ -15,6 +21,7 @@ pub struct AudioManager {
     background_sink: Option<Sink>,
     #[cfg(target_arch = "wasm32")]
     is_playing: bool,
+    volume: f32,
 }
 
 impl std::fmt::Debug for AudioManager {
@@ -34,6 +41,7 @@ impl AudioManager {
             Ok(AudioManager {
                 _stream,
                 background_sink: None,
+                volume: 1.0,
             })
         }
         
@@ -41,6 +49,7 @@ impl AudioManager {
         {
             Ok(AudioManager {
                 is_playing: false,
+                volume: 1.0,
             })
         }
     }
@@ -66,7 +75,7 @@ impl AudioManager {
                     }
                 }
                 
-                sink.set_volume(0.2);  // Quieter for background
+                sink.set_volume(BACKGROUND_MUSIC_LEVEL * self.volume);
                 self.background_sink = Some(sink);
             }
         }
@@ -108,7 +117,7 @@ impl AudioManager {
                 let source = Decoder::new(cursor)?;
                 let sink = Sink::connect_new(&self._stream.mixer());
                 sink.append(source);
-                sink.set_volume(0.5);
+                sink.set_volume(SOUND_EFFECT_LEVEL * self.volume);
                 sink.detach(); // Let it play independently
             }
         }
@@ -116,6 +125,24 @@ impl AudioManager {
         Ok(())
     }
     
+    /// Sets the volume from 0.0 (muted) to 1.0, including for music already playing
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume.clamp(0.0, 1.0);
+        
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(ref sink) = self.background_sink {
+                sink.set_volume(BACKGROUND_MUSIC_LEVEL * self.volume);
+            }
+        }
+        
+        #[cfg(target_arch = "wasm32")]
+        {
+            // Menu music and scene audio share one output in the browser
+            crate::gui::event_handlers::web_set_audio_volume(self.volume);
+        }
+    }
+    
     pub fn is_background_playing(&self) -> bool {
         #[cfg(not(target_arch = "wasm32"))]
         {
@@ -139,6 +166,7 @@ impl Default for AudioManager {
                     AudioManager {
                         _stream,
                         background_sink: None,
+                        volume: 1.0,
                     }
                 } else {
                     // If even fallback fails, create a minimal struct
@@ -150,6 +178,7 @@ impl Default for AudioManager {
             {
                 AudioManager {
                     is_playing: false,
+                    volume: 1.0,
                 }
             }
         })
@@ -0,0 +1,175 @@
+/// Native screen reader bridge using AccessKit. iced draws its own widgets and exposes none
+/// of them to the platform, so this publishes a small tree of its own for the game window:
+/// the scene text as a live region, and the focused control, which screen readers follow.
+use accesskit::{Action, ActionHandler, ActionRequest, ActivationHandler, DeactivationHandler, Live, Node, NodeId, Role, Toggled, Tree, TreeUpdate};
+use crate::accessibility::{AccessibleControl, ControlKind};
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+const WINDOW_NODE: NodeId = NodeId(0);
+const SCENE_NODE: NodeId = NodeId(1);
+// Every focus change gets a new node id, so it is announced even when two controls share a label
+const FIRST_CONTROL_NODE: u64 = 2;
+
+#[derive(Debug, Clone, Default)]
+struct AccessibleContent {
+    scene_text: String,
+    focused: Option<AccessibleControl>,
+    focus_serial: u64,
+}
+
+static CONTENT: once_cell::sync::Lazy<Mutex<AccessibleContent>> = once_cell::sync::Lazy::new(|| Mutex::new(AccessibleContent::default()));
+
+enum PlatformAdapter {
+    #[cfg(target_os = "windows")]
+    Windows(accesskit_windows::SubclassingAdapter),
+    #[cfg(target_os = "macos")]
+    MacOs(accesskit_macos::SubclassingAdapter),
+    #[cfg(all(unix, not(target_os = "macos")))]
+    Unix(accesskit_unix::Adapter),
+}
+
+thread_local! {
+    // The Windows and macOS adapters hold on to the native window, so they stay on the GUI thread
+    static ADAPTER: RefCell<Option<PlatformAdapter>> = const { RefCell::new(None) };
+}
+
+/// Answers the screen reader's first request for the tree, which may come long after startup
+struct TreeSource;
+
+impl ActivationHandler for TreeSource {
+    fn request_initial_tree(&mut self) -> Option<TreeUpdate> {
+        CONTENT.lock().ok().map(|content| build_tree(&content))
+    }
+}
+
+/// Focus is driven by the keyboard and controller, so requests from the screen reader are ignored
+struct IgnoredRequests;
+
+impl ActionHandler for IgnoredRequests {
+    fn do_action(&mut self, _request: ActionRequest) {}
+}
+
+impl DeactivationHandler for IgnoredRequests {
+    fn deactivate_accessibility(&mut self) {}
+}
+
+/// Connects the game window to the platform's accessibility API. Call on the GUI thread.
+pub fn attach_to_window(window: raw_window_handle::RawWindowHandle) {
+    let adapter = match window {
+        #[cfg(target_os = "windows")]
+        raw_window_handle::RawWindowHandle::Win32(handle) => PlatformAdapter::Windows(
+            accesskit_windows::SubclassingAdapter::new(accesskit_windows::HWND(handle.hwnd.get() as *mut _), TreeSource, IgnoredRequests)
+        ),
+        #[cfg(target_os = "macos")]
+        raw_window_handle::RawWindowHandle::AppKit(handle) => PlatformAdapter::MacOs(
+            // Safety: the view belongs to the game window, which lives as long as the application
+            unsafe { accesskit_macos::SubclassingAdapter::new(handle.ns_view.as_ptr(), TreeSource, IgnoredRequests) }
+        ),
+        #[cfg(all(unix, not(target_os = "macos")))]
+        _ => {
+            let mut adapter = accesskit_unix::Adapter::new(TreeSource, IgnoredRequests, IgnoredRequests);
+            adapter.update_window_focus_state(true);
+            PlatformAdapter::Unix(adapter)
+        }
+        #[allow(unreachable_patterns)]
+        _ => {
+            eprintln!("Screen reader support is not available for this window system");
+            return;
+        }
+    };
+    ADAPTER.with(|cell| *cell.borrow_mut() = Some(adapter));
+}
+
+pub fn set_scene_text(text: &str) {
+    if let Ok(mut content) = CONTENT.lock() {
+        content.scene_text = text.to_string();
+    }
+    publish();
+}
+
+pub fn set_focused_control(control: Option<AccessibleControl>) {
+    if let Ok(mut content) = CONTENT.lock() {
+        content.focused = control;
+        content.focus_serial = content.focus_serial.wrapping_add(1);
+    }
+    publish();
+}
+
+/// Sends the current content to the screen reader, if one is listening
+fn publish() {
+    // Copied out so the adapter never runs while the content is locked
+    let Some(content) = CONTENT.lock().ok().map(|content| content.clone()) else {
+        return;
+    };
+    ADAPTER.with(|cell| {
+        match cell.borrow_mut().as_mut() {
+            #[cfg(target_os = "windows")]
+            Some(PlatformAdapter::Windows(adapter)) => {
+                if let Some(events) = adapter.update_if_active(|| build_tree(&content)) {
+                    events.raise();
+                }
+            }
+            #[cfg(target_os = "macos")]
+            Some(PlatformAdapter::MacOs(adapter)) => {
+                if let Some(events) = adapter.update_if_active(|| build_tree(&content)) {
+                    events.raise();
+                }
+            }
+            #[cfg(all(unix, not(target_os = "macos")))]
+            Some(PlatformAdapter::Unix(adapter)) => {
+                adapter.update_if_active(|| build_tree(&content));
+            }
+            None => {}
+        }
+    });
+}
+
+fn build_tree(content: &AccessibleContent) -> TreeUpdate {
+    let mut window = Node::new(Role::Window);
+    window.set_label("Full Crisis");
+
+    let mut scene = Node::new(Role::Label);
+    scene.set_value(content.scene_text.clone());
+    scene.set_live(Live::Polite);
+
+    let mut children = vec![SCENE_NODE];
+    let mut control_node = None;
+    if let Some(ref control) = content.focused {
+        let id = NodeId(FIRST_CONTROL_NODE + content.focus_serial);
+        children.push(id);
+        control_node = Some((id, build_control(control)));
+    }
+    window.set_children(children);
+
+    let focus = control_node.as_ref().map(|(id, _)| *id).unwrap_or(WINDOW_NODE);
+    let mut nodes = vec![(WINDOW_NODE, window), (SCENE_NODE, scene)];
+    nodes.extend(control_node);
+    TreeUpdate {
+        nodes,
+        tree: Some(Tree::new(WINDOW_NODE)),
+        focus,
+    }
+}
+
+fn build_control(control: &AccessibleControl) -> Node {
+    let mut node = Node::new(match control.kind {
+        ControlKind::Button => Role::Button,
+        ControlKind::TextInput => Role::TextInput,
+        ControlKind::PickList => Role::ComboBox,
+        ControlKind::Toggle(_) => Role::Switch,
+        ControlKind::Slider => Role::Slider,
+    });
+    node.set_label(control.label.clone());
+    node.add_action(Action::Focus);
+    match control.kind {
+        // Screen readers say "on" and "off" themselves for switches
+        ControlKind::Toggle(on) => node.set_toggled(if on { Toggled::True } else { Toggled::False }),
+        _ => {
+            if let Some(ref value) = control.value {
+                node.set_value(value.clone());
+            }
+        }
+    }
+    node
+}
@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+
+/// Places a policy is looked for, first match wins: next to the executable for portable
+/// installs, then the system-wide configuration folder, which players cannot write to
+fn policy_paths() -> Vec<PathBuf> {
+  let mut paths = vec![];
+  if let Some(exe_dir) = std::env::current_exe().ok().and_then(|exe| exe.parent().map(|dir| dir.to_path_buf())) {
+    paths.push(exe_dir.join(crate::policy::POLICY_FILE_NAME));
+  }
+  #[cfg(target_os = "windows")]
+  if let Some(program_data) = std::env::var_os("ProgramData") {
+    paths.push(PathBuf::from(program_data).join("Full-Crisis").join(crate::policy::POLICY_FILE_NAME));
+  }
+  #[cfg(target_os = "macos")]
+  paths.push(PathBuf::from("/Library/Application Support/Full-Crisis").join(crate::policy::POLICY_FILE_NAME));
+  #[cfg(all(unix, not(target_os = "macos")))]
+  paths.push(PathBuf::from("/etc/full-crisis").join(crate::policy::POLICY_FILE_NAME));
+  paths
+}
+
+/// Where the policy was found and its contents
+pub fn read_policy() -> Option<(String, String)> {
+  for path in policy_paths() {
+    if !path.is_file() {
+      continue;
+    }
+    match std::fs::read_to_string(&path) {
+      Ok(contents) => return Some((format!("{:?}", path), contents)),
+      Err(e) => {
+        eprintln!("Error reading the admin policy {:?}: {:?}", &path, e);
+      }
+    }
+  }
+  None
+}
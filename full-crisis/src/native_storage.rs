@@ -1,21 +1,108 @@
 use std::hash::Hasher;
+use std::path::PathBuf;
 use std::time::SystemTime;
 use twox_hash::XxHash64;
 
+/// Where on disk an attribute belongs. Settings go in the OS config directory,
+/// saves in the data directory, and everything else is treated as a
+/// disposable cache which OS cleaners are allowed to wipe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageKind {
+  Config,
+  Data,
+  Cache,
+}
+
+impl StorageKind {
+  pub fn for_attr(name: &str) -> Self {
+    // Per-profile attributes are named "<attr>.<profile id>"
+    match name.split('.').next().unwrap_or(name) {
+      "game_settings" | "profiles" => StorageKind::Config,
+      "saved_games" | "player_stats" => StorageKind::Data,
+      _ => StorageKind::Cache,
+    }
+  }
+}
+
+fn project_dirs() -> Option<directories::ProjectDirs> {
+  directories::ProjectDirs::from("com.jmcateer.full-crisis", "Full-Crisis",  "Full-Crisis")
+}
+
+/// Returns the directory used for the given kind of data, honoring the
+/// `--data-dir` override used by portable installs.
+pub fn storage_dir(kind: StorageKind) -> Option<PathBuf> {
+  if let Some(base_dir) = crate::DATA_DIR_OVERRIDE.get() {
+    let sub_dir = match kind {
+      StorageKind::Config => "config",
+      StorageKind::Data => "data",
+      StorageKind::Cache => "cache",
+    };
+    return Some(base_dir.join(sub_dir));
+  }
+  let proj_dirs = project_dirs()?;
+  Some(match kind {
+    StorageKind::Config => proj_dirs.config_dir().to_path_buf(),
+    StorageKind::Data => proj_dirs.data_dir().to_path_buf(),
+    StorageKind::Cache => proj_dirs.cache_dir().to_path_buf(),
+  })
+}
+
+fn attr_file_name(name: &str) -> String {
+  let safe_name: String = name.chars()
+    .map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == '-' { c } else { '_' })
+    .collect();
+  format!("{}.json", safe_name)
+}
+
+fn attr_path(name: &str) -> Option<PathBuf> {
+  let dir = storage_dir(StorageKind::for_attr(name))?;
+  if let Err(e) = std::fs::create_dir_all(&dir) {
+    eprintln!("Error creating {:?}: {:?}", dir, e);
+  }
+  let path = dir.join(attr_file_name(name));
+  migrate_legacy_attr(name, &path);
+  Some(path)
+}
+
 fn attr_hash_name(name: &str) -> String {
   let mut hasher = XxHash64::with_seed(0);
   hasher.write(name.as_bytes());
   format!("{:016x}", hasher.finish())
 }
 
-pub fn get_attr(name: &str) -> Option<String> {
-  if let Some(proj_dirs) = directories::ProjectDirs::from("com.jmcateer.full-crisis", "Full-Crisis",  "Full-Crisis") {
-    let cache_dir = proj_dirs.cache_dir();
-    if let Err(e) = std::fs::create_dir_all(cache_dir) {
-      eprintln!("Error creating {:?}: {:?}", cache_dir, e);
+/// Older builds stored every attribute in the OS cache directory under a hashed
+/// file name. If one of those files is still around, move it to its new home.
+/// Portable installs never used that layout, so nothing is migrated into them.
+fn migrate_legacy_attr(name: &str, new_path: &PathBuf) {
+  if crate::DATA_DIR_OVERRIDE.get().is_some() || new_path.exists() {
+    return;
+  }
+  let legacy_path = match project_dirs() {
+    Some(proj_dirs) => proj_dirs.cache_dir().join(attr_hash_name(name)),
+    None => return,
+  };
+  if !legacy_path.exists() {
+    return;
+  }
+  match std::fs::read_to_string(&legacy_path) {
+    Ok(content) => {
+      if let Err(e) = std::fs::write(new_path, content) {
+        eprintln!("Error migrating the attribute \"{}\" from {:?} to {:?}: {:?}", name, &legacy_path, new_path, e);
+        return;
+      }
+      if let Err(e) = std::fs::remove_file(&legacy_path) {
+        eprintln!("Error removing legacy attribute file {:?}: {:?}", &legacy_path, e);
+      }
+      eprintln!("Migrated the attribute \"{}\" from {:?} to {:?}", name, &legacy_path, new_path);
     }
-    let mut attr_file = cache_dir.to_path_buf();
-    attr_file.push(attr_hash_name(name));
+    Err(e) => {
+      eprintln!("Error reading legacy attribute \"{}\" from {:?}: {:?}", name, &legacy_path, e);
+    }
+  }
+}
+
+pub fn get_attr(name: &str) -> Option<String> {
+  if let Some(attr_file) = attr_path(name) {
     if attr_file.exists() {
       match std::fs::read_to_string(&attr_file) {
         Ok(content) => return Some(content),
@@ -24,24 +111,15 @@ pub fn get_attr(name: &str) -> Option<String> {
         }
       }
     }
-
   }
   None
 }
 
 pub fn set_attr(name: &str, value: &str) {
-  if let Some(proj_dirs) = directories::ProjectDirs::from("com.jmcateer.full-crisis", "Full-Crisis",  "Full-Crisis") {
-    let cache_dir = proj_dirs.cache_dir();
-    if let Err(e) = std::fs::create_dir_all(cache_dir) {
-      eprintln!("Error creating {:?}: {:?}", cache_dir, e);
-    }
-    let mut attr_file = cache_dir.to_path_buf();
-    attr_file.push(attr_hash_name(name));
-
+  if let Some(attr_file) = attr_path(name) {
     if let Err(e) = std::fs::write(&attr_file, value) {
       eprintln!("Error writing the attribute \"{}\" to {:?}: {:?}", name, &attr_file, e);
     }
-
   }
 }
 
@@ -0,0 +1,58 @@
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+/// File an instructor or administrator places where players cannot edit it
+pub const POLICY_FILE_NAME: &str = "policy.toml";
+
+static POLICY: Lazy<AdminPolicy> = Lazy::new(AdminPolicy::load);
+
+/// Settings fixed for every player and profile by whoever set up the machine or hosts the
+/// web build, e.g. a classroom that should only play crises signed by the instructor.
+/// Fields left out of the policy stay under the player's control.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AdminPolicy {
+    pub require_signed_crises: Option<bool>,
+    pub trusted_keys: Option<Vec<String>>,
+}
+
+impl AdminPolicy {
+    /// The policy read at startup; an empty one when there is none
+    pub fn get() -> &'static AdminPolicy {
+        &POLICY
+    }
+
+    fn load() -> AdminPolicy {
+        let Some((origin, contents)) = crate::internal_policy::read_policy() else {
+            return AdminPolicy::default();
+        };
+        match toml::from_str::<AdminPolicy>(&contents) {
+            Ok(policy) => {
+                eprintln!("Using the admin policy from {}", origin);
+                policy
+            }
+            Err(e) => {
+                // A broken policy must not quietly unlock what it was written to lock
+                eprintln!("Invalid admin policy {}: {}; only built in and trusted crises can be played", origin, e);
+                AdminPolicy {
+                    require_signed_crises: Some(true),
+                    trusted_keys: None,
+                }
+            }
+        }
+    }
+
+    pub fn locks_signing(&self) -> bool {
+        self.require_signed_crises.is_some() || self.trusted_keys.is_some()
+    }
+
+    /// Replaces the player's choices with the policy's wherever it sets one
+    pub fn apply(&self, settings: &mut crate::gui::GameSettings) {
+        if let Some(require_signed_crises) = self.require_signed_crises {
+            settings.require_signed_crises = require_signed_crises;
+        }
+        if let Some(ref trusted_keys) = self.trusted_keys {
+            settings.trusted_keys = trusted_keys.clone();
+        }
+    }
+}
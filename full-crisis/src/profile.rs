@@ -0,0 +1,130 @@
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+
+const PROFILES_ATTR: &str = "profiles";
+
+/// The profile that owns the settings, saves and statistics stored before profiles existed;
+/// its attributes keep their original names
+pub const DEFAULT_PROFILE_ID: &str = "default";
+
+/// A local player with their own settings, saved games and statistics
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    pub id: String, // Never changes; namespaces the profile's storage attributes
+    pub name: String,
+}
+
+/// Every profile on this machine and the one in use
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileList {
+    pub profiles: Vec<Profile>,
+    pub active: String,
+}
+
+impl ProfileList {
+    pub fn active_profile(&self) -> Option<&Profile> {
+        self.profiles.iter().find(|profile| profile.id == self.active)
+    }
+}
+
+// The active profile's ID, read from storage once instead of on every attribute lookup
+static ACTIVE_PROFILE: Lazy<RwLock<String>> = Lazy::new(|| RwLock::new(get_profiles().active));
+
+pub fn get_profiles() -> ProfileList {
+    let stored = crate::storage::get_attr(PROFILES_ATTR)
+        .and_then(|content| serde_json::from_str::<ProfileList>(&content).ok())
+        .filter(|list| !list.profiles.is_empty());
+    match stored {
+        Some(mut list) => {
+            if list.active_profile().is_none() {
+                list.active = list.profiles[0].id.clone();
+            }
+            list
+        }
+        None => ProfileList {
+            profiles: vec![Profile { id: DEFAULT_PROFILE_ID.to_string(), name: legacy_profile_name() }],
+            active: DEFAULT_PROFILE_ID.to_string(),
+        },
+    }
+}
+
+fn save_profiles(list: &ProfileList) {
+    if let Ok(serialized) = serde_json::to_string(list) {
+        crate::storage::set_attr(PROFILES_ATTR, &serialized);
+    }
+}
+
+/// Names the first profile after the player name last typed in, before profiles existed.
+/// Without one the name stays empty and the switcher shows the profile by number; it is not
+/// translated here because the translations themselves read settings through this module.
+fn legacy_profile_name() -> String {
+    crate::storage::get_attr("game_settings")
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|settings| settings.get("last_username")?.as_str().map(|name| name.trim().to_string()))
+        .unwrap_or_default()
+}
+
+/// "Player 2" and so on, in the given language
+pub fn numbered_profile_name(number: usize, language: &str) -> String {
+    let mut vars = std::collections::HashMap::new();
+    vars.insert("number".to_string(), number.to_string());
+    crate::translations::t_vars(crate::translations::TranslationKey::NewProfileName, language, &vars)
+}
+
+pub fn active_profile_id() -> String {
+    match ACTIVE_PROFILE.read() {
+        Ok(active) => active.clone(),
+        Err(_) => get_profiles().active,
+    }
+}
+
+/// The storage attribute `name` belongs under for the active profile
+pub fn attr_name(name: &str) -> String {
+    let profile_id = active_profile_id();
+    if profile_id == DEFAULT_PROFILE_ID {
+        name.to_string()
+    } else {
+        format!("{}.{}", name, profile_id)
+    }
+}
+
+/// Makes `profile_id` the active profile; false if there is no such profile
+pub fn switch_profile(profile_id: &str) -> bool {
+    let mut list = get_profiles();
+    if !list.profiles.iter().any(|profile| profile.id == profile_id) {
+        return false;
+    }
+    list.active = profile_id.to_string();
+    save_profiles(&list);
+    if let Ok(mut active) = ACTIVE_PROFILE.write() {
+        *active = profile_id.to_string();
+    }
+    true
+}
+
+/// Adds a profile without switching to it. It starts with default settings and no saves.
+pub fn create_profile(name: &str) -> Profile {
+    let mut list = get_profiles();
+    let timestamp_ms = crate::internal_storage::time_now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let mut id = format!("p{:x}", timestamp_ms);
+    while list.profiles.iter().any(|profile| profile.id == id) {
+        id.push('0');
+    }
+    let profile = Profile { id, name: name.to_string() };
+    list.profiles.push(profile.clone());
+    save_profiles(&list);
+    profile
+}
+
+pub fn rename_active_profile(name: &str) {
+    let mut list = get_profiles();
+    let active = list.active.clone();
+    if let Some(profile) = list.profiles.iter_mut().find(|profile| profile.id == active) {
+        profile.name = name.to_string();
+        save_profiles(&list);
+    }
+}
@@ -0,0 +1,149 @@
+use super::keys::TranslationKey;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// UI text compiled into the game, one TOML catalog per language. build.rs fails the
+/// build when eng.toml is missing a `TranslationKey`.
+pub const EMBEDDED_CATALOGS: &[(&str, &str)] = &[
+    ("eng", include_str!("catalogs/eng.toml")),
+    ("spa", include_str!("catalogs/spa.toml")),
+    ("fra", include_str!("catalogs/fra.toml")),
+    ("deu", include_str!("catalogs/deu.toml")),
+    ("ita", include_str!("catalogs/ita.toml")),
+    ("por", include_str!("catalogs/por.toml")),
+    ("rus", include_str!("catalogs/rus.toml")),
+    ("jpn", include_str!("catalogs/jpn.toml")),
+    ("kor", include_str!("catalogs/kor.toml")),
+    ("zho", include_str!("catalogs/zho.toml")),
+    ("ara", include_str!("catalogs/ara.toml")),
+    ("heb", include_str!("catalogs/heb.toml")),
+];
+
+/// Folder inside the crises folder holding `<language>.toml` catalogs that override or add UI text
+pub const OVERRIDES_FOLDER: &str = "translations";
+
+/// A catalog value: plain text, or one text per CLDR plural category ("zero", "one", "two", "few", "many", "other")
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum CatalogEntry {
+    Text(String),
+    Plural(HashMap<String, String>),
+}
+
+impl CatalogEntry {
+    /// The text to show for a count; plain text ignores the count
+    pub fn for_count(&self, language: &str, count: i64) -> Option<&String> {
+        match self {
+            CatalogEntry::Text(text) => Some(text),
+            CatalogEntry::Plural(forms) => forms.get(plural_category(language, count))
+                .or_else(|| forms.get("other")),
+        }
+    }
+
+    /// The text without a count, the "other" form of plurals
+    pub fn text(&self) -> Option<&String> {
+        match self {
+            CatalogEntry::Text(text) => Some(text),
+            CatalogEntry::Plural(forms) => forms.get("other"),
+        }
+    }
+}
+
+/// Facts about a catalog's language rather than text, written as `meta.<name> = ...`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct CatalogMeta {
+    pub direction: Option<crate::language::TextDirection>, // Left to right when no catalog of the language sets it
+}
+
+/// One parsed catalog file
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    pub entries: HashMap<TranslationKey, CatalogEntry>,
+    pub meta: CatalogMeta,
+    pub unknown_keys: Vec<String>, // Keys that are not `TranslationKey` variants, sorted
+}
+
+#[derive(Deserialize)]
+struct RawCatalog {
+    #[serde(default)]
+    meta: CatalogMeta,
+    #[serde(flatten)]
+    entries: HashMap<String, CatalogEntry>,
+}
+
+/// Parses one catalog. Keys that are not `TranslationKey` variants are returned separately
+/// so a typo in an override file can be reported instead of silently ignored.
+pub fn parse_catalog(contents: &str) -> Result<Catalog, String> {
+    let raw: RawCatalog = toml::from_str(contents).map_err(|e| e.to_string())?;
+    let mut entries = HashMap::new();
+    let mut unknown_keys = vec![];
+    for (name, entry) in raw.entries {
+        match TranslationKey::from_name(&name) {
+            Some(key) => {
+                entries.insert(key, entry);
+            }
+            None => unknown_keys.push(name),
+        }
+    }
+    unknown_keys.sort();
+    Ok(Catalog { entries, meta: raw.meta, unknown_keys })
+}
+
+/// CLDR cardinal plural category for the languages the game ships with. Languages without
+/// rules here use the English rule, and catalogs always fall back to "other".
+pub fn plural_category(language: &str, count: i64) -> &'static str {
+    let n = count.unsigned_abs();
+    match language {
+        "jpn" | "kor" | "zho" => "other",
+        "fra" => if n <= 1 { "one" } else { "other" },
+        "heb" => match n {
+            1 => "one",
+            2 => "two",
+            _ => "other",
+        },
+        "ara" => match n {
+            0 => "zero",
+            1 => "one",
+            2 => "two",
+            _ if (3..=10).contains(&(n % 100)) => "few",
+            _ if (11..=99).contains(&(n % 100)) => "many",
+            _ => "other",
+        },
+        "rus" => {
+            if n % 10 == 1 && n % 100 != 11 {
+                "one"
+            } else if (2..=4).contains(&(n % 10)) && !(12..=14).contains(&(n % 100)) {
+                "few"
+            } else {
+                "many"
+            }
+        }
+        _ => if n == 1 { "one" } else { "other" },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::TextDirection;
+
+    #[test]
+    fn reads_the_direction_apart_from_the_text() {
+        let catalog = parse_catalog("meta.direction = \"rtl\"\nQuitGame = \"Quit\"\n").unwrap();
+        assert_eq!(catalog.meta.direction, Some(TextDirection::RightToLeft));
+        assert_eq!(catalog.entries.get(&TranslationKey::QuitGame), Some(&CatalogEntry::Text("Quit".to_string())));
+        assert!(catalog.unknown_keys.is_empty());
+    }
+
+    #[test]
+    fn builtin_catalogs_declare_their_direction() {
+        for (language, contents) in EMBEDDED_CATALOGS {
+            let expected = if matches!(*language, "ara" | "heb") {
+                Some(TextDirection::RightToLeft)
+            } else {
+                None
+            };
+            assert_eq!(parse_catalog(contents).unwrap().meta.direction, expected, "{}", language);
+        }
+    }
+}
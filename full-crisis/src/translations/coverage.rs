@@ -0,0 +1,204 @@
+use crate::crisis::CrisisDefinition;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// One translatable text with all of its translations, e.g. the text of one choice
+#[derive(Debug, Clone)]
+pub struct TextEntry {
+    pub id: String, // Stable name such as "scenes/intro/choices/gather_details/text"; survives reordering
+    pub path: String, // Where the texts are in the TOML right now, such as "scenes/intro/choices/0/text"
+    pub texts: HashMap<String, String>, // Language code to text
+}
+
+impl TextEntry {
+    /// An entry whose id is also its path
+    pub fn new(id: String, texts: HashMap<String, String>) -> Self {
+        Self { path: id.clone(), id, texts }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlaceholderMismatch {
+    pub id: String,
+    pub language: String,
+    pub expected: BTreeSet<String>, // Placeholders in the reference language
+    pub found: BTreeSet<String>,
+}
+
+/// Missing translations and placeholder mismatches for one crisis or the UI table
+#[derive(Debug, Clone)]
+pub struct CoverageReport {
+    pub subject: String, // Crisis template name, or "UI" for the TranslationKey table
+    pub reference_language: String,
+    pub total: usize, // Number of texts that should exist in every language
+    pub missing: HashMap<String, Vec<String>>, // Language code to ids of missing texts
+    pub mismatched: Vec<PlaceholderMismatch>,
+    pub missing_pronouns: Vec<(String, crate::crisis::PlayerGender)>, // Languages using {they} without pronouns for a gender
+}
+
+impl CoverageReport {
+    pub fn build(subject: &str, reference_language: &str, entries: &[TextEntry], languages: &[String]) -> Self {
+        let mut missing: HashMap<String, Vec<String>> = HashMap::new();
+        let mut mismatched = vec![];
+        for entry in entries {
+            let expected = entry.texts.get(reference_language).map(|text| placeholders(text));
+            for language in languages {
+                match entry.texts.get(language) {
+                    Some(text) if !text.trim().is_empty() => {
+                        let found = placeholders(text);
+                        if let Some(expected) = expected.as_ref() {
+                            if *expected != found {
+                                mismatched.push(PlaceholderMismatch {
+                                    id: entry.id.clone(),
+                                    language: language.clone(),
+                                    expected: expected.clone(),
+                                    found,
+                                });
+                            }
+                        }
+                    }
+                    _ => missing.entry(language.clone()).or_default().push(entry.id.clone()),
+                }
+            }
+        }
+
+        Self {
+            subject: subject.to_string(),
+            reference_language: reference_language.to_string(),
+            total: entries.len(),
+            missing,
+            mismatched,
+            missing_pronouns: vec![],
+        }
+    }
+
+    /// Records the languages whose scene text uses pronoun placeholders without a pronoun set
+    pub fn check_pronouns(mut self, crisis: &CrisisDefinition) -> Self {
+        self.missing_pronouns = crate::crisis::pronouns::missing_pronoun_sets(crisis);
+        self
+    }
+
+    /// Drops placeholders that are allowed to differ between languages from the mismatch list
+    pub fn ignore_placeholders(mut self, names: &BTreeSet<String>) -> Self {
+        for mismatch in self.mismatched.iter_mut() {
+            mismatch.expected.retain(|name| !names.contains(name));
+            mismatch.found.retain(|name| !names.contains(name));
+        }
+        self.mismatched.retain(|mismatch| mismatch.expected != mismatch.found);
+        self
+    }
+
+    pub fn missing_in(&self, language: &str) -> &[String] {
+        self.missing.get(language).map(|ids| ids.as_slice()).unwrap_or(&[])
+    }
+
+    /// Share of texts present in a language, 0.0 to 1.0
+    pub fn coverage(&self, language: &str) -> f32 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        1.0 - self.missing_in(language).len() as f32 / self.total as f32
+    }
+}
+
+/// The `{name}` placeholders in a text, e.g. {character_name}. Pronoun placeholders such as
+/// {they} are left out, since languages like Spanish often drop the pronoun altogether.
+pub fn placeholders(text: &str) -> BTreeSet<String> {
+    let mut found = BTreeSet::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start + 1..];
+        let Some(end) = rest.find('}') else {
+            break;
+        };
+        let name = &rest[..end];
+        if !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            if !crate::crisis::pronouns::PRONOUN_PLACEHOLDERS.contains(&name.to_lowercase().as_str()) {
+                found.insert(name.to_string());
+            }
+            rest = &rest[end + 1..];
+        }
+    }
+    found
+}
+
+/// Words from `[gender_forms.<language>]`; each language defines its own, so they may differ between translations
+pub fn gender_form_names(crisis: &CrisisDefinition) -> BTreeSet<String> {
+    crisis.gender_forms.values()
+        .flat_map(|words| words.keys().cloned())
+        .collect()
+}
+
+/// Every translatable text of a crisis: name, description, character names and HUD labels,
+/// then each scene's text and choices, with scenes sorted by id so reports and exports are stable
+pub fn crisis_text_entries(crisis: &CrisisDefinition) -> Vec<TextEntry> {
+    let mut entries = vec![
+        TextEntry::new("name".to_string(), crisis.name.clone()),
+        TextEntry::new("description".to_string(), crisis.description.clone()),
+    ];
+
+    // One entry per character role and gender, e.g. "character_names/student/female"
+    let mut character_names: BTreeMap<(String, String), HashMap<String, String>> = BTreeMap::new();
+    for list in crisis.character_names.lists() {
+        if list.names.is_empty() {
+            continue;
+        }
+        character_names.entry((list.role.clone(), list.gender.clone()))
+            .or_default()
+            .insert(list.language.clone(), list.names.join(", "));
+    }
+    for ((role, gender), texts) in character_names {
+        entries.push(TextEntry::new(format!("character_names/{}/{}", role, gender), texts));
+    }
+
+    // HUD labels, named after their variable
+    for (index, spec) in crisis.conditions.variable_specs().iter().enumerate() {
+        if !spec.label.is_empty() {
+            entries.push(TextEntry {
+                id: format!("conditions/variables/{}/label", spec.name),
+                path: format!("conditions/variables/{}/label", index),
+                texts: spec.label.clone(),
+            });
+        }
+    }
+
+    let mut scene_ids: Vec<&String> = crisis.scenes.keys().collect();
+    scene_ids.sort();
+    for scene_id in scene_ids {
+        let scene = &crisis.scenes[scene_id];
+        entries.push(TextEntry::new(format!("scenes/{}/text", scene_id), scene.text.clone()));
+        for (index, choice) in scene.choices.iter().enumerate() {
+            let choice_id = format!("scenes/{}/choices/{}", scene_id, choice_key(&scene.choices, index));
+            let choice_path = format!("scenes/{}/choices/{}", scene_id, index);
+            entries.push(TextEntry {
+                id: format!("{}/text", choice_id),
+                path: format!("{}/text", choice_path),
+                texts: choice.text.clone(),
+            });
+            if let Some(placeholder) = choice.text_input.as_ref().and_then(|text_input| text_input.placeholder.as_ref()) {
+                entries.push(TextEntry {
+                    id: format!("{}/text_input/placeholder", choice_id),
+                    path: format!("{}/text_input/placeholder", choice_path),
+                    texts: placeholder.clone(),
+                });
+            }
+        }
+    }
+    entries
+}
+
+/// Names a choice after the scene it leads to, so its id stays put when choices are reordered.
+/// Further choices leading to the same scene get "~2", "~3", ... in the order they are written.
+fn choice_key(choices: &[crate::crisis::CrisisChoice], index: usize) -> String {
+    let leads_to = &choices[index].leads_to;
+    let occurrence = choices[..index].iter().filter(|choice| choice.leads_to == *leads_to).count() + 1;
+    if occurrence == 1 {
+        leads_to.clone()
+    } else {
+        format!("{}~{}", leads_to, occurrence)
+    }
+}
+
+/// Every `TranslationKey` in the UI catalogs (overrides included), keyed by its variant name
+pub fn ui_text_entries() -> Vec<TextEntry> {
+    super::get_translation_manager().text_entries()
+}
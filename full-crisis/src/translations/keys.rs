@@ -8,13 +8,46 @@ pub enum TranslationKey {
     NewGame,
     Settings,
     Licenses,
+    Statistics,
+    Profile,
+    NewProfile,
+    NewProfileName,
+    ProfileName,
+    ProfileExplanation,
+    SwitchProfilePrompt,
+    SwitchProfile,
     QuitGame,
     
     // New Game UI
     PlayerName,
     EnterName,
-    GameType,
-    SelectGameType,
+    Pronouns,
+    PronounsShe,
+    PronounsHe,
+    PronounsThey,
+    CustomPronouns,
+    SearchCrises,
+    AllLanguages,
+    AllTags,
+    CrisisTags,
+    SortOrder,
+    SortByName,
+    SortByAuthor,
+    SortByLength,
+    SortByDifficulty,
+    CrisisAuthor,
+    CrisisMinutes,
+    NoMatchingCrises,
+    EndingsReached,
+    ScenesExplored,
+    RunCount,
+    CompletionCount,
+    BestTime,
+    BestScore,
+    LastPlayed,
+    NotPlayedYet,
+    AllCrisesTotals,
+    CrisesPlayed,
     Go,
     
     // Continue Game UI
@@ -30,8 +63,30 @@ pub enum TranslationKey {
     GameCrisesFolder,
     EnterCrisesFolderPath,
     CrisesFolderExplanation,
+    LoadCrisisPack,
+    CrisisSource,
+    SourceBuiltIn,
+    SourceCrisesFolder,
+    SourcePack,
+    Overrides,
+    ForkCrisis,
+    CrisisForked,
+    CrisisForkFailed,
+    TrustedKeys,
+    EnterTrustedKeys,
+    RequireSignedCrises,
+    SigningExplanation,
+    SigningLockedByPolicy,
+    SignatureVerified,
+    SignatureUntrusted,
+    SignatureInvalid,
+    SignatureUnsigned,
+    PackLoaded,
+    PackLoadFailed,
+    PackNeedsNewerEngine,
     OpenFolder,
     SettingsStoragePath,
+    SavesStoragePath,
     SettingsStorageExplanation,
     DifficultyLevel,
     SelectDifficulty,
@@ -40,6 +95,16 @@ pub enum TranslationKey {
     SelectLanguage,
     FontScale,
     FontScaleExplanation,
+    Volume,
+    ReduceMotion,
+    ReduceMotionExplanation,
+    Theme,
+    ThemeSystem,
+    ThemeLight,
+    ThemeDark,
+    ThemeHighContrast,
+    On,
+    Off,
     
     // Game Interface
     WhatDoYouChoose,
@@ -51,6 +116,31 @@ pub enum TranslationKey {
     RequirementsNotMet,
     SaveAndQuit,
     Quit,
+    Rewind,
+    RestartCrisis,
+    Timeline,
+    DecisionCount,
+    ShowDecisionLog,
+    HideDecisionLog,
+    DecisionInput,
+    CrisisReloadFailed,
+    CrisisSceneRemoved,
+    Dismiss,
+    PauseMenu,
+    Paused,
+    Resume,
+    SaveGame,
+    LoadGame,
+    Back,
+    GameSaved,
+    SaveFailed,
+    NoSavedGames,
+    UnsavedProgress,
+    QuitWithoutSaving,
+    UnsavedProgressLoad,
+    LoadWithoutSaving,
+    SecondLanguage,
+    NoSecondLanguage,
     
     // Difficulty Levels
     Easy,
@@ -59,4 +149,12 @@ pub enum TranslationKey {
     
     // Generic
     SelectFromLeftMenu,
-}
\ No newline at end of file
+}
+
+impl TranslationKey {
+    /// Looks up a key by its variant name, as used in the translation catalogs
+    pub fn from_name(name: &str) -> Option<Self> {
+        use serde::de::{value::Error, IntoDeserializer};
+        Self::deserialize(IntoDeserializer::<Error>::into_deserializer(name)).ok()
+    }
+}
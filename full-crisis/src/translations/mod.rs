@@ -1,39 +1,35 @@
 pub mod keys;
-pub mod data;
+pub mod catalog;
+pub mod coverage;
+pub mod po;
 
 pub use keys::TranslationKey;
-pub use data::Translation;
+pub use catalog::CatalogEntry;
 
 use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 
 /// Central translation manager
 pub struct TranslationManager {
-    translations: HashMap<TranslationKey, HashMap<String, String>>,
+    translations: HashMap<String, HashMap<TranslationKey, CatalogEntry>>, // Language code to its catalog, overrides applied
+    directions: HashMap<String, crate::language::TextDirection>, // Languages whose catalog sets `meta.direction`
 }
 
 impl TranslationManager {
     pub fn new() -> Self {
         let mut manager = Self {
             translations: HashMap::new(),
+            directions: HashMap::new(),
         };
         manager.load_builtin_translations();
+        #[cfg(not(target_arch = "wasm32"))]
+        manager.load_override_translations();
         manager
     }
     
     /// Get translated text for a key in the specified language
     pub fn get(&self, key: TranslationKey, language: &str) -> String {
-        let fallback_chain = crate::language::get_language_fallback_chain(language);
-        
-        if let Some(translations) = self.translations.get(&key) {
-            for lang in fallback_chain {
-                if let Some(text) = translations.get(&lang) {
-                    return text.clone();
-                }
-            }
-        }
-        
-        // Final fallback - return the key name as a readable string
-        format!("{:?}", key)
+        self.lookup(key, language, |entry, _| entry.text())
     }
     
     /// Get translated text with variable substitution
@@ -47,25 +43,135 @@ impl TranslationManager {
         text
     }
     
-    /// Load all builtin translations
+    /// Get the plural form for `count` and fill in its {count} placeholder
+    pub fn get_plural(&self, key: TranslationKey, language: &str, count: i64) -> String {
+        self.lookup(key, language, |entry, language| entry.for_count(language, count))
+            .replace("{count}", &count.to_string())
+    }
+    
+    fn lookup<'a>(&'a self, key: TranslationKey, language: &str, select: impl Fn(&'a CatalogEntry, &str) -> Option<&'a String>) -> String {
+        let fallback_chain = crate::language::get_language_fallback_chain(language);
+        
+        for lang in fallback_chain {
+            if let Some(text) = self.translations.get(&lang).and_then(|catalog| catalog.get(&key)).and_then(|entry| select(entry, &lang)) {
+                return text.clone();
+            }
+        }
+        
+        // Final fallback - return the key name as a readable string
+        format!("{:?}", key)
+    }
+    
+    /// Reading direction a language's catalog declares, left to right when none does
+    pub fn text_direction(&self, language: &str) -> crate::language::TextDirection {
+        self.directions.get(language).copied().unwrap_or_default()
+    }
+    
+    /// Every language with a catalog, including ones only added by override files
+    pub fn languages(&self) -> Vec<String> {
+        let mut languages: Vec<String> = self.translations.keys().cloned().collect();
+        languages.sort();
+        languages
+    }
+    
+    /// Every key with its text in each language, for translation reports
+    pub fn text_entries(&self) -> Vec<coverage::TextEntry> {
+        let mut entries: HashMap<TranslationKey, HashMap<String, String>> = HashMap::new();
+        for (language, catalog) in self.translations.iter() {
+            for (key, entry) in catalog.iter() {
+                if let Some(text) = entry.text() {
+                    entries.entry(*key).or_default().insert(language.clone(), text.clone());
+                }
+            }
+        }
+        
+        let mut entries: Vec<coverage::TextEntry> = entries.into_iter()
+            .map(|(key, texts)| coverage::TextEntry::new(format!("{:?}", key), texts))
+            .collect();
+        entries.sort_by(|a, b| a.id.cmp(&b.id));
+        entries
+    }
+    
+    /// Load the catalogs compiled into the game
     fn load_builtin_translations(&mut self) {
-        let translations = data::get_builtin_translations();
+        for (language, contents) in catalog::EMBEDDED_CATALOGS {
+            match catalog::parse_catalog(contents) {
+                Ok(parsed) => {
+                    if !parsed.unknown_keys.is_empty() {
+                        eprintln!("Translations: Unknown keys in built-in {} catalog: {:?}", language, parsed.unknown_keys);
+                    }
+                    if let Some(direction) = parsed.meta.direction {
+                        self.directions.insert(language.to_string(), direction);
+                    }
+                    self.translations.insert(language.to_string(), parsed.entries);
+                }
+                Err(e) => eprintln!("Translations: Cannot parse built-in {} catalog: {}", language, e),
+            }
+        }
+    }
+    
+    /// Load `<crises folder>/translations/<language>.toml`, replacing built-in text key by key.
+    /// A file for a language the game does not ship adds that language.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_override_translations(&mut self) {
+        let verbosity = crate::VERBOSITY.get().unwrap_or(&0);
+        let overrides_folder = crate::crisis::PlayableCrises::crises_folder().join(catalog::OVERRIDES_FOLDER);
+        let Ok(files) = std::fs::read_dir(&overrides_folder) else {
+            return;
+        };
         
-        // Load translations into the manager
-        for translation in translations {
-            self.translations.insert(translation.key, translation.translations);
+        for path in files.filter_map(|file| file.ok()).map(|file| file.path()) {
+            if path.extension().map(|ext| ext != "toml").unwrap_or(true) {
+                continue;
+            }
+            let Some(language) = path.file_stem().map(|stem| stem.to_string_lossy().to_lowercase()) else {
+                continue;
+            };
+            
+            let parsed = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|contents| catalog::parse_catalog(&contents));
+            match parsed {
+                Ok(parsed) => {
+                    if !parsed.unknown_keys.is_empty() {
+                        eprintln!("Translations: Unknown keys in {:?}: {:?}", path, parsed.unknown_keys);
+                    }
+                    if *verbosity > 0 {
+                        eprintln!("Translations: {} overrides for '{}' from {:?}", parsed.entries.len(), language, path);
+                    }
+                    if let Some(direction) = parsed.meta.direction {
+                        self.directions.insert(language.clone(), direction);
+                    }
+                    // A file that only sets the direction does not add a language to the picker
+                    if !parsed.entries.is_empty() {
+                        self.translations.entry(language).or_default().extend(parsed.entries);
+                    }
+                }
+                Err(e) => eprintln!("Translations: Cannot parse {:?}: {}", path, e),
+            }
         }
     }
 }
 
-/// Global translation manager instance
-static TRANSLATION_MANAGER: once_cell::sync::Lazy<TranslationManager> = once_cell::sync::Lazy::new(|| {
-    TranslationManager::new()
+/// Global translation manager instance, replaced whenever the override files change
+static TRANSLATION_MANAGER: once_cell::sync::Lazy<RwLock<Arc<TranslationManager>>> = once_cell::sync::Lazy::new(|| {
+    RwLock::new(Arc::new(TranslationManager::new()))
 });
 
 /// Get the global translation manager
-pub fn get_translation_manager() -> &'static TranslationManager {
-    &TRANSLATION_MANAGER
+pub fn get_translation_manager() -> Arc<TranslationManager> {
+    match TRANSLATION_MANAGER.read() {
+        Ok(manager) => manager.clone(),
+        Err(_) => Arc::new(TranslationManager::new()),
+    }
+}
+
+/// Rebuild the translations, picking up changed override files
+pub fn reload_translations() {
+    let manager = Arc::new(TranslationManager::new());
+    if let Ok(mut current) = TRANSLATION_MANAGER.write() {
+        *current = manager;
+    }
 }
 
 /// Convenience function to get translated text
@@ -76,4 +182,9 @@ pub fn t(key: TranslationKey, language: &str) -> String {
 /// Convenience function to get translated text with variables
 pub fn t_vars(key: TranslationKey, language: &str, vars: &HashMap<String, String>) -> String {
     get_translation_manager().get_with_vars(key, language, vars)
-}
\ No newline at end of file
+}
+
+/// Convenience function to get the plural form of a text for a count
+pub fn t_count(key: TranslationKey, language: &str, count: i64) -> String {
+    get_translation_manager().get_plural(key, language, count)
+}
@@ -0,0 +1,549 @@
+use super::coverage::{self, TextEntry};
+use crate::crisis::CrisisDefinition;
+use std::collections::{BTreeSet, HashMap};
+
+/// One PO message; `context` holds the stable text id from `coverage::crisis_text_entries`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PoEntry {
+    pub context: String,
+    pub source: String, // msgid, the text in the source language
+    pub translation: String, // msgstr, empty while untranslated
+    pub fuzzy: bool,
+}
+
+/// A gettext PO file holding one crisis in one target language
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PoFile {
+    pub crisis: String, // Template name, e.g. "Fire_Dispatch"
+    pub source_language: String,
+    pub language: String,
+    pub entries: Vec<PoEntry>,
+}
+
+impl PoFile {
+    /// Every translatable crisis text except character names, with English as the source when the crisis has it
+    pub fn from_crisis(template_name: &str, crisis: &CrisisDefinition, language: &str) -> Self {
+        let entries = exported_entries(crisis);
+        let source_language = if entries.iter().any(|entry| entry.texts.contains_key("eng")) {
+            "eng".to_string()
+        } else {
+            crisis.story.default_language.clone()
+        };
+
+        Self {
+            crisis: template_name.to_string(),
+            language: language.to_string(),
+            entries: entries.into_iter()
+                .filter_map(|entry| {
+                    let source = entry.texts.get(&source_language)?.clone();
+                    Some(PoEntry {
+                        translation: entry.texts.get(language).cloned().unwrap_or_default(),
+                        context: entry.id,
+                        source,
+                        fuzzy: false,
+                    })
+                })
+                .collect(),
+            source_language,
+        }
+    }
+
+    pub fn to_po_string(&self) -> String {
+        let mut po = format!("# {} translation of the {} crisis\n", self.language, self.crisis);
+        po.push_str("msgid \"\"\nmsgstr \"\"\n");
+        for (name, value) in [
+            ("Language", self.language.as_str()),
+            ("Content-Type", "text/plain; charset=UTF-8"),
+            ("X-Crisis", self.crisis.as_str()),
+            ("X-Source-Language", self.source_language.as_str()),
+        ] {
+            po.push_str(&format!("\"{}: {}\\n\"\n", name, escape(value)));
+        }
+
+        for entry in self.entries.iter() {
+            po.push('\n');
+            po.push_str(&format!("#: {}\n", entry.context));
+            if entry.fuzzy {
+                po.push_str("#, fuzzy\n");
+            }
+            po.push_str(&format!("msgctxt \"{}\"\n", escape(&entry.context)));
+            po.push_str(&format!("msgid \"{}\"\n", escape(&entry.source)));
+            po.push_str(&format!("msgstr \"{}\"\n", escape(&entry.translation)));
+        }
+        po
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut file = PoFile::default();
+        let mut entry = PoEntry::default();
+        let mut has_entry = false;
+        let mut field: Option<&str> = None;
+
+        for (line_number, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                file.finish_entry(&mut entry, &mut has_entry);
+                field = None;
+                continue;
+            }
+            if let Some(flags) = line.strip_prefix("#,") {
+                if field.is_some() {
+                    file.finish_entry(&mut entry, &mut has_entry);
+                    field = None;
+                }
+                entry.fuzzy = flags.split(',').any(|flag| flag.trim() == "fuzzy");
+                has_entry = true;
+                continue;
+            }
+            if line.starts_with('#') {
+                continue;
+            }
+
+            let (keyword, quoted) = match line.split_once(' ') {
+                Some((keyword, quoted)) if !line.starts_with('"') => (Some(keyword), quoted.trim()),
+                _ => (None, line),
+            };
+            let text = unescape(quoted)
+                .ok_or_else(|| format!("Line {}: expected a quoted string: {}", line_number + 1, line))?;
+
+            match keyword {
+                Some(keyword @ ("msgctxt" | "msgid" | "msgstr")) => {
+                    // A new msgctxt, or a msgid without one, starts the next message
+                    if field.is_some() && (keyword == "msgctxt" || (keyword == "msgid" && field != Some("msgctxt"))) {
+                        file.finish_entry(&mut entry, &mut has_entry);
+                    }
+                    field = Some(keyword);
+                    has_entry = true;
+                    field_mut(&mut entry, keyword).push_str(&text);
+                }
+                Some(keyword) => return Err(format!("Line {}: unsupported keyword '{}'", line_number + 1, keyword)),
+                None => match field {
+                    Some(keyword) => field_mut(&mut entry, keyword).push_str(&text),
+                    None => return Err(format!("Line {}: string outside of a message", line_number + 1)),
+                },
+            }
+        }
+        file.finish_entry(&mut entry, &mut has_entry);
+
+        if file.language.is_empty() {
+            return Err("The PO header has no Language".to_string());
+        }
+        Ok(file)
+    }
+
+    fn finish_entry(&mut self, entry: &mut PoEntry, has_entry: &mut bool) {
+        if !*has_entry {
+            return;
+        }
+        *has_entry = false;
+        let entry = std::mem::take(entry);
+        if !entry.context.is_empty() || !entry.source.is_empty() {
+            self.entries.push(entry);
+            return;
+        }
+
+        // The header; its msgstr is a list of "Name: value" lines
+        for line in entry.translation.lines() {
+            if let Some((name, value)) = line.split_once(':') {
+                match name.trim() {
+                    "Language" => self.language = value.trim().to_string(),
+                    "X-Crisis" => self.crisis = value.trim().to_string(),
+                    "X-Source-Language" => self.source_language = value.trim().to_string(),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Translations whose `{placeholders}` differ from their source text, as (text id, message).
+    /// Placeholders named in `ignored` may differ, e.g. words from `[gender_forms]`.
+    pub fn placeholder_mismatches(&self, ignored: &BTreeSet<String>) -> Vec<(String, String)> {
+        self.entries.iter()
+            .filter(|entry| !entry.translation.is_empty())
+            .filter_map(|entry| {
+                let mut expected = coverage::placeholders(&entry.source);
+                let mut found = coverage::placeholders(&entry.translation);
+                expected.retain(|name| !ignored.contains(name));
+                found.retain(|name| !ignored.contains(name));
+                if expected == found {
+                    return None;
+                }
+                Some((entry.context.clone(), format!("expected {:?} but found {:?}", expected, found)))
+            })
+            .collect()
+    }
+}
+
+fn field_mut<'a>(entry: &'a mut PoEntry, keyword: &str) -> &'a mut String {
+    match keyword {
+        "msgctxt" => &mut entry.context,
+        "msgid" => &mut entry.source,
+        _ => &mut entry.translation,
+    }
+}
+
+fn exported_entries(crisis: &CrisisDefinition) -> Vec<TextEntry> {
+    coverage::crisis_text_entries(crisis)
+        .into_iter()
+        .filter(|entry| !entry.id.starts_with("character_names/"))
+        .collect()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+}
+
+fn unescape(quoted: &str) -> Option<String> {
+    let inner = quoted.strip_prefix('"')?.strip_suffix('"')?;
+    let mut text = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => text.push('\n'),
+            't' => text.push('\t'),
+            other => text.push(other),
+        }
+    }
+    Some(text)
+}
+
+/// What `import_into_folder` changed
+#[derive(Debug, Clone, Default)]
+pub struct PoImportSummary {
+    pub applied: usize,
+    pub skipped_fuzzy: usize,
+    pub stale: Vec<String>, // Ids whose source text changed since the export; these are not applied
+    pub unknown: Vec<String>, // Ids that no longer exist in the crisis
+}
+
+/// Writes the translations of a PO file into crisis.toml and the scene files of a crisis folder,
+/// keeping their formatting. Nothing is written when any placeholders are mismatched. Translations
+/// of a source text that has changed since the export are left out, since they may no longer fit.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn import_into_folder(crisis_folder: &std::path::Path, po: &PoFile) -> Result<PoImportSummary, Box<dyn std::error::Error>> {
+    use toml_edit::DocumentMut;
+
+    let crisis = crate::crisis::migrate::load_folder(crisis_folder)?;
+    let mismatches = po.placeholder_mismatches(&coverage::gender_form_names(&crisis));
+    if !mismatches.is_empty() {
+        let details: Vec<String> = mismatches.iter().map(|(id, message)| format!("  {}: {}", id, message)).collect();
+        return Err(format!("Refusing to import, {} translations have mismatched placeholders:\n{}", mismatches.len(), details.join("\n")).into());
+    }
+    if !crate::language::is_language_supported(&po.language) {
+        return Err(format!("Unsupported language '{}' in the PO header", po.language).into());
+    }
+
+    // Text id to where the text is now and what it says in the source language
+    let sources: HashMap<String, (String, String)> = exported_entries(&crisis).into_iter()
+        .filter_map(|entry| {
+            let source = entry.texts.get(&po.source_language)?.clone();
+            Some((entry.id, (entry.path, source)))
+        })
+        .collect();
+
+    let crisis_path = crisis_folder.join("crisis.toml");
+    let mut crisis_document: DocumentMut = std::fs::read_to_string(&crisis_path)?.parse()?;
+    let mut scene_documents: HashMap<String, DocumentMut> = HashMap::new();
+    for (scene_id, contents) in crate::crisis::migrate::read_scene_files(crisis_folder)? {
+        scene_documents.insert(scene_id, contents.parse()?);
+    }
+
+    let mut summary = PoImportSummary::default();
+    for entry in po.entries.iter().filter(|entry| !entry.translation.is_empty()) {
+        if entry.fuzzy {
+            summary.skipped_fuzzy += 1;
+            continue;
+        }
+        let Some((path, source)) = sources.get(&entry.context) else {
+            summary.unknown.push(entry.context.clone());
+            continue;
+        };
+        if *source != entry.source {
+            summary.stale.push(entry.context.clone());
+            continue;
+        }
+
+        let path: Vec<&str> = path.split('/').collect();
+        let texts = match path.as_slice() {
+            ["scenes", scene_id, rest @ ..] => match scene_documents.get_mut(*scene_id) {
+                Some(scene_document) => text_table(scene_document.as_table_mut(), rest),
+                None => text_table(crisis_document.as_table_mut(), &path),
+            },
+            _ => text_table(crisis_document.as_table_mut(), &path),
+        };
+        match texts {
+            Some(texts) => {
+                texts.insert(&po.language, toml_edit::value(entry.translation.as_str()));
+                summary.applied += 1;
+            }
+            None => summary.unknown.push(entry.context.clone()),
+        }
+    }
+
+    // Make sure the crisis still loads before anything is written
+    let crisis_toml = crisis_document.to_string();
+    let scene_files: Vec<(String, String)> = scene_documents.iter()
+        .map(|(scene_id, document)| (scene_id.clone(), document.to_string()))
+        .collect();
+    crate::crisis::migrate::resolve_definition(&crisis_toml, &scene_files)?;
+
+    std::fs::write(&crisis_path, crisis_toml)?;
+    for (scene_id, contents) in scene_files {
+        std::fs::write(crisis_folder.join("scenes").join(format!("{}.toml", scene_id)), contents)?;
+    }
+    Ok(summary)
+}
+
+/// Follows a text id such as "choices/0/text" down to the language table it names
+#[cfg(not(target_arch = "wasm32"))]
+fn text_table<'a>(table: &'a mut dyn toml_edit::TableLike, path: &[&str]) -> Option<&'a mut dyn toml_edit::TableLike> {
+    match path {
+        [] => Some(table),
+        [key, index, rest @ ..] if index.parse::<usize>().is_ok() => {
+            let index = index.parse::<usize>().ok()?;
+            let item = table.get_mut(key)?;
+            // [[conditions.variables]] tables, or an inline array such as variables = ["a", { name = "b" }]
+            if item.is_array_of_tables() {
+                text_table(item.as_array_of_tables_mut()?.get_mut(index)?, rest)
+            } else {
+                text_table(item.as_array_mut()?.get_mut(index)?.as_inline_table_mut()?, rest)
+            }
+        }
+        [key, rest @ ..] => text_table(table.get_mut(key)?.as_table_like_mut()?, rest),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_po() -> PoFile {
+        PoFile {
+            crisis: "Test_Crisis".to_string(),
+            source_language: "eng".to_string(),
+            language: "spa".to_string(),
+            entries: vec![
+                PoEntry {
+                    context: "scenes/intro/text".to_string(),
+                    source: "Hello {character_name},\n\"call\" C:\\dispatch\tnow".to_string(),
+                    translation: "Hola {character_name},\n\"llama\" C:\\dispatch\tya".to_string(),
+                    fuzzy: false,
+                },
+                PoEntry {
+                    context: "scenes/intro/choices/ending/text".to_string(),
+                    source: "Hang up".to_string(),
+                    translation: "Colgar".to_string(),
+                    fuzzy: true,
+                },
+                PoEntry {
+                    context: "name".to_string(),
+                    source: "Test Crisis".to_string(),
+                    translation: String::new(),
+                    fuzzy: false,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn escape_round_trips() {
+        for text in ["plain", "a \"quote\"", "back\\slash", "two\nlines", "tab\there", "\\n is not a newline", ""] {
+            assert_eq!(unescape(&format!("\"{}\"", escape(text))).as_deref(), Some(text));
+        }
+        assert_eq!(unescape("no quotes"), None);
+    }
+
+    #[test]
+    fn po_string_round_trips() {
+        let po = sample_po();
+        assert_eq!(PoFile::parse(&po.to_po_string()).unwrap(), po);
+    }
+
+    #[test]
+    fn parses_continuation_lines_and_comments() {
+        let contents = "msgid \"\"\nmsgstr \"\"\n\"Language: fra\\n\"\n\"X-Crisis: Test_Crisis\\n\"\n\n# translator note\nmsgctxt \"name\"\nmsgid \"\"\n\"Test \"\n\"Crisis\"\nmsgstr \"Crise \"\n\"de test\"\n";
+        let po = PoFile::parse(contents).unwrap();
+        assert_eq!(po.language, "fra");
+        assert_eq!(po.crisis, "Test_Crisis");
+        assert_eq!(po.entries, vec![PoEntry {
+            context: "name".to_string(),
+            source: "Test Crisis".to_string(),
+            translation: "Crise de test".to_string(),
+            fuzzy: false,
+        }]);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_files() {
+        assert!(PoFile::parse("msgctxt \"name\"\nmsgid \"Name\"\nmsgstr \"Nombre\"\n").is_err()); // No Language header
+        assert!(PoFile::parse("msgid \"\"\nmsgstr \"Language: spa\\n\"\n\nmsgid unquoted\n").is_err());
+        assert!(PoFile::parse("msgid \"\"\nmsgstr \"Language: spa\\n\"\n\nmsgplural \"x\"\n").is_err());
+    }
+
+    #[test]
+    fn finds_placeholder_mismatches() {
+        let mut po = sample_po();
+        assert!(po.placeholder_mismatches(&BTreeSet::new()).is_empty());
+
+        po.entries[0].translation = "Hola {nombre}".to_string();
+        let mismatches = po.placeholder_mismatches(&BTreeSet::new());
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].0, "scenes/intro/text");
+
+        // Pronoun placeholders and ignored words may differ
+        po.entries[0].translation = "Hola {character_name} {they}".to_string();
+        assert!(po.placeholder_mismatches(&BTreeSet::new()).is_empty());
+        po.entries[0].translation = "Hola {character_name} {listo}".to_string();
+        assert!(po.placeholder_mismatches(&BTreeSet::from(["listo".to_string()])).is_empty());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    const CRISIS_TOML: &str = r#"[metadata]
+id = "test_crisis"
+version = "1.0"
+author = "Tester"
+description_key = "test_crisis_desc"
+
+[name]
+eng = "Test Crisis"
+
+[description]
+eng = "A crisis for tests"
+
+[character_names.player.any]
+eng = ["Sam"]
+
+[story]
+starting_scene = "intro"
+default_language = "eng"
+
+[mechanics]
+time_limit_minutes = 5
+save_progress = true
+allow_restart = true
+track_decisions = false
+
+[conditions]
+variables = []
+"#;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    const INTRO_SCENE: &str = r#"text.eng = "Hello {character_name}."
+
+[[choices]]
+text.eng = "Wait"
+leads_to = "intro"
+
+[[choices]]
+text.eng = "Hang up"
+leads_to = "ending"
+"#;
+
+    /// A crisis folder under the temp directory, removed again when dropped
+    #[cfg(not(target_arch = "wasm32"))]
+    struct TestFolder(std::path::PathBuf);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    impl TestFolder {
+        fn new(name: &str) -> Self {
+            let folder = std::env::temp_dir().join(format!("full_crisis_po_{}_{}", name, std::process::id()));
+            let _ = std::fs::remove_dir_all(&folder);
+            std::fs::create_dir_all(folder.join("scenes")).unwrap();
+            std::fs::write(folder.join("crisis.toml"), CRISIS_TOML).unwrap();
+            std::fs::write(folder.join("scenes").join("intro.toml"), INTRO_SCENE).unwrap();
+            Self(folder)
+        }
+
+        fn intro_scene(&self) -> String {
+            std::fs::read_to_string(self.0.join("scenes").join("intro.toml")).unwrap()
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    impl Drop for TestFolder {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn exported(folder: &TestFolder) -> PoFile {
+        let crisis = crate::crisis::migrate::load_folder(&folder.0).unwrap();
+        PoFile::from_crisis("Test_Crisis", &crisis, "spa")
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn translate(po: &mut PoFile, context: &str, translation: &str) {
+        let entry = po.entries.iter_mut().find(|entry| entry.context == context).unwrap();
+        entry.translation = translation.to_string();
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn exports_choices_by_the_scene_they_lead_to() {
+        let folder = TestFolder::new("export");
+        let contexts: Vec<String> = exported(&folder).entries.into_iter().map(|entry| entry.context).collect();
+        assert!(contexts.contains(&"scenes/intro/choices/intro/text".to_string()));
+        assert!(contexts.contains(&"scenes/intro/choices/ending/text".to_string()));
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn import_refuses_mismatched_placeholders() {
+        let folder = TestFolder::new("mismatch");
+        let mut po = exported(&folder);
+        translate(&mut po, "scenes/intro/text", "Hola {nombre}.");
+        translate(&mut po, "scenes/intro/choices/ending/text", "Colgar");
+
+        assert!(import_into_folder(&folder.0, &po).is_err());
+        assert_eq!(folder.intro_scene(), INTRO_SCENE);
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn import_applies_translations_after_choices_are_reordered() {
+        let folder = TestFolder::new("reorder");
+        let mut po = exported(&folder);
+        translate(&mut po, "scenes/intro/text", "Hola {character_name}.");
+        translate(&mut po, "scenes/intro/choices/ending/text", "Colgar");
+
+        // Swap the two choices after the export
+        let (head, choices) = INTRO_SCENE.split_once("[[choices]]").unwrap();
+        let (wait, hang_up) = choices.split_once("[[choices]]").unwrap();
+        std::fs::write(folder.0.join("scenes").join("intro.toml"), format!("{}[[choices]]{}\n[[choices]]{}", head, hang_up.trim_end(), wait)).unwrap();
+
+        let summary = import_into_folder(&folder.0, &po).unwrap();
+        assert_eq!(summary.applied, 2);
+        let crisis = crate::crisis::migrate::load_folder(&folder.0).unwrap();
+        let intro = &crisis.scenes["intro"];
+        assert_eq!(intro.text["spa"], "Hola {character_name}.");
+        assert_eq!(intro.choices[0].leads_to, "ending");
+        assert_eq!(intro.choices[0].text["spa"], "Colgar");
+        assert!(!intro.choices[1].text.contains_key("spa"));
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn import_skips_stale_and_fuzzy_translations() {
+        let folder = TestFolder::new("stale");
+        let mut po = exported(&folder);
+        translate(&mut po, "scenes/intro/text", "Hola {character_name}.");
+        translate(&mut po, "scenes/intro/choices/ending/text", "Colgar");
+        po.entries.iter_mut().find(|entry| entry.context == "scenes/intro/choices/ending/text").unwrap().fuzzy = true;
+
+        std::fs::write(folder.0.join("scenes").join("intro.toml"), INTRO_SCENE.replace("Hello", "Good evening")).unwrap();
+
+        let summary = import_into_folder(&folder.0, &po).unwrap();
+        assert_eq!(summary.applied, 0);
+        assert_eq!(summary.skipped_fuzzy, 1);
+        assert_eq!(summary.stale, vec!["scenes/intro/text".to_string()]);
+        assert!(!folder.intro_scene().contains("spa"));
+    }
+}
@@ -0,0 +1,44 @@
+use wasm_bindgen::prelude::*;
+use crate::accessibility::AccessibleControl;
+
+// The game is drawn on a canvas, which screen readers cannot see into, so what they
+// should read goes into visually hidden ARIA live regions next to it.
+#[wasm_bindgen(inline_js = "
+function liveRegion(id, politeness) {
+    let region = document.getElementById(id);
+    if (!region) {
+        region = document.createElement('div');
+        region.id = id;
+        region.setAttribute('aria-live', politeness);
+        region.setAttribute('aria-atomic', 'true');
+        region.setAttribute('role', politeness === 'assertive' ? 'alert' : 'status');
+        region.style.cssText = 'position:absolute;width:1px;height:1px;margin:-1px;padding:0;overflow:hidden;clip:rect(0,0,0,0);white-space:nowrap;border:0;';
+        document.body.appendChild(region);
+    }
+    return region;
+}
+export function js_announce_scene(text) {
+    liveRegion('full-crisis-scene', 'polite').textContent = text;
+}
+export function js_announce_focus(text) {
+    liveRegion('full-crisis-focus', 'assertive').textContent = text;
+}
+")]
+unsafe extern "C" {
+    pub fn js_announce_scene(text: &str);
+    pub fn js_announce_focus(text: &str);
+}
+
+pub fn set_scene_text(text: &str) {
+    js_announce_scene(text)
+}
+
+/// Live regions only carry text, so the value (a toggle's "on" or "off" included) is read after the label
+pub fn set_focused_control(control: Option<AccessibleControl>) {
+    let announcement = match control {
+        Some(AccessibleControl { label, value: Some(value), .. }) => format!("{}: {}", label, value),
+        Some(AccessibleControl { label, value: None, .. }) => label,
+        None => String::new(),
+    };
+    js_announce_focus(&announcement)
+}
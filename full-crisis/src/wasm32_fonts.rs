@@ -0,0 +1,50 @@
+use wasm_bindgen::prelude::*;
+
+// Fonts the web build leaves out of the wasm file are fetched from next to the
+// page once a language needs them. Like pack uploads the bytes only arrive
+// asynchronously, so they are queued on the JS side and polled from the GUI.
+#[wasm_bindgen(inline_js = "
+const fetchedFonts = [];
+let fontFetchesRunning = 0;
+export function js_request_font(url) {
+    fontFetchesRunning += 1;
+    fetch(url)
+        .then((response) => response.ok ? response.arrayBuffer() : Promise.reject(response.status))
+        .then(
+            (buffer) => fetchedFonts.push(new Uint8Array(buffer)),
+            (e) => console.log(`Could not load font ${url}: ${e}`),
+        )
+        .finally(() => { fontFetchesRunning -= 1; });
+}
+export function js_take_fetched_font() {
+    return fetchedFonts.shift() || new Uint8Array(0);
+}
+export function js_font_fetches_running() {
+    return fontFetchesRunning > 0;
+}
+")]
+unsafe extern "C" {
+    pub fn js_request_font(url: &str);
+    pub fn js_take_fetched_font() -> Vec<u8>;
+    pub fn js_font_fetches_running() -> bool;
+}
+
+/// Starts downloading a font file, relative to the page
+pub fn request_font(url: &str) {
+    js_request_font(url)
+}
+
+/// Returns the next downloaded font, if one has arrived
+pub fn take_fetched_font() -> Option<Vec<u8>> {
+    let bytes = js_take_fetched_font();
+    if bytes.is_empty() {
+        None
+    } else {
+        Some(bytes)
+    }
+}
+
+/// True while any requested font is still downloading
+pub fn font_fetches_running() -> bool {
+    js_font_fetches_running()
+}
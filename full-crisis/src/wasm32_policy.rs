@@ -0,0 +1,22 @@
+use wasm_bindgen::prelude::*;
+
+// Whoever hosts the page sets the policy as TOML text before the game loads:
+// <script>window.fullCrisisPolicy = 'require_signed_crises = true';</script>
+#[wasm_bindgen(inline_js = "
+export function js_get_policy() {
+    return typeof globalThis.fullCrisisPolicy === 'string' ? globalThis.fullCrisisPolicy : '';
+}
+")]
+unsafe extern "C" {
+    pub fn js_get_policy() -> String;
+}
+
+/// Where the policy was found and its contents
+pub fn read_policy() -> Option<(String, String)> {
+    let contents = js_get_policy();
+    if contents.is_empty() {
+        None
+    } else {
+        Some(("window.fullCrisisPolicy".to_string(), contents))
+    }
+}
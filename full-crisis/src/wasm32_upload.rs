@@ -0,0 +1,70 @@
+use wasm_bindgen::prelude::*;
+
+// The browser only hands over file contents asynchronously, so uploads are
+// queued on the JS side and polled from the GUI. A picker that is closed without
+// a file, fails to read, or is still open after the timeout (older browsers never
+// fire "cancel") is reported as given up so the GUI stops polling.
+#[wasm_bindgen(inline_js = "
+const UPLOAD_TIMEOUT_MS = 5 * 60 * 1000;
+const uploadedPacks = [];
+let uploadGivenUp = false;
+let uploadTimeout = null;
+function finishUpload(givenUp) {
+    clearTimeout(uploadTimeout);
+    uploadGivenUp = uploadGivenUp || givenUp;
+}
+export function js_request_pack_upload() {
+    uploadGivenUp = false;
+    const input = document.createElement('input');
+    input.type = 'file';
+    input.accept = '.fcpack';
+    input.onchange = () => {
+        const file = input.files && input.files[0];
+        if (!file) {
+            finishUpload(true);
+            return;
+        }
+        file.arrayBuffer().then(
+            (buffer) => { uploadedPacks.push(new Uint8Array(buffer)); finishUpload(false); },
+            () => finishUpload(true),
+        );
+    };
+    input.addEventListener('cancel', () => finishUpload(true));
+    clearTimeout(uploadTimeout);
+    uploadTimeout = setTimeout(() => finishUpload(true), UPLOAD_TIMEOUT_MS);
+    input.click();
+}
+export function js_take_uploaded_pack() {
+    return uploadedPacks.shift() || new Uint8Array(0);
+}
+export function js_take_pack_upload_given_up() {
+    const givenUp = uploadGivenUp;
+    uploadGivenUp = false;
+    return givenUp;
+}
+")]
+unsafe extern "C" {
+    pub fn js_request_pack_upload();
+    pub fn js_take_uploaded_pack() -> Vec<u8>;
+    pub fn js_take_pack_upload_given_up() -> bool;
+}
+
+/// Opens the browser's file picker for an `.fcpack` file
+pub fn request_pack_upload() {
+    js_request_pack_upload()
+}
+
+/// Returns the next uploaded pack, if the browser has finished reading one
+pub fn take_uploaded_pack() -> Option<Vec<u8>> {
+    let bytes = js_take_uploaded_pack();
+    if bytes.is_empty() {
+        None
+    } else {
+        Some(bytes)
+    }
+}
+
+/// True once if the picker was cancelled, the file could not be read, or the upload timed out
+pub fn take_upload_given_up() -> bool {
+    js_take_pack_upload_given_up()
+}
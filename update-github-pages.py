@@ -187,6 +187,14 @@ with tempfile.TemporaryDirectory(prefix='full-crisis-github-pages') as td:
     os.path.join(f'{td}', 'wasm32'),
     dirs_exist_ok=True
   )
+  # Fonts the wasm build leaves out are downloaded by the page from wasm32/fonts on demand
+  subprocess.run([
+    'uv', 'run', 'fetch_fonts.py', os.path.join(f'{td}', 'wasm32', 'fonts'), '--web-lazy'
+  ], check=True, cwd=os.path.join(git_repo, 'fonts'))
+  shutil.copy(
+    os.path.join(git_repo, 'fonts', 'OFL.txt'),
+    os.path.join(f'{td}', 'wasm32', 'fonts', 'OFL.txt')
+  )
   # Copy the file fc_index.html to wasm32/index.html
   shutil.copy(
     os.path.join(git_repo, 'full-crisis-web', 'fc_index.html'),